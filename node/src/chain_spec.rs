@@ -14,6 +14,10 @@ pub type ChainSpec = sc_service::GenericChainSpec<laos_runtime::RuntimeGenesisCo
 /// The default XCM version to set in genesis config.
 const SAFE_XCM_VERSION: u32 = xcm::prelude::XCM_VERSION;
 
+/// Environment variable with the path to a JSON file holding the genesis config of the living
+/// assets ownership pallet, used to pre-seed collections and asset owners in dev networks.
+const LIVING_ASSETS_GENESIS_ENV: &str = "LIVING_ASSETS_GENESIS";
+
 /// Helper function to generate a crypto pair from seed
 pub fn get_from_seed<TPublic: Public>(seed: &str) -> <TPublic::Pair as Pair>::Public {
 	TPublic::Pair::from_string(&format!("//{seed}"), None)
@@ -62,14 +66,24 @@ pub fn template_session_keys(keys: AuraId) -> laos_runtime::SessionKeys {
 	laos_runtime::SessionKeys { aura: keys }
 }
 
-pub fn development_config() -> ChainSpec {
+/// Reads the genesis config of the living assets ownership pallet from the JSON file set in
+/// [`LIVING_ASSETS_GENESIS_ENV`], or returns an empty one if the variable is not set.
+fn living_assets_ownership_genesis() -> Result<laos_runtime::LivingAssetsOwnershipConfig, String> {
+	let Ok(path) = std::env::var(LIVING_ASSETS_GENESIS_ENV) else { return Ok(Default::default()) };
+	let file = std::fs::File::open(&path).map_err(|e| format!("Error opening {path}: {e}"))?;
+	serde_json::from_reader(file).map_err(|e| format!("Error parsing {path}: {e}"))
+}
+
+pub fn development_config() -> Result<ChainSpec, String> {
+	let living_assets_ownership = living_assets_ownership_genesis()?;
+
 	// Give your base currency a unit name and decimal places
 	let mut properties = sc_chain_spec::Properties::new();
 	properties.insert("tokenSymbol".into(), "UNIT".into());
 	properties.insert("tokenDecimals".into(), 12.into());
 	properties.insert("ss58Format".into(), 42.into());
 
-	ChainSpec::from_genesis(
+	Ok(ChainSpec::from_genesis(
 		// Name
 		"Development",
 		// ID
@@ -105,6 +119,7 @@ pub fn development_config() -> ChainSpec {
 				// Give Alice root privileges
 				Some(get_account_id_from_seed::<sr25519::Public>("Alice")),
 				1000.into(),
+				living_assets_ownership.clone(),
 			)
 		},
 		Vec::new(),
@@ -116,17 +131,19 @@ pub fn development_config() -> ChainSpec {
 			relay_chain: "rococo-local".into(), // You MUST set this to the correct network!
 			para_id: 1000,
 		},
-	)
+	))
 }
 
-pub fn local_testnet_config() -> ChainSpec {
+pub fn local_testnet_config() -> Result<ChainSpec, String> {
+	let living_assets_ownership = living_assets_ownership_genesis()?;
+
 	// Give your base currency a unit name and decimal places
 	let mut properties = sc_chain_spec::Properties::new();
 	properties.insert("tokenSymbol".into(), "UNIT".into());
 	properties.insert("tokenDecimals".into(), 12.into());
 	properties.insert("ss58Format".into(), 42.into());
 
-	ChainSpec::from_genesis(
+	Ok(ChainSpec::from_genesis(
 		// Name
 		"Local Testnet",
 		// ID
@@ -162,6 +179,7 @@ pub fn local_testnet_config() -> ChainSpec {
 				// Give Alice root privileges
 				Some(get_account_id_from_seed::<sr25519::Public>("Alice")),
 				1000.into(),
+				living_assets_ownership.clone(),
 			)
 		},
 		// Bootnodes
@@ -179,7 +197,7 @@ pub fn local_testnet_config() -> ChainSpec {
 			relay_chain: "rococo-local".into(), // You MUST set this to the correct network!
 			para_id: 1000,
 		},
-	)
+	))
 }
 
 fn testnet_genesis(
@@ -187,6 +205,7 @@ fn testnet_genesis(
 	endowed_accounts: Vec<AccountId>,
 	root_key: Option<AccountId>,
 	id: ParaId,
+	living_assets_ownership: laos_runtime::LivingAssetsOwnershipConfig,
 ) -> laos_runtime::RuntimeGenesisConfig {
 	// let alice = get_from_seed::<sr25519::Public>("Alice");
 	// let bob = get_from_seed::<sr25519::Public>("Bob");
@@ -262,6 +281,7 @@ fn testnet_genesis(
 		},
 		sudo: laos_runtime::SudoConfig { key: root_key },
 		transaction_payment: Default::default(),
+		living_assets_ownership,
 		// EVM compatibility
 		evm_chain_id: laos_runtime::EVMChainIdConfig { chain_id: 1000, ..Default::default() },
 		evm: laos_runtime::EVMConfig {
@@ -25,9 +25,9 @@ fn load_spec(id: &str) -> std::result::Result<Box<dyn ChainSpec>, String> {
 		"arrakis" => Box::new(chain_spec::ChainSpec::from_json_bytes(
 			&include_bytes!("../../specs/arrakis-frontier.json")[..],
 		)?),
-		"dev" => Box::new(chain_spec::development_config()),
-		"template-rococo" => Box::new(chain_spec::local_testnet_config()),
-		"" | "local" => Box::new(chain_spec::local_testnet_config()),
+		"dev" => Box::new(chain_spec::development_config()?),
+		"template-rococo" => Box::new(chain_spec::local_testnet_config()?),
+		"" | "local" => Box::new(chain_spec::local_testnet_config()?),
 		path => Box::new(chain_spec::ChainSpec::from_json_file(std::path::PathBuf::from(path))?),
 	})
 }
@@ -0,0 +1,831 @@
+//! Benchmarking setup for pallet-living-assets-ownership
+use super::*;
+
+use crate::traits::{CollectionManager, Erc721};
+#[allow(unused)]
+use crate::Pallet as LivingAssetsOwnership;
+use frame_benchmarking::v2::*;
+use frame_support::traits::{Currency, Get};
+use frame_system::RawOrigin;
+use sp_core::U256;
+use sp_runtime::traits::{Bounded, Convert};
+
+fn base_uri_of_len<T: Config>(len: u32) -> BaseURI<T> {
+	sp_std::vec![b'a'; len as usize]
+		.try_into()
+		.expect("len does not exceed BaseURILimit; qed")
+}
+
+fn fund_account<T: Config>(who: &T::AccountId) {
+	T::Currency::make_free_balance_be(who, BalanceOf::<T>::max_value() / 2u32.into());
+}
+
+fn create_collection_with_uri_len<T: Config>(owner: T::AccountId, len: u32) -> CollectionId {
+	fund_account::<T>(&owner);
+	LivingAssetsOwnership::<T>::do_create_collection(owner, base_uri_of_len::<T>(len))
+		.expect("owner can afford the deposit and collection counter does not overflow; qed")
+}
+
+/// Address of the user of the assets that have one.
+fn user<T: Config>() -> H160 {
+	T::AccountIdToH160::convert(account("user", 0, 0))
+}
+
+/// Makes `owner` the explicit owner of `asset_id` and of the asset indexed after it, so that
+/// taking `asset_id` away moves the other one into its index.
+fn set_asset_owner<T: Config>(collection_id: CollectionId, asset_id: U256, owner: T::AccountId) {
+	let owner = T::AccountIdToH160::convert(owner);
+	for asset_id in [asset_id, asset_id + 1] {
+		AssetOwner::<T>::insert(collection_id, asset_id, owner);
+		LivingAssetsOwnership::<T>::add_asset_to_owner(collection_id, owner, asset_id);
+	}
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn create_collection(b: Linear<0, { T::BaseURILimit::get() }>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let base_uri = base_uri_of_len::<T>(b);
+		fund_account::<T>(&caller);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), base_uri);
+
+		assert_eq!(CollectionOwner::<T>::get(0), Some(caller));
+	}
+
+	#[benchmark]
+	fn create_collection_with_name(b: Linear<0, { T::BaseURILimit::get() }>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let name: Name<T> = sp_std::vec![b'a'; T::NameLimit::get() as usize]
+			.try_into()
+			.expect("len does not exceed NameLimit; qed");
+		let symbol: Symbol<T> = sp_std::vec![b'a'; T::SymbolLimit::get() as usize]
+			.try_into()
+			.expect("len does not exceed SymbolLimit; qed");
+		let base_uri = base_uri_of_len::<T>(b);
+		fund_account::<T>(&caller);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), name.clone(), symbol, base_uri);
+
+		assert_eq!(CollectionName::<T>::get(0), name);
+	}
+
+	#[benchmark]
+	fn create_collection_with_mode(b: Linear<0, { T::BaseURILimit::get() }>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let name: Name<T> = sp_std::vec![b'a'; T::NameLimit::get() as usize]
+			.try_into()
+			.expect("len does not exceed NameLimit; qed");
+		let symbol: Symbol<T> = sp_std::vec![b'a'; T::SymbolLimit::get() as usize]
+			.try_into()
+			.expect("len does not exceed SymbolLimit; qed");
+		let base_uri = base_uri_of_len::<T>(b);
+		let mode = MintingMode::Explicit { max_supply: Some(u64::MAX) };
+		fund_account::<T>(&caller);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), name, symbol, base_uri, mode);
+
+		assert_eq!(CollectionMintingMode::<T>::get(0), mode);
+	}
+
+	#[benchmark]
+	fn transfer_collection_ownership() {
+		let caller: T::AccountId = whitelisted_caller();
+		let new_owner: T::AccountId = account("new_owner", 0, 0);
+		let collection_id = create_collection_with_uri_len::<T>(caller.clone(), 0);
+		// the deposit moves to the new owner, which must exist
+		fund_account::<T>(&new_owner);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection_id, new_owner.clone());
+
+		assert_eq!(CollectionOwner::<T>::get(collection_id), Some(new_owner.clone()));
+		assert_eq!(
+			CollectionDepositOf::<T>::get(collection_id).map(|(depositor, _)| depositor),
+			Some(new_owner)
+		);
+	}
+
+	#[benchmark]
+	fn renounce_collection_ownership() {
+		let caller: T::AccountId = whitelisted_caller();
+		let collection_id = create_collection_with_uri_len::<T>(caller.clone(), 0);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection_id);
+
+		assert_eq!(CollectionOwner::<T>::get(collection_id), None);
+		assert_eq!(CollectionDepositOf::<T>::get(collection_id), None);
+	}
+
+	#[benchmark]
+	fn set_base_uri(b: Linear<0, { T::BaseURILimit::get() }>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let creator: T::AccountId = account("creator", 0, 0);
+		// the deposit moves from the creator to the caller, which is the most expensive path
+		let collection_id = create_collection_with_uri_len::<T>(creator, 0);
+		CollectionOwner::<T>::insert(collection_id, caller.clone());
+		fund_account::<T>(&caller);
+		let base_uri = base_uri_of_len::<T>(b);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection_id, base_uri.clone());
+
+		assert_eq!(CollectionBaseURI::<T>::get(collection_id), Some(base_uri));
+	}
+
+	#[benchmark]
+	fn set_token_uri(b: Linear<0, { T::BaseURILimit::get() }>) {
+		let owner: T::AccountId = account("owner", 0, 0);
+		let caller: T::AccountId = whitelisted_caller();
+		let collection_id = create_collection_with_uri_len::<T>(owner, 0);
+		// a caller holding the role without owning the collection is checked against both
+		CollectionRoles::<T>::insert(
+			(collection_id, CollectionRole::MetadataManager, &caller),
+			true,
+		);
+		let asset_id = U256::from(1);
+		let token_uri = base_uri_of_len::<T>(b);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection_id, asset_id, token_uri.clone());
+
+		assert_eq!(
+			AssetTokenURI::<T>::get(collection_id, asset_id),
+			Some(token_uri).filter(|uri| !uri.is_empty())
+		);
+	}
+
+	#[benchmark]
+	fn transfer() {
+		let caller: T::AccountId = whitelisted_caller();
+		let to: T::AccountId = account("to", 0, 0);
+		let collection_id = create_collection_with_uri_len::<T>(caller.clone(), 0);
+		let asset_id = U256::from(1);
+		set_asset_owner::<T>(collection_id, asset_id, caller.clone());
+		// a user that has not expired is removed and reported as such, the most expensive path
+		AssetUser::<T>::insert(collection_id, asset_id, (user::<T>(), u64::MAX));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection_id, to.clone(), asset_id);
+
+		assert_eq!(
+			AssetOwner::<T>::get(collection_id, asset_id),
+			Some(T::AccountIdToH160::convert(to))
+		);
+	}
+
+	#[benchmark]
+	fn transfer_from() {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner: T::AccountId = account("owner", 0, 0);
+		let to: T::AccountId = account("to", 0, 0);
+		let collection_id = create_collection_with_uri_len::<T>(owner.clone(), 0);
+		let asset_id = U256::from(1);
+		set_asset_owner::<T>(collection_id, asset_id, owner.clone());
+		// a user that has not expired is removed and reported as such, the most expensive path
+		AssetUser::<T>::insert(collection_id, asset_id, (user::<T>(), u64::MAX));
+		// the caller is checked as an operator last, which is the most expensive path
+		OperatorApproval::<T>::insert(
+			(
+				collection_id,
+				T::AccountIdToH160::convert(owner.clone()),
+				T::AccountIdToH160::convert(caller.clone()),
+			),
+			true,
+		);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection_id, owner, to.clone(), asset_id);
+
+		assert_eq!(
+			AssetOwner::<T>::get(collection_id, asset_id),
+			Some(T::AccountIdToH160::convert(to))
+		);
+	}
+
+	#[benchmark]
+	fn batch_transfer(n: Linear<1, { T::MaxBatchTransfers::get() }>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner: T::AccountId = account("owner", 0, 0);
+		let collection_id = create_collection_with_uri_len::<T>(owner.clone(), 0);
+		let owner_address = T::AccountIdToH160::convert(owner.clone());
+		let mut transfers = BatchTransfers::<T>::default();
+		for i in 0..n {
+			let asset_id = U256::from(i);
+			AssetOwner::<T>::insert(collection_id, asset_id, owner_address);
+			LivingAssetsOwnership::<T>::add_asset_to_owner(collection_id, owner_address, asset_id);
+			AssetUser::<T>::insert(collection_id, asset_id, (user::<T>(), u64::MAX));
+			transfers
+				.try_push((collection_id, account("to", i, 0), asset_id))
+				.expect("n does not exceed MaxBatchTransfers; qed");
+		}
+		// the caller is checked as an operator last, which is the most expensive path
+		OperatorApproval::<T>::insert(
+			(collection_id, owner_address, T::AccountIdToH160::convert(caller.clone())),
+			true,
+		);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), owner, transfers);
+
+		assert_eq!(OwnerAssetCount::<T>::get(collection_id, owner_address), 0);
+	}
+
+	#[benchmark]
+	fn destroy_collection() {
+		let caller: T::AccountId = whitelisted_caller();
+		let collection_id = create_collection_with_uri_len::<T>(caller.clone(), 0);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection_id);
+
+		assert_eq!(CollectionBaseURI::<T>::get(collection_id), None);
+		assert_eq!(CollectionDepositOf::<T>::get(collection_id), None);
+	}
+
+	#[benchmark]
+	fn freeze_metadata() {
+		let caller: T::AccountId = whitelisted_caller();
+		let collection_id = create_collection_with_uri_len::<T>(caller.clone(), 0);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection_id);
+
+		assert!(CollectionMetadataFrozen::<T>::get(collection_id));
+	}
+
+	#[benchmark]
+	fn make_soulbound() {
+		let caller: T::AccountId = whitelisted_caller();
+		let collection_id = create_collection_with_uri_len::<T>(caller.clone(), 0);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection_id);
+
+		assert!(SoulboundCollection::<T>::get(collection_id));
+	}
+
+	#[benchmark]
+	fn pause() {
+		let caller: T::AccountId = whitelisted_caller();
+		let collection_id = create_collection_with_uri_len::<T>(caller.clone(), 0);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection_id);
+
+		assert!(PausedCollection::<T>::get(collection_id));
+	}
+
+	#[benchmark]
+	fn unpause() {
+		let caller: T::AccountId = whitelisted_caller();
+		let collection_id = create_collection_with_uri_len::<T>(caller.clone(), 0);
+		PausedCollection::<T>::insert(collection_id, true);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection_id);
+
+		assert!(!PausedCollection::<T>::get(collection_id));
+	}
+
+	#[benchmark]
+	fn grant_role() {
+		// an admin other than the owner, so that its granted role is read too
+		let caller: T::AccountId = whitelisted_caller();
+		let account: T::AccountId = account("account", 0, 0);
+		let collection_id = create_collection_with_uri_len::<T>(account("owner", 0, 0), 0);
+		CollectionRoles::<T>::insert((collection_id, CollectionRole::Admin, &caller), true);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection_id, CollectionRole::Pauser, account.clone());
+
+		assert!(CollectionRoles::<T>::get((collection_id, CollectionRole::Pauser, account)));
+	}
+
+	#[benchmark]
+	fn revoke_role() {
+		let caller: T::AccountId = whitelisted_caller();
+		let account: T::AccountId = account("account", 0, 0);
+		let collection_id = create_collection_with_uri_len::<T>(account("owner", 0, 0), 0);
+		CollectionRoles::<T>::insert((collection_id, CollectionRole::Admin, &caller), true);
+		CollectionRoles::<T>::insert((collection_id, CollectionRole::Pauser, &account), true);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection_id, CollectionRole::Pauser, account.clone());
+
+		assert!(!CollectionRoles::<T>::get((collection_id, CollectionRole::Pauser, account)));
+	}
+
+	#[benchmark]
+	fn renounce_role() {
+		let caller: T::AccountId = whitelisted_caller();
+		let collection_id = create_collection_with_uri_len::<T>(account("owner", 0, 0), 0);
+		CollectionRoles::<T>::insert((collection_id, CollectionRole::Pauser, &caller), true);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), collection_id, CollectionRole::Pauser);
+
+		assert!(!CollectionRoles::<T>::get((collection_id, CollectionRole::Pauser, caller)));
+	}
+
+	#[benchmark]
+	fn set_default_royalty() {
+		let caller: T::AccountId = whitelisted_caller();
+		let collection_id = create_collection_with_uri_len::<T>(caller.clone(), 0);
+		let receiver = T::AccountIdToH160::convert(caller.clone());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection_id, receiver, ROYALTY_DENOMINATOR);
+
+		assert_eq!(
+			CollectionRoyalty::<T>::get(collection_id),
+			Some((receiver, ROYALTY_DENOMINATOR))
+		);
+	}
+
+	#[benchmark]
+	fn set_token_royalty() {
+		let caller: T::AccountId = whitelisted_caller();
+		let collection_id = create_collection_with_uri_len::<T>(caller.clone(), 0);
+		let asset_id = U256::from(1);
+		let receiver = T::AccountIdToH160::convert(caller.clone());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection_id, asset_id, receiver, ROYALTY_DENOMINATOR);
+
+		assert_eq!(
+			AssetRoyalty::<T>::get(collection_id, asset_id),
+			Some((receiver, ROYALTY_DENOMINATOR))
+		);
+	}
+
+	#[benchmark]
+	fn owner_of() {
+		let owner: T::AccountId = account("owner", 0, 0);
+		let collection_id = create_collection_with_uri_len::<T>(owner.clone(), 0);
+		let asset_id = U256::from(1);
+		AssetOwner::<T>::insert(
+			collection_id,
+			asset_id,
+			T::AccountIdToH160::convert(owner.clone()),
+		);
+
+		let result;
+		#[block]
+		{
+			result = <LivingAssetsOwnership<T> as Erc721>::owner_of(collection_id, asset_id);
+		}
+
+		assert_eq!(result, Ok(T::AccountIdToH160::convert(owner)));
+	}
+
+	#[benchmark]
+	fn token_uri(b: Linear<0, { T::BaseURILimit::get() }>) {
+		let owner: T::AccountId = account("owner", 0, 0);
+		let collection_id = create_collection_with_uri_len::<T>(owner, b);
+		let asset_id = U256::MAX;
+		// an asset with its own token URI reads it on top of the base URI
+		AssetTokenURI::<T>::insert(collection_id, asset_id, base_uri_of_len::<T>(b));
+
+		let result;
+		#[block]
+		{
+			result = <LivingAssetsOwnership<T> as Erc721>::token_uri(collection_id, asset_id);
+		}
+
+		assert_eq!(result.map(|token_uri| token_uri.len() as u32), Ok(b));
+	}
+
+	#[benchmark]
+	fn base_uri(b: Linear<0, { T::BaseURILimit::get() }>) {
+		let owner: T::AccountId = account("owner", 0, 0);
+		let collection_id = create_collection_with_uri_len::<T>(owner, b);
+
+		let result;
+		#[block]
+		{
+			result = <LivingAssetsOwnership<T> as CollectionManager>::base_uri(collection_id);
+		}
+
+		assert_eq!(result.map(|base_uri| base_uri.len() as u32), Some(b));
+	}
+
+	#[benchmark]
+	fn approve() {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner: T::AccountId = account("owner", 0, 0);
+		let approved: T::AccountId = account("approved", 0, 0);
+		let collection_id = create_collection_with_uri_len::<T>(owner.clone(), 0);
+		let asset_id = U256::from(1);
+		AssetOwner::<T>::insert(
+			collection_id,
+			asset_id,
+			T::AccountIdToH160::convert(owner.clone()),
+		);
+		let caller = T::AccountIdToH160::convert(caller);
+		let approved = T::AccountIdToH160::convert(approved);
+		// the caller is checked as an operator last, which is the most expensive path
+		OperatorApproval::<T>::insert(
+			(collection_id, T::AccountIdToH160::convert(owner), caller),
+			true,
+		);
+
+		let result;
+		#[block]
+		{
+			result = <LivingAssetsOwnership<T> as Erc721>::approve(
+				caller,
+				collection_id,
+				approved,
+				asset_id,
+			);
+		}
+
+		assert!(result.is_ok());
+		assert_eq!(AssetApproval::<T>::get(collection_id, asset_id), Some(approved));
+	}
+
+	#[benchmark]
+	fn get_approved() {
+		let owner: T::AccountId = account("owner", 0, 0);
+		let approved = T::AccountIdToH160::convert(account("approved", 0, 0));
+		let collection_id = create_collection_with_uri_len::<T>(owner, 0);
+		let asset_id = U256::from(1);
+		AssetApproval::<T>::insert(collection_id, asset_id, approved);
+
+		let result;
+		#[block]
+		{
+			result = <LivingAssetsOwnership<T> as Erc721>::get_approved(collection_id, asset_id);
+		}
+
+		assert_eq!(result, Ok(approved));
+	}
+
+	#[benchmark]
+	fn set_approval_for_all() {
+		let owner: T::AccountId = account("owner", 0, 0);
+		let operator = T::AccountIdToH160::convert(account("operator", 0, 0));
+		let collection_id = create_collection_with_uri_len::<T>(owner.clone(), 0);
+		let owner = T::AccountIdToH160::convert(owner);
+
+		let result;
+		#[block]
+		{
+			result = <LivingAssetsOwnership<T> as Erc721>::set_approval_for_all(
+				owner,
+				collection_id,
+				operator,
+				true,
+			);
+		}
+
+		assert!(result.is_ok());
+		assert!(OperatorApproval::<T>::get((collection_id, owner, operator)));
+	}
+
+	#[benchmark]
+	fn is_approved_for_all() {
+		let owner: T::AccountId = account("owner", 0, 0);
+		let operator = T::AccountIdToH160::convert(account("operator", 0, 0));
+		let collection_id = create_collection_with_uri_len::<T>(owner.clone(), 0);
+		let owner = T::AccountIdToH160::convert(owner);
+		OperatorApproval::<T>::insert((collection_id, owner, operator), true);
+
+		let result;
+		#[block]
+		{
+			result = <LivingAssetsOwnership<T> as Erc721>::is_approved_for_all(
+				collection_id,
+				owner,
+				operator,
+			);
+		}
+
+		assert_eq!(result, Ok(true));
+	}
+
+	#[benchmark]
+	fn locked() {
+		let owner: T::AccountId = account("owner", 0, 0);
+		let collection_id = create_collection_with_uri_len::<T>(owner, 0);
+		SoulboundCollection::<T>::insert(collection_id, true);
+
+		let result;
+		#[block]
+		{
+			result = <LivingAssetsOwnership<T> as Erc721>::locked(collection_id, U256::from(1));
+		}
+
+		assert_eq!(result, Ok(true));
+	}
+
+	#[benchmark]
+	fn is_locked() {
+		let owner: T::AccountId = account("owner", 0, 0);
+		let collection_id = create_collection_with_uri_len::<T>(owner, 0);
+		LivingAssetsOwnership::<T>::do_set_lock(*b"benchmrk", collection_id, U256::from(1))
+			.expect("the collection exists and the asset holds no lock; qed");
+
+		let result;
+		#[block]
+		{
+			result = <LivingAssetsOwnership<T> as Erc721>::is_locked(collection_id, U256::from(1));
+		}
+
+		assert_eq!(result, Ok(true));
+	}
+
+	#[benchmark]
+	fn paused() {
+		let owner: T::AccountId = account("owner", 0, 0);
+		let collection_id = create_collection_with_uri_len::<T>(owner, 0);
+		PausedCollection::<T>::insert(collection_id, true);
+
+		let result;
+		#[block]
+		{
+			result = <LivingAssetsOwnership<T> as Erc721>::paused(collection_id);
+		}
+
+		assert_eq!(result, Ok(true));
+	}
+
+	#[benchmark]
+	fn set_user() {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner: T::AccountId = account("owner", 0, 0);
+		let collection_id = create_collection_with_uri_len::<T>(owner.clone(), 0);
+		let asset_id = U256::from(1);
+		set_asset_owner::<T>(collection_id, asset_id, owner.clone());
+		let caller = T::AccountIdToH160::convert(caller);
+		// the caller is checked as an operator last, which is the most expensive path
+		OperatorApproval::<T>::insert(
+			(collection_id, T::AccountIdToH160::convert(owner), caller),
+			true,
+		);
+
+		let result;
+		#[block]
+		{
+			result = <LivingAssetsOwnership<T> as Erc721>::set_user(
+				caller,
+				collection_id,
+				asset_id,
+				user::<T>(),
+				u64::MAX,
+			);
+		}
+
+		assert!(result.is_ok());
+		assert_eq!(AssetUser::<T>::get(collection_id, asset_id), Some((user::<T>(), u64::MAX)));
+	}
+
+	#[benchmark]
+	fn user_of() {
+		let owner: T::AccountId = account("owner", 0, 0);
+		let collection_id = create_collection_with_uri_len::<T>(owner, 0);
+		AssetUser::<T>::insert(collection_id, U256::from(1), (user::<T>(), u64::MAX));
+
+		let result;
+		#[block]
+		{
+			result = <LivingAssetsOwnership<T> as Erc721>::user_of(collection_id, U256::from(1));
+		}
+
+		assert_eq!(result, Ok(user::<T>()));
+	}
+
+	#[benchmark]
+	fn user_expires() {
+		let owner: T::AccountId = account("owner", 0, 0);
+		let collection_id = create_collection_with_uri_len::<T>(owner, 0);
+		AssetUser::<T>::insert(collection_id, U256::from(1), (user::<T>(), u64::MAX));
+
+		let result;
+		#[block]
+		{
+			result =
+				<LivingAssetsOwnership<T> as Erc721>::user_expires(collection_id, U256::from(1));
+		}
+
+		assert_eq!(result, Ok(u64::MAX));
+	}
+
+	#[benchmark]
+	fn royalty_info() {
+		let owner: T::AccountId = account("owner", 0, 0);
+		let receiver = T::AccountIdToH160::convert(owner.clone());
+		let collection_id = create_collection_with_uri_len::<T>(owner, 0);
+		// the asset has no royalty of its own, so the default one is read too
+		CollectionRoyalty::<T>::insert(collection_id, (receiver, ROYALTY_DENOMINATOR));
+
+		let result;
+		#[block]
+		{
+			result = <LivingAssetsOwnership<T> as Erc721>::royalty_info(
+				collection_id,
+				U256::from(1),
+				U256::MAX,
+			);
+		}
+
+		assert!(result.is_ok());
+	}
+
+	#[benchmark]
+	fn balance_of() {
+		let owner: T::AccountId = account("owner", 0, 0);
+		let collection_id = create_collection_with_uri_len::<T>(owner.clone(), 0);
+		set_asset_owner::<T>(collection_id, U256::from(1), owner.clone());
+
+		let result;
+		#[block]
+		{
+			result = <LivingAssetsOwnership<T> as Erc721>::balance_of(
+				collection_id,
+				T::AccountIdToH160::convert(owner),
+			);
+		}
+
+		assert_eq!(result, Ok(2));
+	}
+
+	#[benchmark]
+	fn token_of_owner_by_index() {
+		let owner: T::AccountId = account("owner", 0, 0);
+		let collection_id = create_collection_with_uri_len::<T>(owner.clone(), 0);
+		set_asset_owner::<T>(collection_id, U256::from(1), owner.clone());
+
+		let result;
+		#[block]
+		{
+			result = <LivingAssetsOwnership<T> as Erc721>::token_of_owner_by_index(
+				collection_id,
+				T::AccountIdToH160::convert(owner),
+				1,
+			);
+		}
+
+		assert_eq!(result, Ok(U256::from(2)));
+	}
+
+	#[benchmark]
+	fn total_supply() {
+		let owner: T::AccountId = account("owner", 0, 0);
+		let collection_id = create_collection_with_uri_len::<T>(owner, 0);
+		CollectionMintingMode::<T>::insert(
+			collection_id,
+			MintingMode::Explicit { max_supply: None },
+		);
+		CollectionTotalSupply::<T>::insert(collection_id, 2);
+
+		let result;
+		#[block]
+		{
+			result = <LivingAssetsOwnership<T> as CollectionManager>::total_supply(collection_id);
+		}
+
+		assert_eq!(result, Ok(2));
+	}
+
+	#[benchmark]
+	fn burn() {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner: T::AccountId = account("owner", 0, 0);
+		let collection_id = create_collection_with_uri_len::<T>(owner.clone(), 0);
+		let asset_id = U256::from(1);
+		set_asset_owner::<T>(collection_id, asset_id, owner.clone());
+		// a user that has not expired is removed and reported as such, the most expensive path
+		AssetUser::<T>::insert(collection_id, asset_id, (user::<T>(), u64::MAX));
+		// burning from a collection with explicit minting also updates its supply
+		CollectionMintingMode::<T>::insert(
+			collection_id,
+			MintingMode::Explicit { max_supply: None },
+		);
+		CollectionTotalSupply::<T>::insert(collection_id, 2);
+		let caller = T::AccountIdToH160::convert(caller);
+		// the caller is checked as an operator last, which is the most expensive path
+		OperatorApproval::<T>::insert(
+			(collection_id, T::AccountIdToH160::convert(owner), caller),
+			true,
+		);
+
+		let result;
+		#[block]
+		{
+			result = <LivingAssetsOwnership<T> as Erc721>::burn(caller, collection_id, asset_id);
+		}
+
+		assert!(result.is_ok());
+		assert!(BurnedAsset::<T>::get(collection_id, asset_id));
+		assert_eq!(CollectionTotalSupply::<T>::get(collection_id), 1);
+	}
+
+	#[benchmark]
+	fn mint() {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner: T::AccountId = account("owner", 0, 0);
+		let collection_id = create_collection_with_uri_len::<T>(owner, 0);
+		CollectionMintingMode::<T>::insert(
+			collection_id,
+			MintingMode::Explicit { max_supply: Some(u64::MAX) },
+		);
+		// the caller is checked as a minter after the owner, which is the most expensive path
+		CollectionRoles::<T>::insert((collection_id, CollectionRole::Minter, &caller), true);
+		let to: T::AccountId = account("to", 0, 0);
+		let asset_id = U256::from(1);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collection_id, to.clone(), asset_id);
+
+		assert_eq!(
+			AssetOwner::<T>::get(collection_id, asset_id),
+			Some(T::AccountIdToH160::convert(to))
+		);
+		assert_eq!(CollectionTotalSupply::<T>::get(collection_id), 1);
+	}
+
+	#[benchmark]
+	fn has_role() {
+		let account: T::AccountId = account("account", 0, 0);
+		let collection_id = create_collection_with_uri_len::<T>(account("owner", 0, 0), 0);
+		CollectionRoles::<T>::insert((collection_id, CollectionRole::Pauser, &account), true);
+
+		let result;
+		#[block]
+		{
+			result = <LivingAssetsOwnership<T> as CollectionManager>::has_role(
+				collection_id,
+				CollectionRole::Pauser,
+				account,
+			);
+		}
+
+		assert_eq!(result, Ok(true));
+	}
+
+	#[benchmark]
+	fn owner_of_collection() {
+		let owner: T::AccountId = account("owner", 0, 0);
+		let collection_id = create_collection_with_uri_len::<T>(owner.clone(), 0);
+
+		let result;
+		#[block]
+		{
+			result =
+				<LivingAssetsOwnership<T> as CollectionManager>::owner_of_collection(collection_id);
+		}
+
+		assert_eq!(result, Some(owner));
+	}
+
+	#[benchmark]
+	fn name() {
+		let owner: T::AccountId = account("owner", 0, 0);
+		let collection_id = create_collection_with_uri_len::<T>(owner, 0);
+		let name: Name<T> = sp_std::vec![b'a'; T::NameLimit::get() as usize]
+			.try_into()
+			.expect("len does not exceed NameLimit; qed");
+		CollectionName::<T>::insert(collection_id, name.clone());
+
+		let result;
+		#[block]
+		{
+			result = <LivingAssetsOwnership<T> as CollectionManager>::name(collection_id);
+		}
+
+		assert_eq!(result, Some(name));
+	}
+
+	#[benchmark]
+	fn symbol() {
+		let owner: T::AccountId = account("owner", 0, 0);
+		let collection_id = create_collection_with_uri_len::<T>(owner, 0);
+		let symbol: Symbol<T> = sp_std::vec![b'a'; T::SymbolLimit::get() as usize]
+			.try_into()
+			.expect("len does not exceed SymbolLimit; qed");
+		CollectionSymbol::<T>::insert(collection_id, symbol.clone());
+
+		let result;
+		#[block]
+		{
+			result = <LivingAssetsOwnership<T> as CollectionManager>::symbol(collection_id);
+		}
+
+		assert_eq!(result, Some(symbol));
+	}
+
+	impl_benchmark_test_suite!(
+		LivingAssetsOwnership,
+		crate::mock::new_test_ext(),
+		crate::mock::Test
+	);
+}
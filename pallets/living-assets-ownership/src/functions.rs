@@ -1,6 +1,14 @@
 //! Contains helper and utility functions of the pallet
 use super::*;
-use frame_support::sp_runtime::traits::One;
+use frame_support::{
+	ensure,
+	sp_runtime::traits::One,
+	storage::{with_transaction, TransactionOutcome},
+	traits::{BalanceStatus, Get, LockIdentifier, ReservableCurrency, UnixTime},
+	weights::Weight,
+};
+use sp_core::U256;
+use sp_runtime::traits::{Convert, Saturating};
 
 impl<T: Config> Pallet<T> {
 	/// See [Self::create_collection]
@@ -11,7 +19,9 @@ impl<T: Config> Pallet<T> {
 		// Retrieve the current collection count to use as the new collection's ID
 		let collection_id = Self::collection_counter();
 
+		Self::update_collection_deposit(collection_id, &who, base_uri.len())?;
 		CollectionBaseURI::<T>::insert(collection_id, base_uri);
+		CollectionOwner::<T>::insert(collection_id, who.clone());
 
 		// Attempt to increment the collection counter by 1. If this operation
 		// would result in an overflow, return early with an error
@@ -23,4 +33,664 @@ impl<T: Config> Pallet<T> {
 
 		Ok(collection_id)
 	}
+
+	/// See [Self::create_collection_with_name]
+	pub fn do_create_collection_with_name(
+		who: T::AccountId,
+		name: Name<T>,
+		symbol: Symbol<T>,
+		base_uri: BaseURI<T>,
+	) -> Result<CollectionId, Error<T>> {
+		let collection_id = Self::do_create_collection(who, base_uri)?;
+
+		CollectionName::<T>::insert(collection_id, name);
+		CollectionSymbol::<T>::insert(collection_id, symbol);
+
+		Ok(collection_id)
+	}
+
+	/// See [Self::create_collection_with_mode]
+	pub fn do_create_collection_with_mode(
+		who: T::AccountId,
+		name: Name<T>,
+		symbol: Symbol<T>,
+		base_uri: BaseURI<T>,
+		mode: MintingMode,
+	) -> Result<CollectionId, Error<T>> {
+		let collection_id = Self::do_create_collection_with_name(who, name, symbol, base_uri)?;
+
+		// implicit collections keep no entry, like those created before minting modes existed
+		if mode != MintingMode::Implicit {
+			CollectionMintingMode::<T>::insert(collection_id, mode);
+		}
+
+		Ok(collection_id)
+	}
+
+	/// See [Self::mint] and [traits::CollectionManager::mint]
+	pub fn do_mint(
+		who: T::AccountId,
+		collection_id: CollectionId,
+		to: H160,
+		asset_id: U256,
+	) -> Result<(), Error<T>> {
+		Pallet::<T>::collection_base_uri(collection_id).ok_or(Error::CollectionDoesNotExist)?;
+		let MintingMode::Explicit { max_supply } = CollectionMintingMode::<T>::get(collection_id)
+		else {
+			return Err(Error::CollectionIsImplicit);
+		};
+		Self::ensure_collection_role(&who, collection_id, CollectionRole::Minter)?;
+		ensure!(!Self::is_paused(collection_id), Error::CollectionIsPaused);
+		ensure!(to != H160::zero(), Error::TransferToNullAddress);
+		ensure!(!migrations::v1::is_migrating::<T>(), Error::MigrationInProgress);
+		// burned ids stay burned, as they do in implicit collections
+		ensure!(
+			!BurnedAsset::<T>::get(collection_id, asset_id) &&
+				!AssetOwner::<T>::contains_key(collection_id, asset_id),
+			Error::AssetAlreadyExists
+		);
+		let supply = CollectionTotalSupply::<T>::get(collection_id);
+		ensure!(max_supply.map_or(true, |max| supply < max), Error::MaxSupplyReached);
+
+		Self::add_asset_to_owner(collection_id, to, asset_id);
+		AssetOwner::<T>::insert(collection_id, asset_id, to);
+		CollectionTotalSupply::<T>::insert(collection_id, supply + 1);
+		Self::deposit_event(Event::AssetMinted {
+			collection_id,
+			asset_id,
+			owner: T::H160ToAccountId::convert(to),
+		});
+
+		Ok(())
+	}
+
+	/// See [Self::transfer_collection_ownership] and [Self::renounce_collection_ownership]
+	pub fn do_transfer_collection_ownership(
+		who: T::AccountId,
+		collection_id: CollectionId,
+		new_owner: Option<T::AccountId>,
+	) -> Result<(), Error<T>> {
+		Self::ensure_collection_owner(&who, collection_id)?;
+
+		// the deposit goes with the ownership, and is released along with it
+		let deposit = CollectionDepositOf::<T>::get(collection_id);
+		match &new_owner {
+			Some(new_owner) => {
+				if let Some((depositor, deposit)) = deposit {
+					// a new owner that does not exist can not hold it, so it stays with the
+					// depositor until the owner changes the base URI or the collection goes
+					if let Ok(unmoved) = T::Currency::repatriate_reserved(
+						&depositor,
+						new_owner,
+						deposit,
+						BalanceStatus::Reserved,
+					) {
+						// whatever the depositor no longer had reserved is not owed
+						T::Currency::unreserve(&depositor, unmoved);
+						CollectionDepositOf::<T>::insert(
+							collection_id,
+							(new_owner, deposit.saturating_sub(unmoved)),
+						);
+					}
+				}
+				CollectionOwner::<T>::insert(collection_id, new_owner);
+			},
+			None => {
+				if let Some((depositor, deposit)) = deposit {
+					T::Currency::unreserve(&depositor, deposit);
+					CollectionDepositOf::<T>::remove(collection_id);
+				}
+				CollectionOwner::<T>::remove(collection_id);
+			},
+		}
+
+		Self::deposit_event(Event::CollectionOwnershipTransferred {
+			collection_id,
+			previous_owner: who,
+			new_owner,
+		});
+
+		Ok(())
+	}
+
+	/// See [Self::set_base_uri]
+	pub fn do_set_base_uri(
+		who: T::AccountId,
+		collection_id: CollectionId,
+		base_uri: BaseURI<T>,
+	) -> Result<(), Error<T>> {
+		Self::ensure_collection_role(&who, collection_id, CollectionRole::MetadataManager)?;
+		ensure!(!Self::is_metadata_frozen(collection_id), Error::<T>::MetadataIsFrozen);
+
+		// metadata managers change the base URI of the collection, not who pays for it
+		let payer = CollectionOwner::<T>::get(collection_id)
+			.or_else(|| {
+				CollectionDepositOf::<T>::get(collection_id).map(|(depositor, _)| depositor)
+			})
+			.unwrap_or(who);
+		Self::update_collection_deposit(collection_id, &payer, base_uri.len())?;
+		CollectionBaseURI::<T>::insert(collection_id, base_uri.clone());
+
+		Self::deposit_event(Event::BaseURIUpdated { collection_id, base_uri });
+
+		Ok(())
+	}
+
+	/// See [Self::set_token_uri]
+	pub fn do_set_token_uri(
+		who: T::AccountId,
+		collection_id: CollectionId,
+		asset_id: U256,
+		token_uri: BaseURI<T>,
+	) -> Result<(), Error<T>> {
+		Self::ensure_collection_role(&who, collection_id, CollectionRole::MetadataManager)?;
+		ensure!(!Self::is_metadata_frozen(collection_id), Error::<T>::MetadataIsFrozen);
+		ensure_asset_exists::<T>(collection_id, asset_id)?;
+
+		if token_uri.is_empty() {
+			AssetTokenURI::<T>::remove(collection_id, asset_id);
+		} else {
+			AssetTokenURI::<T>::insert(collection_id, asset_id, token_uri.clone());
+		}
+
+		Self::deposit_event(Event::TokenURIUpdated { collection_id, asset_id, token_uri });
+
+		Ok(())
+	}
+
+	/// See [Self::destroy_collection]
+	///
+	/// Only collections without explicitly owned assets, token URIs, token royalties nor locked
+	/// assets can be destroyed. Anyone can approve operators of the assets they own implicitly, so
+	/// the roles, operators, approvals, users and burned assets of the collection are not bounded:
+	/// they are removed afterwards, as blocks have weight to spare, see
+	/// [Self::clear_destroyed_collections]. They are unreachable meanwhile, as collection ids are
+	/// never reused.
+	pub fn do_destroy_collection(
+		who: T::AccountId,
+		collection_id: CollectionId,
+	) -> Result<(), Error<T>> {
+		Self::ensure_collection_owner(&who, collection_id)?;
+		ensure!(!Self::is_metadata_frozen(collection_id), Error::<T>::MetadataIsFrozen);
+		ensure!(
+			AssetOwner::<T>::iter_prefix(collection_id).next().is_none() &&
+				AssetTokenURI::<T>::iter_prefix(collection_id).next().is_none() &&
+				AssetRoyalty::<T>::iter_prefix(collection_id).next().is_none() &&
+				AssetLocks::<T>::iter_prefix(collection_id).next().is_none(),
+			Error::<T>::CollectionNotEmpty
+		);
+
+		CollectionBaseURI::<T>::remove(collection_id);
+		CollectionName::<T>::remove(collection_id);
+		CollectionSymbol::<T>::remove(collection_id);
+		CollectionRoyalty::<T>::remove(collection_id);
+		CollectionMintingMode::<T>::remove(collection_id);
+		CollectionTotalSupply::<T>::remove(collection_id);
+		CollectionOwner::<T>::remove(collection_id);
+		SoulboundCollection::<T>::remove(collection_id);
+		PausedCollection::<T>::remove(collection_id);
+		if let Some((depositor, deposit)) = CollectionDepositOf::<T>::take(collection_id) {
+			T::Currency::unreserve(&depositor, deposit);
+		}
+		DestroyedCollections::<T>::insert(collection_id, true);
+
+		Self::deposit_event(Event::CollectionDestroyed { collection_id, who });
+
+		Ok(())
+	}
+
+	/// Removes as many entries left by a destroyed collection as fit in `budget`, see
+	/// [Self::do_destroy_collection], and returns the weight used.
+	///
+	/// A single collection is handled per call, so that each prefix is cleared at most once per
+	/// block, without a cursor.
+	pub(crate) fn clear_destroyed_collections(budget: Weight) -> Weight {
+		let read = Weight::from_parts(0, migrations::MAX_READ_PROOF_SIZE);
+		let overhead = T::DbWeight::get().reads_writes(1, 1).saturating_add(read);
+		let entry = T::DbWeight::get().reads_writes(1, 1).saturating_add(read);
+		if budget.any_lt(overhead.saturating_add(entry)) {
+			return Weight::zero();
+		}
+		let Some(collection_id) = DestroyedCollections::<T>::iter_keys().next() else {
+			return T::DbWeight::get().reads(1).saturating_add(read);
+		};
+
+		let mut limit = migrations::entries_within(budget.saturating_sub(overhead), entry);
+		let mut removed: u32 = 0;
+		let mut stage = 0;
+		while stage < 5 && limit > 0 {
+			let result = match stage {
+				0 => CollectionRoles::<T>::clear_prefix((collection_id,), limit, None),
+				1 => OperatorApproval::<T>::clear_prefix((collection_id,), limit, None),
+				2 => AssetApproval::<T>::clear_prefix(collection_id, limit, None),
+				3 => AssetUser::<T>::clear_prefix(collection_id, limit, None),
+				_ => BurnedAsset::<T>::clear_prefix(collection_id, limit, None),
+			};
+			removed = removed.saturating_add(result.loops);
+			limit = limit.saturating_sub(result.loops);
+			if result.maybe_cursor.is_some() {
+				break;
+			}
+			stage += 1;
+		}
+		if stage == 5 {
+			DestroyedCollections::<T>::remove(collection_id);
+		}
+
+		overhead.saturating_add(entry.saturating_mul(removed.into()))
+	}
+
+	/// See [Self::freeze_metadata]
+	pub fn do_freeze_metadata(
+		who: T::AccountId,
+		collection_id: CollectionId,
+	) -> Result<(), Error<T>> {
+		Self::ensure_collection_role(&who, collection_id, CollectionRole::MetadataManager)?;
+		ensure!(!Self::is_metadata_frozen(collection_id), Error::<T>::MetadataIsFrozen);
+
+		CollectionMetadataFrozen::<T>::insert(collection_id, true);
+
+		Self::deposit_event(Event::MetadataFrozen { collection_id });
+
+		Ok(())
+	}
+
+	/// See [Self::make_soulbound]
+	pub fn do_make_soulbound(
+		who: T::AccountId,
+		collection_id: CollectionId,
+	) -> Result<(), Error<T>> {
+		Self::ensure_collection_role(&who, collection_id, CollectionRole::Admin)?;
+		ensure!(!Self::is_soulbound(collection_id), Error::<T>::CollectionIsSoulbound);
+
+		SoulboundCollection::<T>::insert(collection_id, true);
+
+		Self::deposit_event(Event::CollectionMadeSoulbound { collection_id });
+
+		Ok(())
+	}
+
+	/// See [Self::pause] and [Self::unpause]
+	///
+	/// `who` is `None` for Root, which can pause and unpause any existing collection.
+	pub fn do_set_paused(
+		who: Option<T::AccountId>,
+		collection_id: CollectionId,
+		paused: bool,
+	) -> Result<(), Error<T>> {
+		match &who {
+			Some(who) => Self::ensure_collection_role(who, collection_id, CollectionRole::Pauser)?,
+			None => ensure!(
+				CollectionBaseURI::<T>::contains_key(collection_id),
+				Error::<T>::CollectionDoesNotExist
+			),
+		}
+
+		if paused {
+			ensure!(!Self::is_paused(collection_id), Error::<T>::CollectionIsPaused);
+			PausedCollection::<T>::insert(collection_id, true);
+			Self::deposit_event(Event::CollectionPaused { collection_id, who });
+		} else {
+			ensure!(Self::is_paused(collection_id), Error::<T>::CollectionNotPaused);
+			PausedCollection::<T>::remove(collection_id);
+			Self::deposit_event(Event::CollectionUnpaused { collection_id, who });
+		}
+
+		Ok(())
+	}
+
+	/// See [Self::grant_role]
+	///
+	/// Returns whether the role was granted, which it is not if `account` already holds it.
+	pub fn do_grant_role(
+		who: T::AccountId,
+		collection_id: CollectionId,
+		role: CollectionRole,
+		account: T::AccountId,
+	) -> Result<bool, Error<T>> {
+		Self::ensure_collection_role(&who, collection_id, CollectionRole::Admin)?;
+		if Self::holds_role(&account, collection_id, role) {
+			return Ok(false);
+		}
+
+		CollectionRoles::<T>::insert((collection_id, role, &account), true);
+
+		Self::deposit_event(Event::RoleGranted { collection_id, role, account, sender: who });
+
+		Ok(true)
+	}
+
+	/// See [Self::revoke_role]
+	///
+	/// Returns whether the role was revoked, which it is not if it had not been granted to
+	/// `account`.
+	pub fn do_revoke_role(
+		who: T::AccountId,
+		collection_id: CollectionId,
+		role: CollectionRole,
+		account: T::AccountId,
+	) -> Result<bool, Error<T>> {
+		Self::ensure_collection_role(&who, collection_id, CollectionRole::Admin)?;
+
+		Ok(Self::remove_role(collection_id, role, account, who))
+	}
+
+	/// See [Self::renounce_role]
+	///
+	/// Returns whether the role was renounced, which it is not if it had not been granted to
+	/// `who`.
+	pub fn do_renounce_role(
+		who: T::AccountId,
+		collection_id: CollectionId,
+		role: CollectionRole,
+	) -> Result<bool, Error<T>> {
+		ensure!(
+			CollectionBaseURI::<T>::contains_key(collection_id),
+			Error::<T>::CollectionDoesNotExist
+		);
+
+		Ok(Self::remove_role(collection_id, role, who.clone(), who))
+	}
+
+	/// Removes `role` from the roles granted to `account`, returning whether it held it.
+	fn remove_role(
+		collection_id: CollectionId,
+		role: CollectionRole,
+		account: T::AccountId,
+		sender: T::AccountId,
+	) -> bool {
+		if !CollectionRoles::<T>::take((collection_id, role, &account)) {
+			return false;
+		}
+
+		Self::deposit_event(Event::RoleRevoked { collection_id, role, account, sender });
+
+		true
+	}
+
+	/// See [Self::set_default_royalty] and [Self::set_token_royalty]
+	///
+	/// Sets the royalty of the asset if `asset_id` is given, otherwise the default royalty of the
+	/// collection. A zero `receiver` removes the royalty.
+	pub fn do_set_royalty(
+		who: T::AccountId,
+		collection_id: CollectionId,
+		asset_id: Option<U256>,
+		receiver: H160,
+		basis_points: u16,
+	) -> Result<(), Error<T>> {
+		Self::ensure_collection_role(&who, collection_id, CollectionRole::RoyaltyManager)?;
+		ensure!(basis_points <= ROYALTY_DENOMINATOR, Error::<T>::RoyaltyTooHigh);
+
+		let royalty = (receiver != H160::zero()).then_some((receiver, basis_points));
+		match asset_id {
+			Some(asset_id) => AssetRoyalty::<T>::set(collection_id, asset_id, royalty),
+			None => CollectionRoyalty::<T>::set(collection_id, royalty),
+		}
+
+		Self::deposit_event(Event::RoyaltyUpdated {
+			collection_id,
+			asset_id,
+			receiver,
+			basis_points,
+		});
+
+		Ok(())
+	}
+
+	/// See [Self::transfer_from] and [traits::Erc721::transfer_from]
+	///
+	/// Owners are identified by their EVM address. An asset of an implicit collection handed back
+	/// to its initial owner is removed from `AssetOwner`, as its ownership is implied again by the
+	/// asset id. `recipient` is the account `to` was given as, which the event reports.
+	pub fn do_transfer_from(
+		who: H160,
+		collection_id: CollectionId,
+		from: H160,
+		to: H160,
+		recipient: T::AccountId,
+		asset_id: U256,
+	) -> Result<(), Error<T>> {
+		Pallet::<T>::collection_base_uri(collection_id).ok_or(Error::CollectionDoesNotExist)?;
+		ensure!(!Self::is_soulbound(collection_id), Error::AssetNotTransferable);
+		ensure!(!Self::is_paused(collection_id), Error::CollectionIsPaused);
+		// the indexes of owned assets are rebuilt by the migration
+		ensure!(!migrations::v1::is_migrating::<T>(), Error::MigrationInProgress);
+		let owner = asset_owner::<T>(collection_id, asset_id)?;
+		ensure!(!AssetLocks::<T>::contains_key(collection_id, asset_id), Error::AssetIsLocked);
+		ensure!(owner == from, Error::NoPermission);
+		ensure!(
+			Self::is_approved_or_owner(who, collection_id, asset_id, owner),
+			Error::NoPermission
+		);
+		ensure!(from != to, Error::CannotTransferSelf);
+		ensure!(to != H160::zero(), Error::TransferToNullAddress);
+		let to_initial_owner = to == initial_owner::<T>(asset_id) &&
+			CollectionMintingMode::<T>::get(collection_id) == MintingMode::Implicit;
+
+		// approvals and users do not survive a change of ownership
+		AssetApproval::<T>::remove(collection_id, asset_id);
+		Self::clear_user(collection_id, asset_id);
+
+		if let Some(previous_owner) = AssetOwner::<T>::get(collection_id, asset_id) {
+			Self::remove_asset_from_owner(collection_id, previous_owner, asset_id);
+		}
+		if to_initial_owner {
+			AssetOwner::<T>::remove(collection_id, asset_id);
+		} else {
+			Self::add_asset_to_owner(collection_id, to, asset_id);
+			AssetOwner::<T>::insert(collection_id, asset_id, to);
+		}
+		Self::deposit_event(Event::AssetTransferred { collection_id, asset_id, to: recipient });
+
+		Ok(())
+	}
+
+	/// See [Self::batch_transfer], each transfer being given as for [Self::do_transfer_from]
+	pub fn do_batch_transfer(
+		who: H160,
+		from: H160,
+		transfers: Vec<(CollectionId, H160, T::AccountId, U256)>,
+	) -> Result<(), Error<T>> {
+		ensure!(transfers.len() <= T::MaxBatchTransfers::get() as usize, Error::TooManyTransfers);
+
+		// either every asset is transferred or none is
+		with_transaction::<_, sp_runtime::DispatchError, _>(|| {
+			match transfers.into_iter().try_for_each(|(collection_id, to, recipient, asset_id)| {
+				Self::do_transfer_from(who, collection_id, from, to, recipient, asset_id)
+			}) {
+				Ok(()) => TransactionOutcome::Commit(Ok(Ok(()))),
+				Err(err) => TransactionOutcome::Rollback(Ok(Err(err))),
+			}
+		})
+		.unwrap_or(Err(Error::TransactionalLimitReached))
+	}
+
+	/// See [traits::AssetLockManager::set_lock]
+	pub fn do_set_lock(
+		id: LockIdentifier,
+		collection_id: CollectionId,
+		asset_id: U256,
+	) -> Result<(), Error<T>> {
+		Pallet::<T>::collection_base_uri(collection_id).ok_or(Error::CollectionDoesNotExist)?;
+		ensure_asset_exists::<T>(collection_id, asset_id)?;
+
+		let mut locks = AssetLocks::<T>::get(collection_id, asset_id);
+		if locks.contains(&id) {
+			return Ok(());
+		}
+		locks.try_push(id).map_err(|_| Error::TooManyLocks)?;
+		AssetLocks::<T>::insert(collection_id, asset_id, locks);
+		Self::deposit_event(Event::AssetLocked { collection_id, asset_id, id });
+
+		Ok(())
+	}
+
+	/// See [traits::AssetLockManager::remove_lock]
+	///
+	/// The collection and the asset are not checked, so locks can always be lifted.
+	pub fn do_remove_lock(
+		id: LockIdentifier,
+		collection_id: CollectionId,
+		asset_id: U256,
+	) -> Result<(), Error<T>> {
+		let mut locks = AssetLocks::<T>::get(collection_id, asset_id);
+		let Some(position) = locks.iter().position(|lock| *lock == id) else {
+			return Ok(());
+		};
+		locks.remove(position);
+		// an asset without locks has no entry, so `contains_key` tells whether it is locked
+		if locks.is_empty() {
+			AssetLocks::<T>::remove(collection_id, asset_id);
+		} else {
+			AssetLocks::<T>::insert(collection_id, asset_id, locks);
+		}
+		Self::deposit_event(Event::AssetUnlocked { collection_id, asset_id, id });
+
+		Ok(())
+	}
+
+	/// Returns the user of the asset, unless there is none or it has expired.
+	pub(crate) fn active_user(collection_id: CollectionId, asset_id: U256) -> Option<H160> {
+		let (user, expires) = AssetUser::<T>::get(collection_id, asset_id)?;
+		(expires >= T::UnixTime::now().as_secs()).then_some(user)
+	}
+
+	/// Removes the user of the asset. The removal is only reported if it had not expired, as an
+	/// expired user is already reported as none.
+	pub(crate) fn clear_user(collection_id: CollectionId, asset_id: U256) {
+		if Self::active_user(collection_id, asset_id).is_some() {
+			Self::deposit_event(Event::AssetUserUpdated {
+				collection_id,
+				asset_id,
+				user: H160::zero(),
+				expires: 0,
+			});
+		}
+		AssetUser::<T>::remove(collection_id, asset_id);
+	}
+
+	/// Appends `asset_id` to the explicitly owned assets of `owner`.
+	pub(crate) fn add_asset_to_owner(collection_id: CollectionId, owner: H160, asset_id: U256) {
+		let count = OwnerAssetCount::<T>::get(collection_id, owner);
+		OwnerAssetByIndex::<T>::insert((collection_id, owner, count), asset_id);
+		AssetIndexOfOwner::<T>::insert(collection_id, asset_id, count);
+		OwnerAssetCount::<T>::insert(collection_id, owner, count.saturating_add(1));
+	}
+
+	/// Removes `asset_id` from the explicitly owned assets of `owner`, moving the last one into
+	/// its index.
+	pub(crate) fn remove_asset_from_owner(
+		collection_id: CollectionId,
+		owner: H160,
+		asset_id: U256,
+	) {
+		let Some(index) = AssetIndexOfOwner::<T>::take(collection_id, asset_id) else { return };
+		let last_index = OwnerAssetCount::<T>::get(collection_id, owner).saturating_sub(1);
+
+		if index != last_index {
+			if let Some(last_asset_id) =
+				OwnerAssetByIndex::<T>::get((collection_id, owner, last_index))
+			{
+				OwnerAssetByIndex::<T>::insert((collection_id, owner, index), last_asset_id);
+				AssetIndexOfOwner::<T>::insert(collection_id, last_asset_id, index);
+			}
+		}
+		OwnerAssetByIndex::<T>::remove((collection_id, owner, last_index));
+		if last_index == 0 {
+			OwnerAssetCount::<T>::remove(collection_id, owner);
+		} else {
+			OwnerAssetCount::<T>::insert(collection_id, owner, last_index);
+		}
+	}
+
+	/// Returns the deposit held for a collection whose base URI is `base_uri_len` bytes long.
+	pub fn collection_deposit(base_uri_len: usize) -> BalanceOf<T> {
+		T::DepositPerByte::get()
+			.saturating_mul((base_uri_len as u32).into())
+			.saturating_add(T::CollectionDeposit::get())
+	}
+
+	/// Reserves from `who` the deposit of a collection whose base URI is `base_uri_len` bytes
+	/// long, releasing the one held so far. `who` becomes the depositor of the collection.
+	fn update_collection_deposit(
+		collection_id: CollectionId,
+		who: &T::AccountId,
+		base_uri_len: usize,
+	) -> Result<(), Error<T>> {
+		let deposit = Self::collection_deposit(base_uri_len);
+
+		match CollectionDepositOf::<T>::get(collection_id) {
+			Some((depositor, old_deposit)) if &depositor == who => {
+				if deposit > old_deposit {
+					T::Currency::reserve(who, deposit - old_deposit)
+						.map_err(|_| Error::<T>::InsufficientBalance)?;
+				} else {
+					T::Currency::unreserve(who, old_deposit - deposit);
+				}
+			},
+			previous => {
+				T::Currency::reserve(who, deposit).map_err(|_| Error::<T>::InsufficientBalance)?;
+				if let Some((depositor, old_deposit)) = previous {
+					T::Currency::unreserve(&depositor, old_deposit);
+				}
+			},
+		}
+
+		CollectionDepositOf::<T>::insert(collection_id, (who.clone(), deposit));
+		Ok(())
+	}
+
+	/// Checks that the collection exists and that `who` is its owner.
+	pub(crate) fn ensure_collection_owner(
+		who: &T::AccountId,
+		collection_id: CollectionId,
+	) -> Result<(), Error<T>> {
+		ensure!(
+			CollectionBaseURI::<T>::contains_key(collection_id),
+			Error::<T>::CollectionDoesNotExist
+		);
+		ensure!(
+			CollectionOwner::<T>::get(collection_id).as_ref() == Some(who),
+			Error::<T>::NoPermission
+		);
+		Ok(())
+	}
+
+	/// Checks that the collection exists and that `who` is its owner or has been granted `role`.
+	pub(crate) fn ensure_collection_role(
+		who: &T::AccountId,
+		collection_id: CollectionId,
+		role: CollectionRole,
+	) -> Result<(), Error<T>> {
+		ensure!(
+			CollectionBaseURI::<T>::contains_key(collection_id),
+			Error::<T>::CollectionDoesNotExist
+		);
+		ensure!(Self::holds_role(who, collection_id, role), Error::<T>::NoPermission);
+		Ok(())
+	}
+
+	/// Returns `true` if `who` is the owner of the collection or has been granted `role` on it.
+	pub(crate) fn holds_role(
+		who: &T::AccountId,
+		collection_id: CollectionId,
+		role: CollectionRole,
+	) -> bool {
+		CollectionOwner::<T>::get(collection_id).as_ref() == Some(who) ||
+			CollectionRoles::<T>::get((collection_id, role, who))
+	}
+
+	/// Returns `true` if `spender` is the `owner` of the asset, the address approved for it
+	/// or an operator approved by the `owner` for the whole collection.
+	pub(crate) fn is_approved_or_owner(
+		spender: H160,
+		collection_id: CollectionId,
+		asset_id: U256,
+		owner: H160,
+	) -> bool {
+		spender == owner ||
+			AssetApproval::<T>::get(collection_id, asset_id) == Some(spender) ||
+			OperatorApproval::<T>::get((collection_id, owner, spender))
+	}
 }
@@ -8,7 +8,13 @@ use parity_scale_codec::alloc::string::ToString;
 use sp_core::H160;
 use sp_std::vec::Vec;
 mod functions;
+pub mod migrations;
 pub mod traits;
+pub mod weights;
+pub use weights::WeightInfo;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -16,6 +22,7 @@ pub mod pallet {
 	use super::*;
 	use frame_support::{
 		pallet_prelude::{OptionQuery, ValueQuery, *},
+		traits::{Currency, LockIdentifier, ReservableCurrency, UnixTime},
 		BoundedVec,
 	};
 	use frame_system::pallet_prelude::*;
@@ -28,7 +35,59 @@ pub mod pallet {
 	/// Base URI type
 	pub type BaseURI<T> = BoundedVec<u8, <T as Config>::BaseURILimit>;
 
+	/// Collection name type
+	pub type Name<T> = BoundedVec<u8, <T as Config>::NameLimit>;
+
+	/// Collection symbol type
+	pub type Symbol<T> = BoundedVec<u8, <T as Config>::SymbolLimit>;
+
+	/// Batch of transfers, each one given by its collection, receiver and asset
+	pub type BatchTransfers<T> = BoundedVec<
+		(CollectionId, <T as frame_system::Config>::AccountId, U256),
+		<T as Config>::MaxBatchTransfers,
+	>;
+
+	/// Royalty basis points are divided by this amount, so 10,000 stands for the whole sale price
+	pub const ROYALTY_DENOMINATOR: u16 = 10_000;
+
+	/// Roles that can be granted on a collection. Its owner holds all of them implicitly.
+	#[derive(Clone, Copy, Encode, Decode, MaxEncodedLen, TypeInfo, PartialEq, Eq, RuntimeDebug)]
+	pub enum CollectionRole {
+		/// Grants and revokes the roles of the collection and makes it soulbound
+		Admin,
+		/// Sets the base URI and token URIs of the collection and freezes them
+		MetadataManager,
+		/// Pauses and unpauses the collection
+		Pauser,
+		/// Sets the default and token royalties of the collection
+		RoyaltyManager,
+		/// Mints the assets of a collection with explicit minting
+		Minter,
+	}
+
+	/// How the assets of a collection come into existence, chosen when it is created.
+	#[derive(
+		Clone, Copy, Default, Encode, Decode, MaxEncodedLen, TypeInfo, PartialEq, Eq, RuntimeDebug,
+	)]
+	pub enum MintingMode {
+		/// Every asset exists and is owned by the initial owner encoded in its id until it is
+		/// transferred
+		#[default]
+		Implicit,
+		/// Assets only exist once minted by a minter, up to `max_supply` of them at a time if
+		/// given
+		Explicit { max_supply: Option<u64> },
+	}
+
+	/// Balance type of the currency in which deposits are reserved
+	pub type BalanceOf<T> =
+		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+	/// The in-code storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	/// Configure the pallet by specifying the parameters and types on which it depends.
@@ -48,6 +107,14 @@ pub mod pallet {
 		#[pallet::constant]
 		type BaseURILimit: Get<u32>;
 
+		/// The maximum length of the name of a collection.
+		#[pallet::constant]
+		type NameLimit: Get<u32>;
+
+		/// The maximum length of the symbol of a collection.
+		#[pallet::constant]
+		type SymbolLimit: Get<u32>;
+
 		/// This associated type defines a conversion from the `AccountId` type, which is internal
 		/// to the implementing type (represented by `Self`), to an `H160` type. The `H160` type
 		/// is commonly used to represent Ethereum addresses.
@@ -61,6 +128,37 @@ pub mod pallet {
 		/// Type alias for implementing the `AssetIdToInitialOwner` trait for a given account ID type.
 		/// This allows you to specify which account should initially own each new asset.
 		type AssetIdToInitialOwner: Convert<U256, Self::AccountId>;
+
+		/// Weight information for extrinsics and precompile calls in this pallet.
+		type WeightInfo: WeightInfo;
+
+		/// The currency in which collection deposits are reserved.
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// The base deposit reserved for holding a collection in storage, which also covers its
+		/// name and symbol.
+		#[pallet::constant]
+		type CollectionDeposit: Get<BalanceOf<Self>>;
+
+		/// The additional deposit reserved per byte of the base URI of a collection.
+		#[pallet::constant]
+		type DepositPerByte: Get<BalanceOf<Self>>;
+
+		/// The maximum number of assets moved by a single batch transfer.
+		#[pallet::constant]
+		type MaxBatchTransfers: Get<u32>;
+
+		/// The maximum number of locks other pallets can place on a single asset.
+		#[pallet::constant]
+		type MaxLocksPerAsset: Get<u32>;
+
+		/// Source of the current time, against which the expiry of the users of the assets is
+		/// checked.
+		type UnixTime: UnixTime;
+
+		/// Migrations of the runtime run a few entries per block alongside the ones of the
+		/// pallet, such as [`migrations::MigrateAddressMapping`], or `()` if there are none.
+		type SteppedMigrations: migrations::SteppedMigration;
 	}
 
 	/// Collection counter
@@ -74,7 +172,93 @@ pub mod pallet {
 	pub(super) type CollectionBaseURI<T: Config> =
 		StorageMap<_, Blake2_128Concat, CollectionId, BaseURI<T>, OptionQuery>;
 
-	/// Asset owner
+	/// Collection name, empty if it has none
+	#[pallet::storage]
+	#[pallet::getter(fn collection_name)]
+	pub(super) type CollectionName<T: Config> =
+		StorageMap<_, Blake2_128Concat, CollectionId, Name<T>, ValueQuery>;
+
+	/// Collection symbol, empty if it has none
+	#[pallet::storage]
+	#[pallet::getter(fn collection_symbol)]
+	pub(super) type CollectionSymbol<T: Config> =
+		StorageMap<_, Blake2_128Concat, CollectionId, Symbol<T>, ValueQuery>;
+
+	/// Collection owner
+	#[pallet::storage]
+	#[pallet::getter(fn collection_owner)]
+	pub(super) type CollectionOwner<T: Config> =
+		StorageMap<_, Blake2_128Concat, CollectionId, T::AccountId, OptionQuery>;
+
+	/// Account that reserved the deposit of a collection and the amount reserved
+	#[pallet::storage]
+	#[pallet::getter(fn collection_deposit_of)]
+	pub(super) type CollectionDepositOf<T: Config> =
+		StorageMap<_, Blake2_128Concat, CollectionId, (T::AccountId, BalanceOf<T>), OptionQuery>;
+
+	/// Collections whose base URI and token URIs can no longer be changed
+	#[pallet::storage]
+	#[pallet::getter(fn is_metadata_frozen)]
+	pub(super) type CollectionMetadataFrozen<T: Config> =
+		StorageMap<_, Blake2_128Concat, CollectionId, bool, ValueQuery>;
+
+	/// Collections whose assets can not be transferred
+	#[pallet::storage]
+	#[pallet::getter(fn is_soulbound)]
+	pub(super) type SoulboundCollection<T: Config> =
+		StorageMap<_, Blake2_128Concat, CollectionId, bool, ValueQuery>;
+
+	/// Collections whose assets can not be transferred nor burned until they are unpaused
+	#[pallet::storage]
+	#[pallet::getter(fn is_paused)]
+	pub(super) type PausedCollection<T: Config> =
+		StorageMap<_, Blake2_128Concat, CollectionId, bool, ValueQuery>;
+
+	/// Minting mode of a collection, implicit unless chosen otherwise at creation
+	#[pallet::storage]
+	#[pallet::getter(fn minting_mode)]
+	pub(super) type CollectionMintingMode<T: Config> =
+		StorageMap<_, Blake2_128Concat, CollectionId, MintingMode, ValueQuery>;
+
+	/// Number of minted assets of a collection with explicit minting that have not been burned
+	#[pallet::storage]
+	pub(super) type CollectionTotalSupply<T: Config> =
+		StorageMap<_, Blake2_128Concat, CollectionId, u64, ValueQuery>;
+
+	/// Default royalty receiver and basis points of the assets of a collection
+	#[pallet::storage]
+	#[pallet::getter(fn collection_royalty)]
+	pub(super) type CollectionRoyalty<T: Config> =
+		StorageMap<_, Blake2_128Concat, CollectionId, (H160, u16), OptionQuery>;
+
+	/// Royalty receiver and basis points of an asset, overriding the default one of the collection
+	#[pallet::storage]
+	#[pallet::getter(fn asset_royalty)]
+	pub(super) type AssetRoyalty<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CollectionId,
+		Blake2_128Concat,
+		U256,
+		(H160, u16),
+		OptionQuery,
+	>;
+
+	/// Token URI of an asset, overriding the one derived from the collection base URI
+	#[pallet::storage]
+	#[pallet::getter(fn asset_token_uri)]
+	pub(super) type AssetTokenURI<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CollectionId,
+		Blake2_128Concat,
+		U256,
+		BaseURI<T>,
+		OptionQuery,
+	>;
+
+	/// Address owning an asset, only kept while it differs from the initial owner given by
+	/// `AssetIdToInitialOwner`
 	#[pallet::storage]
 	pub(super) type AssetOwner<T: Config> = StorageDoubleMap<
 		_,
@@ -82,13 +266,210 @@ pub mod pallet {
 		CollectionId,
 		Blake2_128Concat,
 		U256,
-		T::AccountId,
+		H160,
+		OptionQuery,
+	>;
+
+	/// Number of explicitly owned assets of an address in a collection, that is, the assets it
+	/// holds in `AssetOwner`. Assets owned by their initial owner are not counted.
+	#[pallet::storage]
+	pub(super) type OwnerAssetCount<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CollectionId,
+		Blake2_128Concat,
+		H160,
+		u32,
+		ValueQuery,
+	>;
+
+	/// Explicitly owned assets of an address in a collection, by index in `[0, OwnerAssetCount)`
+	#[pallet::storage]
+	pub(super) type OwnerAssetByIndex<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, CollectionId>,
+			NMapKey<Blake2_128Concat, H160>,
+			NMapKey<Blake2_128Concat, u32>,
+		),
+		U256,
+		OptionQuery,
+	>;
+
+	/// Index of an explicitly owned asset in `OwnerAssetByIndex`
+	#[pallet::storage]
+	pub(super) type AssetIndexOfOwner<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CollectionId,
+		Blake2_128Concat,
+		U256,
+		u32,
+		OptionQuery,
+	>;
+
+	/// Approved address of an asset
+	#[pallet::storage]
+	pub(super) type AssetApproval<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CollectionId,
+		Blake2_128Concat,
+		U256,
+		H160,
+		OptionQuery,
+	>;
+
+	/// Operators approved by an owner to manage all of its assets in a collection
+	#[pallet::storage]
+	pub(super) type OperatorApproval<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, CollectionId>,
+			NMapKey<Blake2_128Concat, H160>,
+			NMapKey<Blake2_128Concat, H160>,
+		),
+		bool,
+		ValueQuery,
+	>;
+
+	/// Roles granted on a collection, on top of the ones its owner holds implicitly
+	#[pallet::storage]
+	pub(super) type CollectionRoles<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, CollectionId>,
+			NMapKey<Blake2_128Concat, CollectionRole>,
+			NMapKey<Blake2_128Concat, T::AccountId>,
+		),
+		bool,
+		ValueQuery,
+	>;
+
+	/// Assets that have been burned, so they are not handed back to their initial owner
+	#[pallet::storage]
+	pub(super) type BurnedAsset<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CollectionId,
+		Blake2_128Concat,
+		U256,
+		bool,
+		ValueQuery,
+	>;
+
+	/// Locks placed on an asset by other pallets, it can not be transferred nor burned while it
+	/// holds any
+	#[pallet::storage]
+	pub(super) type AssetLocks<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CollectionId,
+		Blake2_128Concat,
+		U256,
+		BoundedVec<LockIdentifier, T::MaxLocksPerAsset>,
+		ValueQuery,
+	>;
+
+	/// User of an asset, as described by ERC-4907, and the unix timestamp in seconds until which
+	/// it is its user
+	#[pallet::storage]
+	pub(super) type AssetUser<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CollectionId,
+		Blake2_128Concat,
+		U256,
+		(H160, u64),
 		OptionQuery,
 	>;
 
-	fn asset_owner<T: Config>(collection_id: CollectionId, asset_id: U256) -> T::AccountId {
-		AssetOwner::<T>::get(collection_id, asset_id)
-			.unwrap_or_else(|| T::AssetIdToInitialOwner::convert(asset_id))
+	/// Collections destroyed whose roles, operators, approvals, users and burned assets are still
+	/// being removed, a few per block
+	#[pallet::storage]
+	pub(super) type DestroyedCollections<T: Config> =
+		StorageMap<_, Blake2_128Concat, CollectionId, bool, ValueQuery>;
+
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound, frame_support::CloneNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		/// Collections to create, as `(owner, base_uri)`. They get consecutive ids starting at 0
+		/// and no deposit is reserved for them.
+		pub collections: Vec<(T::AccountId, Vec<u8>)>,
+		/// Explicit owners of assets, as `(collection_id, asset_id, owner)`. Owners are stored as
+		/// their `AccountIdToH160` address, and not at all if it is the initial owner of the asset.
+		/// Each asset can only be listed once.
+		pub asset_owners: Vec<(CollectionId, U256, T::AccountId)>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			for (collection_id, (owner, base_uri)) in self.collections.iter().enumerate() {
+				let base_uri: BaseURI<T> =
+					base_uri.clone().try_into().expect("genesis base URI exceeds BaseURILimit");
+				let collection_id = collection_id as CollectionId;
+				CollectionBaseURI::<T>::insert(collection_id, base_uri);
+				CollectionOwner::<T>::insert(collection_id, owner);
+			}
+			CollectionCounter::<T>::put(self.collections.len() as CollectionId);
+
+			let mut assets = sp_std::collections::btree_set::BTreeSet::new();
+			for (collection_id, asset_id, owner) in &self.asset_owners {
+				assert!(
+					CollectionBaseURI::<T>::contains_key(collection_id),
+					"genesis asset owner of unexistent collection {}",
+					collection_id
+				);
+				// checked before skipping initial owners, which are not stored
+				assert!(
+					assets.insert((*collection_id, *asset_id)),
+					"duplicate genesis asset owner of asset {} of collection {}",
+					asset_id,
+					collection_id
+				);
+				let owner = T::AccountIdToH160::convert(owner.clone());
+				if owner == initial_owner::<T>(*asset_id) {
+					continue;
+				}
+				AssetOwner::<T>::insert(collection_id, asset_id, owner);
+				Pallet::<T>::add_asset_to_owner(*collection_id, owner, *asset_id);
+			}
+		}
+	}
+
+	/// Address of the initial owner of an asset.
+	pub(super) fn initial_owner<T: Config>(asset_id: U256) -> H160 {
+		T::AccountIdToH160::convert(T::AssetIdToInitialOwner::convert(asset_id))
+	}
+
+	pub(super) fn asset_owner<T: Config>(
+		collection_id: CollectionId,
+		asset_id: U256,
+	) -> Result<H160, Error<T>> {
+		ensure!(!BurnedAsset::<T>::get(collection_id, asset_id), Error::AssetDoesNotExist);
+		let owner = if migrations::v1::is_migrating::<T>() {
+			migrations::v1::stored_owner::<T>(collection_id, asset_id)
+		} else {
+			AssetOwner::<T>::get(collection_id, asset_id)
+		};
+		match owner {
+			Some(owner) => Ok(owner),
+			// unminted assets of collections with explicit minting have no owner
+			None if CollectionMintingMode::<T>::get(collection_id) != MintingMode::Implicit => {
+				Err(Error::AssetDoesNotExist)
+			},
+			None => Ok(initial_owner::<T>(asset_id)),
+		}
+	}
+
+	/// Fails unless the asset exists, that is, it has not been burned and it has been minted if
+	/// its collection has explicit minting.
+	pub(super) fn ensure_asset_exists<T: Config>(
+		collection_id: CollectionId,
+		asset_id: U256,
+	) -> Result<(), Error<T>> {
+		asset_owner::<T>(collection_id, asset_id).map(|_| ())
 	}
 
 	/// Pallet events
@@ -101,6 +482,80 @@ pub mod pallet {
 		/// Asset transferred to `who`
 		/// parameters. [collection_id, asset_id, who]
 		AssetTransferred { collection_id: CollectionId, asset_id: U256, to: T::AccountId },
+		/// `approved` is allowed to transfer the asset on behalf of `owner`
+		/// parameters. [collection_id, asset_id, owner, approved]
+		AssetApproved { collection_id: CollectionId, asset_id: U256, owner: H160, approved: H160 },
+		/// `operator` is allowed (or no longer allowed) to manage all the assets of `owner`
+		/// parameters. [collection_id, owner, operator, approved]
+		ApprovalForAll { collection_id: CollectionId, owner: H160, operator: H160, approved: bool },
+		/// Ownership of the collection transferred, `None` means the ownership was renounced
+		/// parameters. [collection_id, previous_owner, new_owner]
+		CollectionOwnershipTransferred {
+			collection_id: CollectionId,
+			previous_owner: T::AccountId,
+			new_owner: Option<T::AccountId>,
+		},
+		/// Base URI of the collection updated
+		/// parameters. [collection_id, base_uri]
+		BaseURIUpdated { collection_id: CollectionId, base_uri: BaseURI<T> },
+		/// Token URI of the asset updated
+		/// parameters. [collection_id, asset_id, token_uri]
+		TokenURIUpdated { collection_id: CollectionId, asset_id: U256, token_uri: BaseURI<T> },
+		/// Asset burned, it can not be owned or transferred anymore
+		/// parameters. [collection_id, asset_id, owner]
+		AssetBurned { collection_id: CollectionId, asset_id: U256, owner: T::AccountId },
+		/// Collection destroyed, its deposit is released
+		/// parameters. [collection_id, who]
+		CollectionDestroyed { collection_id: CollectionId, who: T::AccountId },
+		/// Base URI and token URIs of the collection can no longer be changed
+		/// parameters. [collection_id]
+		MetadataFrozen { collection_id: CollectionId },
+		/// Assets of the collection can no longer be transferred
+		/// parameters. [collection_id]
+		CollectionMadeSoulbound { collection_id: CollectionId },
+		/// Royalty of the collection, or of one of its assets if `asset_id` is given, updated.
+		/// A zero `receiver` means the royalty was removed
+		/// parameters. [collection_id, asset_id, receiver, basis_points]
+		RoyaltyUpdated {
+			collection_id: CollectionId,
+			asset_id: Option<U256>,
+			receiver: H160,
+			basis_points: u16,
+		},
+		/// Lock `id` placed on the asset
+		/// parameters. [collection_id, asset_id, id]
+		AssetLocked { collection_id: CollectionId, asset_id: U256, id: LockIdentifier },
+		/// Lock `id` lifted from the asset, which may still hold other locks
+		/// parameters. [collection_id, asset_id, id]
+		AssetUnlocked { collection_id: CollectionId, asset_id: U256, id: LockIdentifier },
+		/// Transfers and burns of the assets of the collection halted, `who` is `None` for Root
+		/// parameters. [collection_id, who]
+		CollectionPaused { collection_id: CollectionId, who: Option<T::AccountId> },
+		/// Transfers and burns of the assets of the collection resumed, `who` is `None` for Root
+		/// parameters. [collection_id, who]
+		CollectionUnpaused { collection_id: CollectionId, who: Option<T::AccountId> },
+		/// User of the asset updated until `expires`. A zero `user` means it was removed
+		/// parameters. [collection_id, asset_id, user, expires]
+		AssetUserUpdated { collection_id: CollectionId, asset_id: U256, user: H160, expires: u64 },
+		/// `role` granted to `account` by `sender`
+		/// parameters. [collection_id, role, account, sender]
+		RoleGranted {
+			collection_id: CollectionId,
+			role: CollectionRole,
+			account: T::AccountId,
+			sender: T::AccountId,
+		},
+		/// `role` revoked from `account` by `sender`, who is `account` itself if renounced
+		/// parameters. [collection_id, role, account, sender]
+		RoleRevoked {
+			collection_id: CollectionId,
+			role: CollectionRole,
+			account: T::AccountId,
+			sender: T::AccountId,
+		},
+		/// Asset of a collection with explicit minting minted to `owner`
+		/// parameters. [collection_id, asset_id, owner]
+		AssetMinted { collection_id: CollectionId, asset_id: U256, owner: T::AccountId },
 	}
 
 	// Errors inform users that something went wrong.
@@ -119,6 +574,44 @@ pub mod pallet {
 		CannotTransferSelf,
 		// TransferToNullAddress,
 		TransferToNullAddress,
+		// ApprovalToCurrentOwner,
+		ApprovalToCurrentOwner,
+		// ApproveToCaller,
+		ApproveToCaller,
+		// InsufficientBalance,
+		InsufficientBalance,
+		// CollectionNotEmpty,
+		CollectionNotEmpty,
+		// MetadataIsFrozen,
+		MetadataIsFrozen,
+		// AssetNotTransferable,
+		AssetNotTransferable,
+		// CollectionIsSoulbound,
+		CollectionIsSoulbound,
+		// RoyaltyTooHigh,
+		RoyaltyTooHigh,
+		// OwnerIndexOutOfBounds,
+		OwnerIndexOutOfBounds,
+		// TooManyTransfers,
+		TooManyTransfers,
+		// AssetIsLocked,
+		AssetIsLocked,
+		// TooManyLocks,
+		TooManyLocks,
+		// CollectionIsPaused,
+		CollectionIsPaused,
+		// CollectionNotPaused,
+		CollectionNotPaused,
+		// CollectionIsImplicit,
+		CollectionIsImplicit,
+		// AssetAlreadyExists,
+		AssetAlreadyExists,
+		// MaxSupplyReached,
+		MaxSupplyReached,
+		// MigrationInProgress,
+		MigrationInProgress,
+		// TransactionalLimitReached,
+		TransactionalLimitReached,
 	}
 
 	impl<T: Config> AsRef<[u8]> for Error<T> {
@@ -131,17 +624,51 @@ pub mod pallet {
 				Error::AssetDoesNotExist => b"AssetDoesNotExist",
 				Error::CannotTransferSelf => b"CannotTransferSelf",
 				Error::TransferToNullAddress => b"TransferToNullAddress",
+				Error::ApprovalToCurrentOwner => b"ApprovalToCurrentOwner",
+				Error::ApproveToCaller => b"ApproveToCaller",
+				Error::InsufficientBalance => b"InsufficientBalance",
+				Error::CollectionNotEmpty => b"CollectionNotEmpty",
+				Error::MetadataIsFrozen => b"MetadataIsFrozen",
+				Error::AssetNotTransferable => b"AssetNotTransferable",
+				Error::CollectionIsSoulbound => b"CollectionIsSoulbound",
+				Error::RoyaltyTooHigh => b"RoyaltyTooHigh",
+				Error::OwnerIndexOutOfBounds => b"OwnerIndexOutOfBounds",
+				Error::TooManyTransfers => b"TooManyTransfers",
+				Error::AssetIsLocked => b"AssetIsLocked",
+				Error::TooManyLocks => b"TooManyLocks",
+				Error::CollectionIsPaused => b"CollectionIsPaused",
+				Error::CollectionNotPaused => b"CollectionNotPaused",
+				Error::CollectionIsImplicit => b"CollectionIsImplicit",
+				Error::AssetAlreadyExists => b"AssetAlreadyExists",
+				Error::MaxSupplyReached => b"MaxSupplyReached",
+				Error::MigrationInProgress => b"MigrationInProgress",
+				Error::TransactionalLimitReached => b"TransactionalLimitReached",
 			}
 		}
 	}
 
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+			use migrations::SteppedMigration;
+
+			let budget = migrations::BLOCK_WEIGHT_SHARE * T::BlockWeights::get().max_block;
+			let used = migrations::v1::MigrateToV1::<T>::on_initialize(budget);
+			used.saturating_add(T::SteppedMigrations::on_initialize(budget.saturating_sub(used)))
+		}
+
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::clear_destroyed_collections(remaining_weight)
+		}
+	}
+
 	// Dispatchable functions allows users to interact with the pallet and invoke state changes.
 	// These functions materialize as "extrinsics", which are often compared to transactions.
 	// Dispatchable functions must be annotated with a weight and must return a DispatchResult.
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		#[pallet::call_index(0)]
-		#[pallet::weight(10_000 + T::DbWeight::get().writes(1).ref_time())] // TODO set proper weight
+		#[pallet::weight(T::WeightInfo::create_collection(base_uri.len() as u32))]
 		pub fn create_collection(origin: OriginFor<T>, base_uri: BaseURI<T>) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
@@ -150,23 +677,472 @@ pub mod pallet {
 				Err(err) => Err(err.into()),
 			}
 		}
+
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::transfer_collection_ownership())]
+		pub fn transfer_collection_ownership(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			new_owner: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::do_transfer_collection_ownership(who, collection_id, Some(new_owner))?;
+			Ok(())
+		}
+
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::renounce_collection_ownership())]
+		pub fn renounce_collection_ownership(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::do_transfer_collection_ownership(who, collection_id, None)?;
+			Ok(())
+		}
+
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::set_base_uri(base_uri.len() as u32))]
+		pub fn set_base_uri(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			base_uri: BaseURI<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::do_set_base_uri(who, collection_id, base_uri)?;
+			Ok(())
+		}
+
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::set_token_uri(token_uri.len() as u32))]
+		pub fn set_token_uri(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			asset_id: U256,
+			token_uri: BaseURI<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::do_set_token_uri(who, collection_id, asset_id, token_uri)?;
+			Ok(())
+		}
+
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::transfer())]
+		pub fn transfer(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			to: T::AccountId,
+			asset_id: U256,
+		) -> DispatchResult {
+			let who = T::AccountIdToH160::convert(ensure_signed(origin)?);
+
+			Self::do_transfer_from(
+				who,
+				collection_id,
+				who,
+				T::AccountIdToH160::convert(to.clone()),
+				to,
+				asset_id,
+			)?;
+			Ok(())
+		}
+
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::transfer_from())]
+		pub fn transfer_from(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			from: T::AccountId,
+			to: T::AccountId,
+			asset_id: U256,
+		) -> DispatchResult {
+			let who = T::AccountIdToH160::convert(ensure_signed(origin)?);
+
+			Self::do_transfer_from(
+				who,
+				collection_id,
+				T::AccountIdToH160::convert(from),
+				T::AccountIdToH160::convert(to.clone()),
+				to,
+				asset_id,
+			)?;
+			Ok(())
+		}
+
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::destroy_collection())]
+		pub fn destroy_collection(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::do_destroy_collection(who, collection_id)?;
+			Ok(())
+		}
+
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::freeze_metadata())]
+		pub fn freeze_metadata(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::do_freeze_metadata(who, collection_id)?;
+			Ok(())
+		}
+
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::make_soulbound())]
+		pub fn make_soulbound(origin: OriginFor<T>, collection_id: CollectionId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::do_make_soulbound(who, collection_id)?;
+			Ok(())
+		}
+
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::create_collection_with_name(base_uri.len() as u32))]
+		pub fn create_collection_with_name(
+			origin: OriginFor<T>,
+			name: Name<T>,
+			symbol: Symbol<T>,
+			base_uri: BaseURI<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::do_create_collection_with_name(who, name, symbol, base_uri)?;
+			Ok(())
+		}
+
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::set_default_royalty())]
+		pub fn set_default_royalty(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			receiver: H160,
+			basis_points: u16,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::do_set_royalty(who, collection_id, None, receiver, basis_points)?;
+			Ok(())
+		}
+
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::set_token_royalty())]
+		pub fn set_token_royalty(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			asset_id: U256,
+			receiver: H160,
+			basis_points: u16,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::do_set_royalty(who, collection_id, Some(asset_id), receiver, basis_points)?;
+			Ok(())
+		}
+
+		#[pallet::call_index(13)]
+		#[pallet::weight(T::WeightInfo::batch_transfer(transfers.len() as u32))]
+		pub fn batch_transfer(
+			origin: OriginFor<T>,
+			from: T::AccountId,
+			transfers: BatchTransfers<T>,
+		) -> DispatchResult {
+			let who = T::AccountIdToH160::convert(ensure_signed(origin)?);
+
+			Self::do_batch_transfer(
+				who,
+				T::AccountIdToH160::convert(from),
+				transfers
+					.into_iter()
+					.map(|(collection_id, to, asset_id)| {
+						(collection_id, T::AccountIdToH160::convert(to.clone()), to, asset_id)
+					})
+					.collect(),
+			)?;
+			Ok(())
+		}
+
+		#[pallet::call_index(14)]
+		#[pallet::weight(T::WeightInfo::pause())]
+		pub fn pause(origin: OriginFor<T>, collection_id: CollectionId) -> DispatchResult {
+			let who = frame_system::ensure_signed_or_root(origin)?;
+
+			Self::do_set_paused(who, collection_id, true)?;
+			Ok(())
+		}
+
+		#[pallet::call_index(15)]
+		#[pallet::weight(T::WeightInfo::unpause())]
+		pub fn unpause(origin: OriginFor<T>, collection_id: CollectionId) -> DispatchResult {
+			let who = frame_system::ensure_signed_or_root(origin)?;
+
+			Self::do_set_paused(who, collection_id, false)?;
+			Ok(())
+		}
+
+		#[pallet::call_index(16)]
+		#[pallet::weight(T::WeightInfo::grant_role())]
+		pub fn grant_role(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			role: CollectionRole,
+			account: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::do_grant_role(who, collection_id, role, account)?;
+			Ok(())
+		}
+
+		#[pallet::call_index(17)]
+		#[pallet::weight(T::WeightInfo::revoke_role())]
+		pub fn revoke_role(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			role: CollectionRole,
+			account: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::do_revoke_role(who, collection_id, role, account)?;
+			Ok(())
+		}
+
+		#[pallet::call_index(18)]
+		#[pallet::weight(T::WeightInfo::renounce_role())]
+		pub fn renounce_role(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			role: CollectionRole,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::do_renounce_role(who, collection_id, role)?;
+			Ok(())
+		}
+
+		#[pallet::call_index(19)]
+		#[pallet::weight(T::WeightInfo::create_collection_with_mode(base_uri.len() as u32))]
+		pub fn create_collection_with_mode(
+			origin: OriginFor<T>,
+			name: Name<T>,
+			symbol: Symbol<T>,
+			base_uri: BaseURI<T>,
+			mode: MintingMode,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::do_create_collection_with_mode(who, name, symbol, base_uri, mode)?;
+			Ok(())
+		}
+
+		#[pallet::call_index(20)]
+		#[pallet::weight(T::WeightInfo::mint())]
+		pub fn mint(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			to: T::AccountId,
+			asset_id: U256,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::do_mint(who, collection_id, T::AccountIdToH160::convert(to), asset_id)?;
+			Ok(())
+		}
 	}
 
 	impl<T: Config> traits::CollectionManager for Pallet<T> {
 		type Error = Error<T>;
 		type AccountId = T::AccountId;
 		type BaseURI = BaseURI<T>;
+		type Name = Name<T>;
+		type Symbol = Symbol<T>;
 
 		fn base_uri(collection_id: CollectionId) -> Option<Self::BaseURI> {
 			CollectionBaseURI::<T>::get(collection_id)
 		}
 
+		fn name(collection_id: CollectionId) -> Option<Self::Name> {
+			CollectionBaseURI::<T>::contains_key(collection_id)
+				.then(|| CollectionName::<T>::get(collection_id))
+		}
+
+		fn symbol(collection_id: CollectionId) -> Option<Self::Symbol> {
+			CollectionBaseURI::<T>::contains_key(collection_id)
+				.then(|| CollectionSymbol::<T>::get(collection_id))
+		}
+
 		fn create_collection(
 			owner: T::AccountId,
 			base_uri: Self::BaseURI,
 		) -> Result<CollectionId, Self::Error> {
 			Self::do_create_collection(owner, base_uri)
 		}
+
+		fn create_collection_with_name(
+			owner: T::AccountId,
+			name: Self::Name,
+			symbol: Self::Symbol,
+			base_uri: Self::BaseURI,
+		) -> Result<CollectionId, Self::Error> {
+			Self::do_create_collection_with_name(owner, name, symbol, base_uri)
+		}
+
+		fn create_collection_with_mode(
+			owner: T::AccountId,
+			name: Self::Name,
+			symbol: Self::Symbol,
+			base_uri: Self::BaseURI,
+			mode: MintingMode,
+		) -> Result<CollectionId, Self::Error> {
+			Self::do_create_collection_with_mode(owner, name, symbol, base_uri, mode)
+		}
+
+		fn mint(
+			origin: T::AccountId,
+			collection_id: CollectionId,
+			to: H160,
+			asset_id: U256,
+		) -> Result<(), Self::Error> {
+			Self::do_mint(origin, collection_id, to, asset_id)
+		}
+
+		fn total_supply(collection_id: CollectionId) -> Result<u64, Self::Error> {
+			Pallet::<T>::collection_base_uri(collection_id).ok_or(Error::CollectionDoesNotExist)?;
+			ensure!(
+				CollectionMintingMode::<T>::get(collection_id) != MintingMode::Implicit,
+				Error::CollectionIsImplicit
+			);
+			Ok(CollectionTotalSupply::<T>::get(collection_id))
+		}
+
+		fn owner_of_collection(collection_id: CollectionId) -> Option<T::AccountId> {
+			CollectionOwner::<T>::get(collection_id)
+		}
+
+		fn transfer_collection_ownership(
+			origin: T::AccountId,
+			collection_id: CollectionId,
+			new_owner: T::AccountId,
+		) -> Result<(), Self::Error> {
+			Self::do_transfer_collection_ownership(origin, collection_id, Some(new_owner))
+		}
+
+		fn renounce_collection_ownership(
+			origin: T::AccountId,
+			collection_id: CollectionId,
+		) -> Result<(), Self::Error> {
+			Self::do_transfer_collection_ownership(origin, collection_id, None)
+		}
+
+		fn set_base_uri(
+			origin: T::AccountId,
+			collection_id: CollectionId,
+			base_uri: Self::BaseURI,
+		) -> Result<(), Self::Error> {
+			Self::do_set_base_uri(origin, collection_id, base_uri)
+		}
+
+		fn set_token_uri(
+			origin: T::AccountId,
+			collection_id: CollectionId,
+			asset_id: U256,
+			token_uri: Self::BaseURI,
+		) -> Result<(), Self::Error> {
+			Self::do_set_token_uri(origin, collection_id, asset_id, token_uri)
+		}
+
+		fn destroy_collection(
+			origin: T::AccountId,
+			collection_id: CollectionId,
+		) -> Result<(), Self::Error> {
+			Self::do_destroy_collection(origin, collection_id)
+		}
+
+		fn freeze_metadata(
+			origin: T::AccountId,
+			collection_id: CollectionId,
+		) -> Result<(), Self::Error> {
+			Self::do_freeze_metadata(origin, collection_id)
+		}
+
+		fn make_soulbound(
+			origin: T::AccountId,
+			collection_id: CollectionId,
+		) -> Result<(), Self::Error> {
+			Self::do_make_soulbound(origin, collection_id)
+		}
+
+		fn pause(origin: T::AccountId, collection_id: CollectionId) -> Result<(), Self::Error> {
+			Self::do_set_paused(Some(origin), collection_id, true)
+		}
+
+		fn unpause(origin: T::AccountId, collection_id: CollectionId) -> Result<(), Self::Error> {
+			Self::do_set_paused(Some(origin), collection_id, false)
+		}
+
+		fn set_default_royalty(
+			origin: T::AccountId,
+			collection_id: CollectionId,
+			receiver: H160,
+			basis_points: u16,
+		) -> Result<(), Self::Error> {
+			Self::do_set_royalty(origin, collection_id, None, receiver, basis_points)
+		}
+
+		fn set_token_royalty(
+			origin: T::AccountId,
+			collection_id: CollectionId,
+			asset_id: U256,
+			receiver: H160,
+			basis_points: u16,
+		) -> Result<(), Self::Error> {
+			Self::do_set_royalty(origin, collection_id, Some(asset_id), receiver, basis_points)
+		}
+
+		fn has_role(
+			collection_id: CollectionId,
+			role: CollectionRole,
+			account: T::AccountId,
+		) -> Result<bool, Self::Error> {
+			Pallet::<T>::collection_base_uri(collection_id).ok_or(Error::CollectionDoesNotExist)?;
+			Ok(Self::holds_role(&account, collection_id, role))
+		}
+
+		fn grant_role(
+			origin: T::AccountId,
+			collection_id: CollectionId,
+			role: CollectionRole,
+			account: T::AccountId,
+		) -> Result<bool, Self::Error> {
+			Self::do_grant_role(origin, collection_id, role, account)
+		}
+
+		fn revoke_role(
+			origin: T::AccountId,
+			collection_id: CollectionId,
+			role: CollectionRole,
+			account: T::AccountId,
+		) -> Result<bool, Self::Error> {
+			Self::do_revoke_role(origin, collection_id, role, account)
+		}
+
+		fn renounce_role(
+			origin: T::AccountId,
+			collection_id: CollectionId,
+			role: CollectionRole,
+		) -> Result<bool, Self::Error> {
+			Self::do_renounce_role(origin, collection_id, role)
+		}
 	}
 
 	impl<T: Config> traits::Erc721 for Pallet<T> {
@@ -174,7 +1150,7 @@ pub mod pallet {
 
 		fn owner_of(collection_id: CollectionId, asset_id: U256) -> Result<H160, Self::Error> {
 			Pallet::<T>::collection_base_uri(collection_id).ok_or(Error::CollectionDoesNotExist)?;
-			Ok(T::AccountIdToH160::convert(asset_owner::<T>(collection_id, asset_id)))
+			asset_owner::<T>(collection_id, asset_id)
 		}
 
 		fn transfer_from(
@@ -184,25 +1160,41 @@ pub mod pallet {
 			to: H160,
 			asset_id: U256,
 		) -> Result<(), Self::Error> {
-			Pallet::<T>::collection_base_uri(collection_id).ok_or(Error::CollectionDoesNotExist)?;
-			ensure!(origin == from, Error::NoPermission);
-			ensure!(
-				T::AccountIdToH160::convert(asset_owner::<T>(collection_id, asset_id)) == from,
-				Error::NoPermission
-			);
-			ensure!(from != to, Error::CannotTransferSelf);
-			ensure!(to != H160::zero(), Error::TransferToNullAddress);
-
-			let to = T::H160ToAccountId::convert(to.clone());
-			AssetOwner::<T>::set(collection_id, asset_id, Some(to.clone()));
-			Self::deposit_event(Event::AssetTransferred { collection_id, asset_id, to });
+			Self::do_transfer_from(
+				origin,
+				collection_id,
+				from,
+				to,
+				T::H160ToAccountId::convert(to),
+				asset_id,
+			)
+		}
 
-			Ok(())
+		fn batch_transfer_from(
+			origin: H160,
+			from: H160,
+			transfers: Vec<(CollectionId, H160, U256)>,
+		) -> Result<(), Self::Error> {
+			Self::do_batch_transfer(
+				origin,
+				from,
+				transfers
+					.into_iter()
+					.map(|(collection_id, to, asset_id)| {
+						(collection_id, to, T::H160ToAccountId::convert(to), asset_id)
+					})
+					.collect(),
+			)
 		}
 
 		fn token_uri(collection_id: CollectionId, asset_id: U256) -> Result<Vec<u8>, Self::Error> {
 			let base_uri = Pallet::<T>::collection_base_uri(collection_id)
 				.ok_or(Error::CollectionDoesNotExist)?;
+			ensure_asset_exists::<T>(collection_id, asset_id)?;
+
+			if let Some(token_uri) = AssetTokenURI::<T>::get(collection_id, asset_id) {
+				return Ok(token_uri.into_inner());
+			}
 
 			// concatenate base_uri with asset_id
 			let mut token_uri = base_uri.to_vec();
@@ -210,6 +1202,227 @@ pub mod pallet {
 			token_uri.extend_from_slice(asset_id.to_string().as_bytes());
 			Ok(token_uri)
 		}
+
+		fn approve(
+			origin: H160,
+			collection_id: CollectionId,
+			to: H160,
+			asset_id: U256,
+		) -> Result<(), Self::Error> {
+			Pallet::<T>::collection_base_uri(collection_id).ok_or(Error::CollectionDoesNotExist)?;
+			let owner = asset_owner::<T>(collection_id, asset_id)?;
+			ensure!(to != owner, Error::ApprovalToCurrentOwner);
+			ensure!(
+				origin == owner || OperatorApproval::<T>::get((collection_id, owner, origin)),
+				Error::NoPermission
+			);
+
+			if to == H160::zero() {
+				AssetApproval::<T>::remove(collection_id, asset_id);
+			} else {
+				AssetApproval::<T>::insert(collection_id, asset_id, to);
+			}
+			Self::deposit_event(Event::AssetApproved {
+				collection_id,
+				asset_id,
+				owner,
+				approved: to,
+			});
+
+			Ok(())
+		}
+
+		fn get_approved(collection_id: CollectionId, asset_id: U256) -> Result<H160, Self::Error> {
+			Pallet::<T>::collection_base_uri(collection_id).ok_or(Error::CollectionDoesNotExist)?;
+			ensure_asset_exists::<T>(collection_id, asset_id)?;
+			Ok(AssetApproval::<T>::get(collection_id, asset_id).unwrap_or_default())
+		}
+
+		fn set_approval_for_all(
+			origin: H160,
+			collection_id: CollectionId,
+			operator: H160,
+			approved: bool,
+		) -> Result<(), Self::Error> {
+			Pallet::<T>::collection_base_uri(collection_id).ok_or(Error::CollectionDoesNotExist)?;
+			ensure!(origin != operator, Error::ApproveToCaller);
+
+			if approved {
+				OperatorApproval::<T>::insert((collection_id, origin, operator), true);
+			} else {
+				OperatorApproval::<T>::remove((collection_id, origin, operator));
+			}
+			Self::deposit_event(Event::ApprovalForAll {
+				collection_id,
+				owner: origin,
+				operator,
+				approved,
+			});
+
+			Ok(())
+		}
+
+		fn is_approved_for_all(
+			collection_id: CollectionId,
+			owner: H160,
+			operator: H160,
+		) -> Result<bool, Self::Error> {
+			Pallet::<T>::collection_base_uri(collection_id).ok_or(Error::CollectionDoesNotExist)?;
+			Ok(OperatorApproval::<T>::get((collection_id, owner, operator)))
+		}
+
+		fn locked(collection_id: CollectionId, asset_id: U256) -> Result<bool, Self::Error> {
+			Pallet::<T>::collection_base_uri(collection_id).ok_or(Error::CollectionDoesNotExist)?;
+			ensure_asset_exists::<T>(collection_id, asset_id)?;
+			Ok(SoulboundCollection::<T>::get(collection_id))
+		}
+
+		fn is_locked(collection_id: CollectionId, asset_id: U256) -> Result<bool, Self::Error> {
+			Pallet::<T>::collection_base_uri(collection_id).ok_or(Error::CollectionDoesNotExist)?;
+			ensure_asset_exists::<T>(collection_id, asset_id)?;
+			Ok(AssetLocks::<T>::contains_key(collection_id, asset_id))
+		}
+
+		fn paused(collection_id: CollectionId) -> Result<bool, Self::Error> {
+			Pallet::<T>::collection_base_uri(collection_id).ok_or(Error::CollectionDoesNotExist)?;
+			Ok(PausedCollection::<T>::get(collection_id))
+		}
+
+		fn set_user(
+			origin: H160,
+			collection_id: CollectionId,
+			asset_id: U256,
+			user: H160,
+			expires: u64,
+		) -> Result<(), Self::Error> {
+			Pallet::<T>::collection_base_uri(collection_id).ok_or(Error::CollectionDoesNotExist)?;
+			let owner = asset_owner::<T>(collection_id, asset_id)?;
+			ensure!(
+				Self::is_approved_or_owner(origin, collection_id, asset_id, owner),
+				Error::NoPermission
+			);
+
+			if user == H160::zero() {
+				AssetUser::<T>::remove(collection_id, asset_id);
+			} else {
+				AssetUser::<T>::insert(collection_id, asset_id, (user, expires));
+			}
+			Self::deposit_event(Event::AssetUserUpdated { collection_id, asset_id, user, expires });
+
+			Ok(())
+		}
+
+		fn user_of(collection_id: CollectionId, asset_id: U256) -> Result<H160, Self::Error> {
+			Pallet::<T>::collection_base_uri(collection_id).ok_or(Error::CollectionDoesNotExist)?;
+			ensure_asset_exists::<T>(collection_id, asset_id)?;
+			Ok(Self::active_user(collection_id, asset_id).unwrap_or_default())
+		}
+
+		fn user_expires(collection_id: CollectionId, asset_id: U256) -> Result<u64, Self::Error> {
+			Pallet::<T>::collection_base_uri(collection_id).ok_or(Error::CollectionDoesNotExist)?;
+			ensure_asset_exists::<T>(collection_id, asset_id)?;
+			Ok(AssetUser::<T>::get(collection_id, asset_id).map_or(0, |(_, expires)| expires))
+		}
+
+		fn royalty_info(
+			collection_id: CollectionId,
+			asset_id: U256,
+			sale_price: U256,
+		) -> Result<(H160, U256), Self::Error> {
+			Pallet::<T>::collection_base_uri(collection_id).ok_or(Error::CollectionDoesNotExist)?;
+			match AssetRoyalty::<T>::get(collection_id, asset_id)
+				.or_else(|| CollectionRoyalty::<T>::get(collection_id))
+			{
+				Some((receiver, basis_points)) => {
+					// split the price so that `sale_price * basis_points` can not overflow
+					let denominator = U256::from(ROYALTY_DENOMINATOR);
+					let basis_points = U256::from(basis_points);
+					let royalty = sale_price / denominator * basis_points
+						+ sale_price % denominator * basis_points / denominator;
+					Ok((receiver, royalty))
+				},
+				None => Ok((H160::zero(), U256::zero())),
+			}
+		}
+
+		fn balance_of(collection_id: CollectionId, owner: H160) -> Result<u32, Self::Error> {
+			Pallet::<T>::collection_base_uri(collection_id).ok_or(Error::CollectionDoesNotExist)?;
+			ensure!(!migrations::v1::is_migrating::<T>(), Error::MigrationInProgress);
+			Ok(OwnerAssetCount::<T>::get(collection_id, owner))
+		}
+
+		fn token_of_owner_by_index(
+			collection_id: CollectionId,
+			owner: H160,
+			index: u32,
+		) -> Result<U256, Self::Error> {
+			Pallet::<T>::collection_base_uri(collection_id).ok_or(Error::CollectionDoesNotExist)?;
+			ensure!(!migrations::v1::is_migrating::<T>(), Error::MigrationInProgress);
+			OwnerAssetByIndex::<T>::get((collection_id, owner, index))
+				.ok_or(Error::OwnerIndexOutOfBounds)
+		}
+
+		fn burn(
+			origin: H160,
+			collection_id: CollectionId,
+			asset_id: U256,
+		) -> Result<(), Self::Error> {
+			Pallet::<T>::collection_base_uri(collection_id).ok_or(Error::CollectionDoesNotExist)?;
+			ensure!(!Self::is_paused(collection_id), Error::CollectionIsPaused);
+			ensure!(!migrations::v1::is_migrating::<T>(), Error::MigrationInProgress);
+			let owner = asset_owner::<T>(collection_id, asset_id)?;
+			ensure!(!AssetLocks::<T>::contains_key(collection_id, asset_id), Error::AssetIsLocked);
+			ensure!(
+				Self::is_approved_or_owner(origin, collection_id, asset_id, owner),
+				Error::NoPermission
+			);
+
+			AssetApproval::<T>::remove(collection_id, asset_id);
+			Self::clear_user(collection_id, asset_id);
+			if AssetOwner::<T>::take(collection_id, asset_id).is_some() {
+				Self::remove_asset_from_owner(collection_id, owner, asset_id);
+			}
+			AssetTokenURI::<T>::remove(collection_id, asset_id);
+			AssetRoyalty::<T>::remove(collection_id, asset_id);
+			if CollectionMintingMode::<T>::get(collection_id) != MintingMode::Implicit {
+				CollectionTotalSupply::<T>::mutate(collection_id, |supply| {
+					*supply = supply.saturating_sub(1)
+				});
+			}
+			// without a tombstone the asset would go back to its initial owner
+			BurnedAsset::<T>::insert(collection_id, asset_id, true);
+			Self::deposit_event(Event::AssetBurned {
+				collection_id,
+				asset_id,
+				owner: T::H160ToAccountId::convert(owner),
+			});
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> traits::AssetLockManager for Pallet<T> {
+		type Error = Error<T>;
+
+		fn set_lock(
+			id: LockIdentifier,
+			collection_id: CollectionId,
+			asset_id: U256,
+		) -> Result<(), Self::Error> {
+			Self::do_set_lock(id, collection_id, asset_id)
+		}
+
+		fn remove_lock(
+			id: LockIdentifier,
+			collection_id: CollectionId,
+			asset_id: U256,
+		) -> Result<(), Self::Error> {
+			Self::do_remove_lock(id, collection_id, asset_id)
+		}
+
+		fn is_locked(collection_id: CollectionId, asset_id: U256) -> bool {
+			AssetLocks::<T>::contains_key(collection_id, asset_id)
+		}
 	}
 }
 
@@ -0,0 +1,566 @@
+//! Storage migrations of the pallet
+use super::*;
+use frame_support::{
+	pallet_prelude::{Blake2_128Concat, OptionQuery},
+	storage_alias,
+	traits::{
+		BalanceStatus, Currency, ExistenceRequirement, Get, OnRuntimeUpgrade, ReservableCurrency,
+	},
+	weights::Weight,
+};
+use parity_scale_codec::{Decode, Encode};
+use sp_core::U256;
+use sp_runtime::{
+	traits::{Convert, Saturating},
+	Perbill, RuntimeDebug,
+};
+use sp_std::marker::PhantomData;
+
+/// Share of the weight of each block spent migrating.
+pub const BLOCK_WEIGHT_SHARE: Perbill = Perbill::from_percent(10);
+
+/// A migration run over as many blocks as needed, a few entries at a time.
+pub trait SteppedMigration {
+	/// Migrates as many entries as fit in `budget` if the migration is running, and returns the
+	/// weight used.
+	fn on_initialize(budget: Weight) -> Weight;
+}
+
+impl SteppedMigration for () {
+	fn on_initialize(_budget: Weight) -> Weight {
+		Weight::zero()
+	}
+}
+
+/// Addresses read a page at a time by a migration.
+pub trait AddressPages {
+	/// Returns up to `limit` addresses of the page `cursor` points to, or of the first page if it
+	/// is `None`, and the cursor of the next page unless it was the last one.
+	fn page(cursor: Option<Vec<u8>>, limit: u32) -> (Vec<H160>, Option<Vec<u8>>);
+}
+
+/// Where [`MigrateAddressMapping`] stands.
+#[derive(Encode, Decode, Clone, PartialEq, RuntimeDebug)]
+pub enum AddressMappingStage {
+	/// Moving the balance and nonce of the addresses of the page the cursor points to, or of the
+	/// first page.
+	Accounts(Option<Vec<u8>>),
+	/// Moving the collections and deposits of the moved accounts, from the given collection on.
+	Collections(CollectionId),
+	/// Removing `MovedAccounts`, from the cursor of the last removal if any.
+	Cleanup(Option<Vec<u8>>),
+}
+
+/// Stage of [`MigrateAddressMapping`], only present while it runs
+#[storage_alias]
+pub type AddressMappingProgress<T: Config> =
+	StorageValue<Pallet<T>, AddressMappingStage, OptionQuery>;
+
+/// Accounts of the old mapping moved by [`MigrateAddressMapping`] and the accounts they were moved
+/// to, only present while it runs
+#[storage_alias]
+pub type MovedAccounts<T: Config> = StorageMap<
+	Pallet<T>,
+	Blake2_128Concat,
+	<T as frame_system::Config>::AccountId,
+	<T as frame_system::Config>::AccountId,
+	OptionQuery,
+>;
+
+/// Moves the collections, collection deposits, free balance and nonce of `Addresses` from the
+/// accounts `OldMapping` gives them to the ones given by `T::H160ToAccountId`.
+///
+/// Meant for runtimes that change the mapping of EVM addresses, as the accounts of the old mapping
+/// can not be told apart in storage. `Addresses` must list every address holding funds under the
+/// old mapping, or they are stranded once the mapping changes. The nonce of the new account is
+/// raised to the one of the old account, so that transactions signed for the old account can not
+/// be replayed and contracts created by it can not be created again.
+///
+/// The upgrade only starts the migration, which then runs over as many blocks as needed once set as
+/// `T::SteppedMigrations`: accounts a page of `Addresses` at a time, then the collections, each
+/// block migrating as many as fit in its share of the weight. Addresses mapped to the same account
+/// by both mappings, or whose old account does not exist, are skipped, so running the migration
+/// again only moves what was left behind.
+pub struct MigrateAddressMapping<T, OldMapping, Addresses>(PhantomData<(T, OldMapping, Addresses)>);
+
+impl<T, OldMapping, Addresses> MigrateAddressMapping<T, OldMapping, Addresses>
+where
+	T: Config,
+	OldMapping: Convert<H160, T::AccountId>,
+	Addresses: AddressPages,
+{
+	/// Weight of migrating the most expensive entry, the account of an address or a collection.
+	fn entry_weight() -> Weight {
+		T::DbWeight::get()
+			.reads_writes(4, 4)
+			.saturating_add(Weight::from_parts(0, 4 * MAX_READ_PROOF_SIZE))
+	}
+
+	/// Moves the free balance and nonce of `old` to `new`.
+	fn move_account(old: &T::AccountId, new: &T::AccountId) {
+		// read before the transfer, which may reap the old account
+		let nonce = frame_system::Pallet::<T>::account_nonce(old);
+		// an account below the existential deposit of `new` keeps its balance
+		let _ = T::Currency::transfer(
+			old,
+			new,
+			T::Currency::free_balance(old),
+			ExistenceRequirement::AllowDeath,
+		);
+		if nonce > frame_system::Pallet::<T>::account_nonce(new) {
+			frame_system::Account::<T>::mutate(new, |info| info.nonce = nonce);
+		}
+	}
+
+	/// Moves the deposit of a collection from `old` to `new`, which may not exist yet. The deposit
+	/// stays with `old` if it can not be moved, and whatever `old` does not have reserved of it is
+	/// dropped.
+	fn move_deposit(
+		collection_id: CollectionId,
+		old: &T::AccountId,
+		new: &T::AccountId,
+		deposit: BalanceOf<T>,
+	) {
+		let moved =
+			match T::Currency::repatriate_reserved(old, new, deposit, BalanceStatus::Reserved) {
+				Ok(unmoved) => {
+					T::Currency::unreserve(old, unmoved);
+					deposit.saturating_sub(unmoved)
+				},
+				// `new` does not exist, so it is created with the deposit as free balance first
+				Err(_) => {
+					let unreserved = deposit.saturating_sub(T::Currency::unreserve(old, deposit));
+					let result = T::Currency::transfer(
+						old,
+						new,
+						unreserved,
+						ExistenceRequirement::AllowDeath,
+					)
+					.and_then(|_| T::Currency::reserve(new, unreserved));
+					if result.is_err() {
+						let _ = T::Currency::reserve(old, unreserved);
+						return;
+					}
+					unreserved
+				},
+			};
+		CollectionDepositOf::<T>::insert(collection_id, (new.clone(), moved));
+	}
+
+	/// Migrates up to `limit` entries from where the previous step stopped, and ends the
+	/// migration once there are none left.
+	pub fn step(limit: u32) -> Weight {
+		let Some(stage) = AddressMappingProgress::<T>::get() else {
+			return T::DbWeight::get().reads(1);
+		};
+		let mut reads: u64 = 1;
+		let mut writes: u64 = 1;
+
+		let next = match stage {
+			AddressMappingStage::Accounts(cursor) => {
+				let (addresses, cursor) = Addresses::page(cursor, limit);
+				for address in addresses {
+					let old = OldMapping::convert(address);
+					let new = T::H160ToAccountId::convert(address);
+					reads += 1;
+					if old == new || !frame_system::Pallet::<T>::account_exists(&old) {
+						continue;
+					}
+					Self::move_account(&old, &new);
+					MovedAccounts::<T>::insert(old, new);
+					reads += 3;
+					writes += 4;
+				}
+				Some(match cursor {
+					Some(cursor) => AddressMappingStage::Accounts(Some(cursor)),
+					None => AddressMappingStage::Collections(0),
+				})
+			},
+			AddressMappingStage::Collections(first) => {
+				let end = CollectionCounter::<T>::get().min(first.saturating_add(limit as u64));
+				for collection_id in first..end {
+					if let Some(new) =
+						CollectionOwner::<T>::get(collection_id).and_then(MovedAccounts::<T>::get)
+					{
+						CollectionOwner::<T>::insert(collection_id, new);
+					}
+					if let Some((old, deposit)) = CollectionDepositOf::<T>::get(collection_id) {
+						if let Some(new) = MovedAccounts::<T>::get(&old) {
+							Self::move_deposit(collection_id, &old, &new, deposit);
+						}
+					}
+					reads += 4;
+					writes += 4;
+				}
+				reads += 1;
+				// collections created meanwhile are owned by accounts of the new mapping
+				Some(if end < CollectionCounter::<T>::get() {
+					AddressMappingStage::Collections(end)
+				} else {
+					AddressMappingStage::Cleanup(None)
+				})
+			},
+			AddressMappingStage::Cleanup(cursor) => {
+				let result = MovedAccounts::<T>::clear(limit, cursor.as_deref());
+				reads += result.loops as u64;
+				writes += result.unique as u64;
+				result.maybe_cursor.map(|cursor| AddressMappingStage::Cleanup(Some(cursor)))
+			},
+		};
+
+		match next {
+			Some(stage) => AddressMappingProgress::<T>::put(stage),
+			None => AddressMappingProgress::<T>::kill(),
+		}
+		T::DbWeight::get()
+			.reads_writes(reads, writes)
+			.saturating_add(Weight::from_parts(0, reads * MAX_READ_PROOF_SIZE))
+	}
+
+	/// Every address of `Addresses` with the accounts the old and the new mapping give it, if
+	/// they differ.
+	#[cfg(feature = "try-runtime")]
+	fn accounts() -> Vec<(T::AccountId, T::AccountId)> {
+		let mut accounts = Vec::new();
+		let mut cursor = None;
+		loop {
+			let (addresses, next) = Addresses::page(cursor, u32::MAX);
+			accounts.extend(
+				addresses
+					.into_iter()
+					.map(|address| {
+						(OldMapping::convert(address), T::H160ToAccountId::convert(address))
+					})
+					.filter(|(old, new)| old != new),
+			);
+			match next {
+				Some(next) => cursor = Some(next),
+				None => return accounts,
+			}
+		}
+	}
+
+	/// Checks the accounts listed were moved as `expected`, as left by `pre_upgrade`.
+	#[cfg(feature = "try-runtime")]
+	fn check(
+		expected: Vec<(T::AccountId, T::AccountId, BalanceOf<T>, T::Nonce)>,
+	) -> Result<(), sp_runtime::TryRuntimeError> {
+		let mut old_accounts = sp_std::collections::btree_set::BTreeSet::new();
+		for (old, new, balance, nonce) in expected {
+			let moved = T::Currency::free_balance(&new);
+			frame_support::ensure!(
+				moved.saturating_add(T::Currency::free_balance(&old)) == balance,
+				"free balance lost by the migration"
+			);
+			// only balances below the existential deposit stay with the old account
+			frame_support::ensure!(
+				moved == balance || balance < T::Currency::minimum_balance(),
+				"free balance left with an account of the old address mapping"
+			);
+			frame_support::ensure!(
+				frame_system::Pallet::<T>::account_nonce(&new) >= nonce,
+				"nonce not moved to the account of the new address mapping"
+			);
+			old_accounts.insert(old);
+		}
+
+		frame_support::ensure!(
+			!CollectionOwner::<T>::iter_values().any(|owner| old_accounts.contains(&owner)),
+			"collection owned by an account of the old address mapping"
+		);
+		frame_support::ensure!(
+			MovedAccounts::<T>::iter().next().is_none(),
+			"moved accounts not removed"
+		);
+		Ok(())
+	}
+}
+
+impl<T, OldMapping, Addresses> SteppedMigration for MigrateAddressMapping<T, OldMapping, Addresses>
+where
+	T: Config,
+	OldMapping: Convert<H160, T::AccountId>,
+	Addresses: AddressPages,
+{
+	fn on_initialize(budget: Weight) -> Weight {
+		if !AddressMappingProgress::<T>::exists() {
+			return T::DbWeight::get().reads(1);
+		}
+		Self::step(entries_within(budget, Self::entry_weight()))
+	}
+}
+
+impl<T, OldMapping, Addresses> OnRuntimeUpgrade for MigrateAddressMapping<T, OldMapping, Addresses>
+where
+	T: Config,
+	OldMapping: Convert<H160, T::AccountId>,
+	Addresses: AddressPages,
+{
+	fn on_runtime_upgrade() -> Weight {
+		if !AddressMappingProgress::<T>::exists() {
+			AddressMappingProgress::<T>::put(AddressMappingStage::Accounts(None));
+		}
+		T::DbWeight::get().reads_writes(1, 1)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+		// the balance and nonce each new account is expected to end up with
+		let expected: Vec<(T::AccountId, T::AccountId, BalanceOf<T>, T::Nonce)> = Self::accounts()
+			.into_iter()
+			.map(|(old, new)| {
+				let balance =
+					T::Currency::free_balance(&old).saturating_add(T::Currency::free_balance(&new));
+				let nonce = frame_system::Pallet::<T>::account_nonce(&old)
+					.max(frame_system::Pallet::<T>::account_nonce(&new));
+				(old, new, balance, nonce)
+			})
+			.collect();
+		Ok(expected.encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+		use frame_support::storage::{with_transaction, TransactionOutcome};
+
+		let expected: Vec<(T::AccountId, T::AccountId, BalanceOf<T>, T::Nonce)> =
+			Decode::decode(&mut &state[..]).map_err(|_| "expected accounts not decodable")?;
+		// the blocks the migration takes are run here, and rolled back once checked
+		with_transaction(|| {
+			while AddressMappingProgress::<T>::exists() {
+				Self::step(u32::MAX);
+			}
+			TransactionOutcome::Rollback(Self::check(expected))
+		})
+	}
+}
+
+/// Proof size `benchmark pallet` attributes to a read of `System::Account`, the largest entry a
+/// migration reads.
+pub(crate) const MAX_READ_PROOF_SIZE: u64 = 2_603;
+
+/// How many entries weighing `entry` fit in `budget`, in ref time and in proof size, and at least
+/// one so that a migration always makes progress.
+pub(crate) fn entries_within(budget: Weight, entry: Weight) -> u32 {
+	let by_ref_time = budget.ref_time().checked_div(entry.ref_time()).unwrap_or(u64::MAX);
+	let by_proof_size = budget.proof_size().checked_div(entry.proof_size()).unwrap_or(u64::MAX);
+	by_ref_time.min(by_proof_size).clamp(1, u32::MAX as u64) as u32
+}
+
+/// Storage layout before owners were stored as addresses.
+pub mod v0 {
+	use super::*;
+	use frame_support::{
+		pallet_prelude::{Blake2_128Concat, OptionQuery, StorageDoubleMap},
+		storage_alias,
+	};
+
+	/// Asset owner, as an account
+	#[storage_alias]
+	pub type AssetOwner<T: Config> = StorageDoubleMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		CollectionId,
+		Blake2_128Concat,
+		U256,
+		<T as frame_system::Config>::AccountId,
+		OptionQuery,
+	>;
+}
+
+/// Stores the owners of `AssetOwner`, and the keys of the indexes of explicitly owned assets, as
+/// addresses instead of accounts. Assets held by their initial owner are dropped from `AssetOwner`.
+///
+/// The upgrade only starts the migration, which then runs over as many blocks as needed: each block
+/// migrates as many entries as fit in `BLOCK_WEIGHT_SHARE` of its weight. Meanwhile owners are read
+/// whether they are stored as accounts or as addresses, while the indexes, which are rebuilt, can
+/// not be read and assets can not change hands.
+pub mod v1 {
+	use super::*;
+	use frame_support::{
+		pallet_prelude::OptionQuery,
+		storage_alias,
+		traits::{GetStorageVersion, StorageVersion},
+	};
+	use parity_scale_codec::DecodeAll;
+
+	/// Where the migration stands.
+	#[derive(Encode, Decode, Clone, PartialEq, RuntimeDebug)]
+	pub enum Stage {
+		/// Removing the entries of the indexes, which are keyed by the old owners:
+		/// `OwnerAssetCount`, `OwnerAssetByIndex` and `AssetIndexOfOwner` in turn, from the cursor
+		/// of the last removal if any.
+		ClearIndex(u8, Option<Vec<u8>>),
+		/// Storing the owners of `AssetOwner` as addresses and indexing their assets, from the
+		/// entry after the given raw key if any.
+		MigrateOwners(Option<Vec<u8>>),
+	}
+
+	/// Stage of the migration, only present while it runs
+	#[storage_alias]
+	pub type Progress<T: Config> = StorageValue<Pallet<T>, Stage, OptionQuery>;
+
+	/// Returns `true` while the migration runs.
+	pub(crate) fn is_migrating<T: Config>() -> bool {
+		Progress::<T>::exists()
+	}
+
+	/// Owner stored in `AssetOwner` for an asset while the migration runs, as an address whether
+	/// it is migrated or still stored as an account.
+	///
+	/// Entries are told apart by their length, so accounts must not be encoded in 20 bytes.
+	pub(crate) fn stored_owner<T: Config>(
+		collection_id: CollectionId,
+		asset_id: U256,
+	) -> Option<H160> {
+		let key = AssetOwner::<T>::hashed_key_for(collection_id, asset_id);
+		let value = frame_support::storage::unhashed::get_raw(&key)?;
+		H160::decode_all(&mut &value[..]).ok().or_else(|| {
+			T::AccountId::decode_all(&mut &value[..]).ok().map(T::AccountIdToH160::convert)
+		})
+	}
+
+	/// Migrates the pallet storage from version 0 to version 1.
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> MigrateToV1<T> {
+		/// Weight of migrating the most expensive entry, the owner of an asset.
+		fn entry_weight() -> Weight {
+			T::DbWeight::get()
+				.reads_writes(2, 4)
+				.saturating_add(Weight::from_parts(0, 2 * MAX_READ_PROOF_SIZE))
+		}
+
+		/// Migrates up to `limit` entries from where the previous step stopped, and ends the
+		/// migration once there are none left.
+		pub fn step(limit: u32) -> Weight {
+			let Some(stage) = Progress::<T>::get() else {
+				return T::DbWeight::get().reads(1);
+			};
+			let mut reads: u64 = 1;
+			let mut writes: u64 = 1;
+
+			let next = match stage {
+				Stage::ClearIndex(index, cursor) => {
+					let cursor = cursor.as_deref();
+					let result = match index {
+						0 => OwnerAssetCount::<T>::clear(limit, cursor),
+						1 => OwnerAssetByIndex::<T>::clear(limit, cursor),
+						_ => AssetIndexOfOwner::<T>::clear(limit, cursor),
+					};
+					reads += result.loops as u64;
+					writes += result.unique as u64;
+					match result.maybe_cursor {
+						Some(cursor) => Some(Stage::ClearIndex(index, Some(cursor))),
+						None if index < 2 => Some(Stage::ClearIndex(index + 1, None)),
+						None => Some(Stage::MigrateOwners(None)),
+					}
+				},
+				Stage::MigrateOwners(last_key) => {
+					let mut owners = match last_key {
+						Some(key) => v0::AssetOwner::<T>::iter_from(key),
+						None => v0::AssetOwner::<T>::iter(),
+					};
+					let mut migrated: u32 = 0;
+					let mut finished = false;
+					while migrated < limit {
+						let Some((collection_id, asset_id, owner)) = owners.next() else {
+							finished = true;
+							break;
+						};
+						let owner = T::AccountIdToH160::convert(owner);
+						if owner == initial_owner::<T>(asset_id) {
+							AssetOwner::<T>::remove(collection_id, asset_id);
+						} else {
+							AssetOwner::<T>::insert(collection_id, asset_id, owner);
+							Pallet::<T>::add_asset_to_owner(collection_id, owner, asset_id);
+						}
+						migrated += 1;
+					}
+					reads += 2 * migrated as u64 + 1;
+					writes += 4 * migrated as u64;
+					(!finished).then(|| Stage::MigrateOwners(Some(owners.last_raw_key().to_vec())))
+				},
+			};
+
+			match next {
+				Some(stage) => Progress::<T>::put(stage),
+				None => Progress::<T>::kill(),
+			}
+			T::DbWeight::get()
+				.reads_writes(reads, writes)
+				.saturating_add(Weight::from_parts(0, reads * MAX_READ_PROOF_SIZE))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn check(explicit_owners: u64) -> Result<(), sp_runtime::TryRuntimeError> {
+			frame_support::ensure!(!is_migrating::<T>(), "migration not finished");
+			frame_support::ensure!(
+				AssetOwner::<T>::iter().count() as u64 == explicit_owners,
+				"explicit owners lost by the migration"
+			);
+			frame_support::ensure!(
+				AssetOwner::<T>::iter()
+					.all(|(_, asset_id, owner)| owner != initial_owner::<T>(asset_id)),
+				"asset owner stored although it is the initial owner"
+			);
+			frame_support::ensure!(
+				OwnerAssetCount::<T>::iter_values().map(u64::from).sum::<u64>()
+					== AssetIndexOfOwner::<T>::iter().count() as u64,
+				"explicitly owned assets not indexed consistently"
+			);
+			Ok(())
+		}
+	}
+
+	impl<T: Config> SteppedMigration for MigrateToV1<T> {
+		fn on_initialize(budget: Weight) -> Weight {
+			if !is_migrating::<T>() {
+				return T::DbWeight::get().reads(1);
+			}
+			Self::step(entries_within(budget, Self::entry_weight()))
+		}
+	}
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 0 {
+				return T::DbWeight::get().reads(1);
+			}
+
+			// the indexes are keyed by the old owners, so they are rebuilt from scratch
+			Progress::<T>::put(Stage::ClearIndex(0, None));
+			StorageVersion::new(1).put::<Pallet<T>>();
+			T::DbWeight::get().reads_writes(1, 2)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			let explicit_owners = v0::AssetOwner::<T>::iter()
+				.filter(|(_, asset_id, owner)| {
+					T::AccountIdToH160::convert(owner.clone()) != initial_owner::<T>(*asset_id)
+				})
+				.count() as u64;
+			Ok(explicit_owners.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			use frame_support::storage::{with_transaction, TransactionOutcome};
+
+			frame_support::ensure!(
+				Pallet::<T>::on_chain_storage_version() == 1,
+				"storage version not updated"
+			);
+			let explicit_owners =
+				u64::decode(&mut &state[..]).map_err(|_| "explicit owner count not decodable")?;
+			// the blocks the migration takes are run here, and rolled back once checked
+			with_transaction(|| {
+				while is_migrating::<T>() {
+					Self::step(u32::MAX);
+				}
+				TransactionOutcome::Rollback(Self::check(explicit_owners))
+			})
+		}
+	}
+}
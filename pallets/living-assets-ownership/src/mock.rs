@@ -10,12 +10,21 @@ use sp_std::{boxed::Box, prelude::*};
 type Block = frame_system::mocking::MockBlock<Test>;
 type Nonce = u32;
 type AccountId = u64;
+type Balance = u64;
+
+pub const ALICE: AccountId = 0x1234;
+pub const BOB: AccountId = 0x2234;
+
+/// Free balance of the accounts endowed at genesis
+pub const INITIAL_BALANCE: Balance = 1_000_000;
 
 // Configure a mock runtime to test the pallet.
 frame_support::construct_runtime!(
 	pub enum Test
 	{
 		System: frame_system,
+		Balances: pallet_balances,
+		Timestamp: pallet_timestamp,
 		LivingAssetsModule: pallet_livingassets_ownership,
 	}
 );
@@ -37,7 +46,7 @@ impl frame_system::Config for Test {
 	type BlockHashCount = ConstU64<250>;
 	type Version = ();
 	type PalletInfo = PalletInfo;
-	type AccountData = ();
+	type AccountData = pallet_balances::AccountData<Balance>;
 	type OnNewAccount = ();
 	type OnKilledAccount = ();
 	type SystemWeightInfo = ();
@@ -46,12 +55,45 @@ impl frame_system::Config for Test {
 	type MaxConsumers = frame_support::traits::ConstU32<16>;
 }
 
+impl pallet_balances::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+	type Balance = Balance;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU64<1>;
+	type AccountStore = System;
+	type ReserveIdentifier = ();
+	type FreezeIdentifier = ();
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type MaxHolds = ();
+	type MaxFreezes = ();
+	type RuntimeHoldReason = ();
+}
+
+impl pallet_timestamp::Config for Test {
+	type Moment = u64;
+	type OnTimestampSet = ();
+	type MinimumPeriod = ConstU64<1>;
+	type WeightInfo = ();
+}
+
 impl pallet_livingassets_ownership::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type BaseURILimit = ConstU32<256>;
+	type NameLimit = ConstU32<64>;
+	type SymbolLimit = ConstU32<16>;
 	type AccountIdToH160 = MockAccountIdToH160;
 	type H160ToAccountId = MockH160ToAccountId;
 	type AssetIdToInitialOwner = MockAssetIdToInitialOwner;
+	type WeightInfo = ();
+	type Currency = Balances;
+	type CollectionDeposit = ConstU64<100>;
+	type DepositPerByte = ConstU64<1>;
+	type MaxBatchTransfers = ConstU32<4>;
+	type MaxLocksPerAsset = ConstU32<2>;
+	type UnixTime = Timestamp;
+	type SteppedMigrations = ();
 }
 
 pub struct MockAccountIdToH160;
@@ -79,5 +121,13 @@ impl Convert<U256, AccountId> for MockAssetIdToInitialOwner {
 
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
-	RuntimeGenesisConfig::default().build_storage().unwrap().into()
+	RuntimeGenesisConfig {
+		balances: pallet_balances::GenesisConfig {
+			balances: vec![(ALICE, INITIAL_BALANCE), (BOB, INITIAL_BALANCE)],
+		},
+		..Default::default()
+	}
+	.build_storage()
+	.unwrap()
+	.into()
 }
@@ -1,16 +1,14 @@
 use crate::{
-	address_to_collection_id, collection_id_to_address, is_collection_address, mock::*, AssetOwner,
-	CollectionBaseURI, CollectionError, Event,
+	address_to_collection_id, collection_id_to_address, is_collection_address, mock::*,
+	AssetApproval, AssetOwner, AssetUser, BatchTransfers, CollectionBaseURI, CollectionError,
+	CollectionRole, Error, Event, GenesisConfig, MintingMode, OwnerAssetByIndex, OwnerAssetCount,
 };
 use core::str::FromStr;
-use frame_support::assert_ok;
-use sp_core::H160;
+use frame_support::{assert_noop, assert_ok};
+use sp_core::{H160, U256};
+use sp_runtime::BuildStorage;
 
 type BaseURI = crate::BaseURI<Test>;
-type AccountId = <Test as frame_system::Config>::AccountId;
-
-const ALICE: AccountId = 0x1234;
-const BOB: AccountId = 0x2234;
 
 #[test]
 fn base_uri_unexistent_collection_is_none() {
@@ -85,319 +83,3260 @@ fn create_new_collections_should_emit_events_with_collection_id_consecutive() {
 }
 
 #[test]
-fn test_collection_id_to_address() {
-	let collection_id = 5;
-	let expected_address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
-	assert_eq!(collection_id_to_address(collection_id), expected_address);
+fn create_collection_with_name_should_store_the_name_and_symbol() {
+	let name = crate::Name::<Test>::try_from("Carbonara".as_bytes().to_vec()).unwrap();
+	let symbol = crate::Symbol::<Test>::try_from("CRB".as_bytes().to_vec()).unwrap();
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(LivingAssetsModule::create_collection_with_name(
+			RuntimeOrigin::signed(ALICE),
+			name.clone(),
+			symbol.clone(),
+			BaseURI::default()
+		));
+		System::assert_last_event(Event::CollectionCreated { collection_id: 0, who: ALICE }.into());
+		assert_eq!(LivingAssetsModule::collection_name(0), name);
+		assert_eq!(LivingAssetsModule::collection_symbol(0), symbol);
+		assert_eq!(LivingAssetsModule::collection_owner(0), Some(ALICE));
+		assert_eq!(Balances::reserved_balance(ALICE), LivingAssetsModule::collection_deposit(0));
+
+		assert_ok!(LivingAssetsModule::destroy_collection(RuntimeOrigin::signed(ALICE), 0));
+		assert!(LivingAssetsModule::collection_name(0).is_empty());
+		assert!(LivingAssetsModule::collection_symbol(0).is_empty());
+	});
 }
 
 #[test]
-fn invalid_collection_address_should_error() {
-	let address = H160::from_str("8000000000000000000000000000000000000005").unwrap();
-	let error = address_to_collection_id(address).unwrap_err();
-	assert_eq!(error, CollectionError::InvalidPrefix);
+fn create_collection_should_set_the_caller_as_owner() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LivingAssetsModule::create_collection(
+			RuntimeOrigin::signed(ALICE),
+			BaseURI::default()
+		));
+		assert_eq!(LivingAssetsModule::collection_owner(0), Some(ALICE));
+	});
 }
 
 #[test]
-fn valid_collection_address_should_return_collection_id() {
-	let address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
-	let collection_id = address_to_collection_id(address).unwrap();
-	assert_eq!(collection_id, 5);
+fn transfer_collection_ownership_should_change_the_owner() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(LivingAssetsModule::create_collection(
+			RuntimeOrigin::signed(ALICE),
+			BaseURI::default()
+		));
+		assert_ok!(LivingAssetsModule::transfer_collection_ownership(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			BOB
+		));
+		assert_eq!(LivingAssetsModule::collection_owner(0), Some(BOB));
+		System::assert_last_event(
+			Event::CollectionOwnershipTransferred {
+				collection_id: 0,
+				previous_owner: ALICE,
+				new_owner: Some(BOB),
+			}
+			.into(),
+		);
+	});
 }
 
 #[test]
-fn test_is_collection_address_valid() {
-	let collection_id = 1234567890;
-	let address = collection_id_to_address(collection_id);
+fn transfer_collection_ownership_by_non_owner_should_fail() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LivingAssetsModule::create_collection(
+			RuntimeOrigin::signed(ALICE),
+			BaseURI::default()
+		));
+		assert_noop!(
+			LivingAssetsModule::transfer_collection_ownership(RuntimeOrigin::signed(BOB), 0, BOB),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			LivingAssetsModule::transfer_collection_ownership(RuntimeOrigin::signed(ALICE), 1, BOB),
+			Error::<Test>::CollectionDoesNotExist
+		);
+	});
+}
 
-	assert!(is_collection_address(address));
+#[test]
+fn renounce_collection_ownership_should_leave_the_collection_without_owner() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(LivingAssetsModule::create_collection(
+			RuntimeOrigin::signed(ALICE),
+			BaseURI::default()
+		));
+		assert_ok!(LivingAssetsModule::renounce_collection_ownership(
+			RuntimeOrigin::signed(ALICE),
+			0
+		));
+		assert_eq!(LivingAssetsModule::collection_owner(0), None);
+		System::assert_last_event(
+			Event::CollectionOwnershipTransferred {
+				collection_id: 0,
+				previous_owner: ALICE,
+				new_owner: None,
+			}
+			.into(),
+		);
+		assert_noop!(
+			LivingAssetsModule::transfer_collection_ownership(RuntimeOrigin::signed(ALICE), 0, BOB),
+			Error::<Test>::NoPermission
+		);
+	});
 }
 
 #[test]
-fn test_is_collection_address_invalid() {
-	let invalid_address = H160([0u8; 20]);
+fn set_base_uri_should_update_the_base_uri_and_emit_event() {
+	let base_uri = BaseURI::try_from("https://new.example.com".as_bytes().to_vec()).unwrap();
 
-	assert!(!is_collection_address(invalid_address));
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(LivingAssetsModule::create_collection(
+			RuntimeOrigin::signed(ALICE),
+			BaseURI::default()
+		));
+		assert_ok!(LivingAssetsModule::set_base_uri(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			base_uri.clone()
+		));
+		assert_eq!(LivingAssetsModule::collection_base_uri(0).unwrap(), base_uri);
+		System::assert_last_event(Event::BaseURIUpdated { collection_id: 0, base_uri }.into());
+	});
 }
 
-mod traits {
-	use super::*;
-	use crate::{
-		traits::{CollectionManager, Erc721},
-		Error, Event,
-	};
-	use frame_support::{assert_err, assert_noop, assert_ok};
-	use sp_core::U256;
-
-	#[test]
-	fn base_uri_of_unexistent_collection_is_none() {
-		new_test_ext().execute_with(|| {
-			assert_eq!(<LivingAssetsModule as CollectionManager>::base_uri(0), None);
-			assert_eq!(<LivingAssetsModule as CollectionManager>::base_uri(1), None);
-		});
-	}
+#[test]
+fn set_base_uri_by_non_owner_should_fail() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LivingAssetsModule::create_collection(
+			RuntimeOrigin::signed(ALICE),
+			BaseURI::default()
+		));
+		assert_noop!(
+			LivingAssetsModule::set_base_uri(RuntimeOrigin::signed(BOB), 0, BaseURI::default()),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			LivingAssetsModule::set_base_uri(RuntimeOrigin::signed(ALICE), 1, BaseURI::default()),
+			Error::<Test>::CollectionDoesNotExist
+		);
+	});
+}
 
-	#[test]
-	fn create_new_collection_should_emit_an_event() {
-		new_test_ext().execute_with(|| {
-			// Go past genesis block so events get deposited
-			System::set_block_number(1);
+#[test]
+fn transfer_should_change_the_owner_and_emit_event() {
+	let asset_id = U256::from(ALICE);
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(LivingAssetsModule::create_collection(
+			RuntimeOrigin::signed(ALICE),
+			BaseURI::default()
+		));
+		assert_ok!(LivingAssetsModule::transfer(RuntimeOrigin::signed(ALICE), 0, BOB, asset_id));
+		assert_eq!(AssetOwner::<Test>::get(0, asset_id), Some(H160::from_low_u64_be(BOB)));
+		System::assert_last_event(
+			Event::AssetTransferred { collection_id: 0, asset_id, to: BOB }.into(),
+		);
+	});
+}
 
-			assert_ok!(<LivingAssetsModule as CollectionManager>::create_collection(
-				ALICE,
-				BaseURI::default(),
-			));
-			System::assert_last_event(
-				Event::CollectionCreated { collection_id: 0, who: ALICE }.into(),
-			);
-		});
-	}
+#[test]
+fn transfer_back_to_the_initial_owner_should_remove_the_owner_entry() {
+	let asset_id = U256::from(ALICE);
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(LivingAssetsModule::create_collection(
+			RuntimeOrigin::signed(ALICE),
+			BaseURI::default()
+		));
+		assert_ok!(LivingAssetsModule::transfer(RuntimeOrigin::signed(ALICE), 0, BOB, asset_id));
+		assert_ok!(LivingAssetsModule::transfer(RuntimeOrigin::signed(BOB), 0, ALICE, asset_id));
 
-	#[test]
-	fn living_assets_ownership_trait_id_of_new_collection_should_be_consecutive() {
-		new_test_ext().execute_with(|| {
-			assert_eq!(
-				<LivingAssetsModule as CollectionManager>::create_collection(
-					ALICE,
-					BaseURI::default()
-				)
-				.unwrap(),
-				0
-			);
-			assert_eq!(
-				<LivingAssetsModule as CollectionManager>::create_collection(
-					ALICE,
-					BaseURI::default()
-				)
-				.unwrap(),
-				1
-			);
-			assert_eq!(
-				<LivingAssetsModule as CollectionManager>::create_collection(
-					ALICE,
-					BaseURI::default()
-				)
-				.unwrap(),
-				2
-			);
-			assert_eq!(
-				<LivingAssetsModule as CollectionManager>::create_collection(
-					ALICE,
-					BaseURI::default()
-				)
-				.unwrap(),
-				3
-			);
-			assert_eq!(
-				<LivingAssetsModule as CollectionManager>::create_collection(
-					ALICE,
-					BaseURI::default()
-				)
-				.unwrap(),
-				4
-			);
-			assert_eq!(
-				<LivingAssetsModule as CollectionManager>::create_collection(
-					ALICE,
-					BaseURI::default()
-				)
-				.unwrap(),
-				5
-			);
-		});
-	}
+		assert_eq!(AssetOwner::<Test>::get(0, asset_id), None);
+		assert_eq!(OwnerAssetCount::<Test>::get(0, H160::from_low_u64_be(BOB)), 0);
+		assert_eq!(OwnerAssetCount::<Test>::get(0, H160::from_low_u64_be(ALICE)), 0);
+		System::assert_last_event(
+			Event::AssetTransferred { collection_id: 0, asset_id, to: ALICE }.into(),
+		);
+	});
+}
 
-	#[test]
-	fn living_assets_ownership_trait_should_set_base_uri_when_creating_new_collection() {
-		let base_uri = BaseURI::try_from("https://example.com/".as_bytes().to_vec()).unwrap();
+#[test]
+fn batch_transfer_should_move_assets_of_several_collections() {
+	let asset_id = U256::from(ALICE);
+	let other_asset_id = U256::from(ALICE) + (U256::one() << 128);
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(LivingAssetsModule::create_collection(
+			RuntimeOrigin::signed(ALICE),
+			BaseURI::default()
+		));
+		assert_ok!(LivingAssetsModule::create_collection(
+			RuntimeOrigin::signed(BOB),
+			BaseURI::default()
+		));
+		let transfers = BatchTransfers::<Test>::try_from(vec![
+			(0, BOB, asset_id),
+			(1, BOB, asset_id),
+			(0, BOB, other_asset_id),
+		])
+		.unwrap();
+		assert_ok!(LivingAssetsModule::batch_transfer(
+			RuntimeOrigin::signed(ALICE),
+			ALICE,
+			transfers
+		));
+		assert_eq!(AssetOwner::<Test>::get(0, asset_id), Some(H160::from_low_u64_be(BOB)));
+		assert_eq!(AssetOwner::<Test>::get(1, asset_id), Some(H160::from_low_u64_be(BOB)));
+		assert_eq!(AssetOwner::<Test>::get(0, other_asset_id), Some(H160::from_low_u64_be(BOB)));
+		System::assert_has_event(
+			Event::AssetTransferred { collection_id: 1, asset_id, to: BOB }.into(),
+		);
+		System::assert_last_event(
+			Event::AssetTransferred { collection_id: 0, asset_id: other_asset_id, to: BOB }.into(),
+		);
+	});
+}
 
-		new_test_ext().execute_with(|| {
-			assert_ok!(<LivingAssetsModule as CollectionManager>::create_collection(
-				ALICE,
-				base_uri.clone()
-			));
-			assert_eq!(LivingAssetsModule::collection_base_uri(0).unwrap(), base_uri);
-		});
-	}
+#[test]
+fn batch_transfer_by_non_owner_should_fail() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LivingAssetsModule::create_collection(
+			RuntimeOrigin::signed(ALICE),
+			BaseURI::default()
+		));
+		let transfers =
+			BatchTransfers::<Test>::try_from(vec![(0, BOB, U256::from(ALICE))]).unwrap();
+		assert_noop!(
+			LivingAssetsModule::batch_transfer(RuntimeOrigin::signed(BOB), ALICE, transfers),
+			Error::<Test>::NoPermission
+		);
+	});
+}
 
-	#[test]
-	fn owner_of_asset_of_unexistent_collection_should_error() {
-		new_test_ext().execute_with(|| {
-			let result = <LivingAssetsModule as Erc721>::owner_of(0, 2.into());
-			assert_err!(result, Error::CollectionDoesNotExist);
-		});
-	}
+#[test]
+fn transfer_of_asset_not_owned_should_fail() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LivingAssetsModule::create_collection(
+			RuntimeOrigin::signed(ALICE),
+			BaseURI::default()
+		));
+		assert_noop!(
+			LivingAssetsModule::transfer(RuntimeOrigin::signed(ALICE), 0, BOB, U256::from(BOB)),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			LivingAssetsModule::transfer(RuntimeOrigin::signed(ALICE), 0, ALICE, U256::from(ALICE)),
+			Error::<Test>::CannotTransferSelf
+		);
+		assert_noop!(
+			LivingAssetsModule::transfer(RuntimeOrigin::signed(ALICE), 1, BOB, U256::from(ALICE)),
+			Error::<Test>::CollectionDoesNotExist
+		);
+	});
+}
 
-	#[test]
-	fn verify_different_owners_for_same_asset_across_different_collections() {
-		let asset_id = U256::from(
-			hex::decode("03C0F0f4ab324C46e55D02D0033343B4Be8A55532d").unwrap().as_slice(),
+#[test]
+fn transfer_from_by_approved_account_should_work() {
+	let asset_id = U256::from(ALICE);
+	new_test_ext().execute_with(|| {
+		assert_ok!(LivingAssetsModule::create_collection(
+			RuntimeOrigin::signed(ALICE),
+			BaseURI::default()
+		));
+		assert_noop!(
+			LivingAssetsModule::transfer_from(RuntimeOrigin::signed(BOB), 0, ALICE, BOB, asset_id),
+			Error::<Test>::NoPermission
 		);
-		let sender = H160::from_str("0000000000000000000000003343b4be8a55532d").unwrap();
-		let receiver = H160::from_low_u64_be(BOB);
-		new_test_ext().execute_with(|| {
-			CollectionBaseURI::<Test>::insert(1, BaseURI::default());
-			CollectionBaseURI::<Test>::insert(2, BaseURI::default());
+		AssetApproval::<Test>::insert(0, asset_id, H160::from_low_u64_be(BOB));
+		assert_ok!(LivingAssetsModule::transfer_from(
+			RuntimeOrigin::signed(BOB),
+			0,
+			ALICE,
+			BOB,
+			asset_id
+		));
+		assert_eq!(AssetOwner::<Test>::get(0, asset_id), Some(H160::from_low_u64_be(BOB)));
+		assert_eq!(AssetApproval::<Test>::get(0, asset_id), None);
+	});
+}
+
+#[test]
+fn create_collection_should_reserve_the_deposit() {
+	let base_uri = BaseURI::try_from("https://example.com/".as_bytes().to_vec()).unwrap();
+	let deposit = LivingAssetsModule::collection_deposit(base_uri.len());
+	assert_eq!(deposit, 100 + 20);
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(LivingAssetsModule::create_collection(RuntimeOrigin::signed(ALICE), base_uri));
+		assert_eq!(Balances::reserved_balance(ALICE), deposit);
+		assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE - deposit);
+		assert_eq!(LivingAssetsModule::collection_deposit_of(0), Some((ALICE, deposit)));
+	});
+}
+
+#[test]
+fn create_collection_without_balance_for_the_deposit_should_fail() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			LivingAssetsModule::create_collection(
+				RuntimeOrigin::signed(0x3234),
+				BaseURI::default()
+			),
+			Error::<Test>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn set_base_uri_should_adjust_the_deposit() {
+	let long_base_uri = BaseURI::try_from("https://example.com/long".as_bytes().to_vec()).unwrap();
+	let short_base_uri = BaseURI::try_from("ipfs://".as_bytes().to_vec()).unwrap();
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(LivingAssetsModule::create_collection(
+			RuntimeOrigin::signed(ALICE),
+			BaseURI::default()
+		));
+		assert_eq!(Balances::reserved_balance(ALICE), 100);
+
+		assert_ok!(LivingAssetsModule::set_base_uri(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			long_base_uri.clone()
+		));
+		assert_eq!(Balances::reserved_balance(ALICE), 100 + long_base_uri.len() as u64);
+
+		assert_ok!(LivingAssetsModule::set_base_uri(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			short_base_uri.clone()
+		));
+		assert_eq!(Balances::reserved_balance(ALICE), 100 + short_base_uri.len() as u64);
+		assert_eq!(
+			LivingAssetsModule::collection_deposit_of(0),
+			Some((ALICE, 100 + short_base_uri.len() as u64))
+		);
+	});
+}
+
+#[test]
+fn transfer_collection_ownership_should_move_the_deposit() {
+	let base_uri = BaseURI::try_from("ipfs://".as_bytes().to_vec()).unwrap();
+	let deposit = 100 + base_uri.len() as u64;
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(LivingAssetsModule::create_collection(RuntimeOrigin::signed(ALICE), base_uri));
+		assert_ok!(LivingAssetsModule::transfer_collection_ownership(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			BOB
+		));
+
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE - deposit);
+		assert_eq!(Balances::reserved_balance(BOB), deposit);
+		assert_eq!(Balances::free_balance(BOB), INITIAL_BALANCE);
+		assert_eq!(LivingAssetsModule::collection_deposit_of(0), Some((BOB, deposit)));
+
+		// the new owner gets it back when destroying the collection
+		assert_ok!(LivingAssetsModule::destroy_collection(RuntimeOrigin::signed(BOB), 0));
+		assert_eq!(Balances::free_balance(BOB), INITIAL_BALANCE + deposit);
+	});
+}
+
+#[test]
+fn transfer_collection_ownership_to_unexistent_account_should_keep_the_deposit() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LivingAssetsModule::create_collection(
+			RuntimeOrigin::signed(ALICE),
+			BaseURI::default()
+		));
+		assert_ok!(LivingAssetsModule::transfer_collection_ownership(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			0x3234
+		));
+
+		assert_eq!(LivingAssetsModule::collection_owner(0), Some(0x3234));
+		assert_eq!(LivingAssetsModule::collection_deposit_of(0), Some((ALICE, 100)));
+		assert_eq!(Balances::reserved_balance(ALICE), 100);
+		assert_eq!(Balances::total_balance(&0x3234), 0);
+
+		// destroying the collection releases the deposit to whoever reserved it
+		assert_ok!(LivingAssetsModule::destroy_collection(RuntimeOrigin::signed(0x3234), 0));
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE);
+	});
+}
+
+#[test]
+fn transfer_collection_ownership_should_only_move_the_deposit_held() {
+	use frame_support::traits::ReservableCurrency;
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(LivingAssetsModule::create_collection(
+			RuntimeOrigin::signed(ALICE),
+			BaseURI::default()
+		));
+		// as if another pallet had released part of the deposit
+		Balances::unreserve(&ALICE, 40);
+
+		assert_ok!(LivingAssetsModule::transfer_collection_ownership(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			BOB
+		));
+
+		assert_eq!(LivingAssetsModule::collection_deposit_of(0), Some((BOB, 60)));
+		assert_eq!(Balances::reserved_balance(BOB), 60);
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE - 60);
+	});
+}
+
+#[test]
+fn renounce_collection_ownership_should_release_the_deposit() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LivingAssetsModule::create_collection(
+			RuntimeOrigin::signed(ALICE),
+			BaseURI::default()
+		));
+		assert_ok!(LivingAssetsModule::renounce_collection_ownership(
+			RuntimeOrigin::signed(ALICE),
+			0
+		));
+
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE);
+		assert_eq!(LivingAssetsModule::collection_deposit_of(0), None);
+	});
+}
+
+#[test]
+fn set_base_uri_by_metadata_manager_should_charge_the_owner() {
+	let base_uri = BaseURI::try_from("ipfs://".as_bytes().to_vec()).unwrap();
+	let other_base_uri = BaseURI::try_from("ar://a/".as_bytes().to_vec()).unwrap();
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(LivingAssetsModule::create_collection(
+			RuntimeOrigin::signed(ALICE),
+			base_uri.clone()
+		));
+		assert_ok!(LivingAssetsModule::grant_role(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			CollectionRole::MetadataManager,
+			BOB
+		));
+
+		assert_ok!(LivingAssetsModule::set_base_uri(
+			RuntimeOrigin::signed(BOB),
+			0,
+			other_base_uri.clone()
+		));
+		assert_eq!(LivingAssetsModule::collection_base_uri(0), Some(other_base_uri.clone()));
+		assert_eq!(Balances::reserved_balance(ALICE), 100 + base_uri.len() as u64);
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+		assert_eq!(
+			LivingAssetsModule::collection_deposit_of(0),
+			Some((ALICE, 100 + other_base_uri.len() as u64))
+		);
+	});
+}
+
+#[test]
+fn destroy_collection_should_remove_it_and_release_the_deposit() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(LivingAssetsModule::create_collection(
+			RuntimeOrigin::signed(ALICE),
+			BaseURI::try_from("ipfs://".as_bytes().to_vec()).unwrap()
+		));
+		assert_ok!(LivingAssetsModule::destroy_collection(RuntimeOrigin::signed(ALICE), 0));
+		System::assert_last_event(
+			Event::CollectionDestroyed { collection_id: 0, who: ALICE }.into(),
+		);
+
+		assert_eq!(LivingAssetsModule::collection_base_uri(0), None);
+		assert_eq!(LivingAssetsModule::collection_owner(0), None);
+		assert_eq!(LivingAssetsModule::collection_deposit_of(0), None);
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE);
+
+		// collection ids are not reused
+		assert_ok!(LivingAssetsModule::create_collection(
+			RuntimeOrigin::signed(ALICE),
+			BaseURI::default()
+		));
+		assert_eq!(LivingAssetsModule::collection_owner(1), Some(ALICE));
+	});
+}
+
+#[test]
+fn destroy_collection_should_remove_everything_left_of_it() {
+	use crate::{
+		traits::Erc721, BurnedAsset, CollectionRoles, DestroyedCollections, OperatorApproval,
+		PausedCollection, SoulboundCollection,
+	};
+	use frame_support::{traits::Hooks, weights::Weight};
+
+	let alice = H160::from_low_u64_be(ALICE);
+	let bob = H160::from_low_u64_be(BOB);
+	let asset_id = U256::from(ALICE);
+	let burned_asset_id = asset_id + (U256::one() << 128);
+	new_test_ext().execute_with(|| {
+		assert_ok!(LivingAssetsModule::create_collection(
+			RuntimeOrigin::signed(ALICE),
+			BaseURI::default()
+		));
+		assert_ok!(LivingAssetsModule::grant_role(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			CollectionRole::Pauser,
+			BOB
+		));
+		assert_ok!(<LivingAssetsModule as Erc721>::set_approval_for_all(bob, 0, alice, true));
+		assert_ok!(<LivingAssetsModule as Erc721>::approve(alice, 0, bob, asset_id));
+		assert_ok!(<LivingAssetsModule as Erc721>::set_user(alice, 0, asset_id, bob, u64::MAX));
+		assert_ok!(<LivingAssetsModule as Erc721>::burn(alice, 0, burned_asset_id));
+		assert_ok!(LivingAssetsModule::make_soulbound(RuntimeOrigin::signed(ALICE), 0));
+		assert_ok!(LivingAssetsModule::pause(RuntimeOrigin::signed(ALICE), 0));
+
+		assert_ok!(LivingAssetsModule::destroy_collection(RuntimeOrigin::signed(ALICE), 0));
+		assert!(!SoulboundCollection::<Test>::contains_key(0));
+		assert!(!PausedCollection::<Test>::contains_key(0));
+		assert!(DestroyedCollections::<Test>::get(0));
+
+		LivingAssetsModule::on_idle(1, Weight::MAX);
+
+		assert!(!DestroyedCollections::<Test>::contains_key(0));
+		assert_eq!(CollectionRoles::<Test>::iter_prefix((0,)).count(), 0);
+		assert_eq!(OperatorApproval::<Test>::iter_prefix((0,)).count(), 0);
+		assert_eq!(AssetApproval::<Test>::iter_prefix(0).count(), 0);
+		assert_eq!(AssetUser::<Test>::iter_prefix(0).count(), 0);
+		assert_eq!(BurnedAsset::<Test>::iter_prefix(0).count(), 0);
+	});
+}
+
+#[test]
+fn destroyed_collections_should_be_removed_within_the_weight_left() {
+	use crate::{traits::Erc721, DestroyedCollections, OperatorApproval};
+	use frame_support::{traits::Hooks, weights::Weight};
+
+	let mut ext = new_test_ext();
+	ext.execute_with(|| {
+		assert_ok!(LivingAssetsModule::create_collection(
+			RuntimeOrigin::signed(ALICE),
+			BaseURI::default()
+		));
+		for owner in 1..=3 {
+			assert_ok!(<LivingAssetsModule as Erc721>::set_approval_for_all(
+				H160::from_low_u64_be(owner),
+				0,
+				H160::from_low_u64_be(BOB),
+				true
+			));
+		}
+		assert_ok!(LivingAssetsModule::destroy_collection(RuntimeOrigin::signed(ALICE), 0));
+	});
+	// removals are only limited for entries of previous blocks
+	ext.commit_all().unwrap();
+
+	ext.execute_with(|| {
+		// too little weight left to remove anything
+		assert_eq!(LivingAssetsModule::on_idle(1, Weight::zero()), Weight::zero());
+		assert_eq!(OperatorApproval::<Test>::iter_prefix((0,)).count(), 3);
+
+		// the proof size of removing two entries, on top of finding the collection
+		LivingAssetsModule::on_idle(1, Weight::from_parts(u64::MAX, 3 * 2_603));
+		assert_eq!(OperatorApproval::<Test>::iter_prefix((0,)).count(), 1);
+		assert!(DestroyedCollections::<Test>::get(0));
+
+		LivingAssetsModule::on_idle(2, Weight::MAX);
+		assert_eq!(OperatorApproval::<Test>::iter_prefix((0,)).count(), 0);
+		assert!(!DestroyedCollections::<Test>::contains_key(0));
+	});
+}
+
+#[test]
+fn destroy_collection_by_non_owner_should_fail() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LivingAssetsModule::create_collection(
+			RuntimeOrigin::signed(ALICE),
+			BaseURI::default()
+		));
+		assert_noop!(
+			LivingAssetsModule::destroy_collection(RuntimeOrigin::signed(BOB), 0),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			LivingAssetsModule::destroy_collection(RuntimeOrigin::signed(ALICE), 1),
+			Error::<Test>::CollectionDoesNotExist
+		);
+	});
+}
+
+#[test]
+fn destroy_collection_with_assets_should_fail() {
+	let asset_id = U256::from(ALICE);
+	new_test_ext().execute_with(|| {
+		assert_ok!(LivingAssetsModule::create_collection(
+			RuntimeOrigin::signed(ALICE),
+			BaseURI::default()
+		));
+		assert_ok!(LivingAssetsModule::transfer(RuntimeOrigin::signed(ALICE), 0, BOB, asset_id));
+		assert_noop!(
+			LivingAssetsModule::destroy_collection(RuntimeOrigin::signed(ALICE), 0),
+			Error::<Test>::CollectionNotEmpty
+		);
+	});
+}
+
+#[test]
+fn destroy_collection_with_token_uris_should_fail() {
+	let asset_id = U256::from(BOB);
+	new_test_ext().execute_with(|| {
+		assert_ok!(LivingAssetsModule::create_collection(
+			RuntimeOrigin::signed(ALICE),
+			BaseURI::default()
+		));
+		assert_ok!(LivingAssetsModule::set_token_uri(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			asset_id,
+			BaseURI::try_from("ipfs://QmToken".as_bytes().to_vec()).unwrap()
+		));
+		assert_noop!(
+			LivingAssetsModule::destroy_collection(RuntimeOrigin::signed(ALICE), 0),
+			Error::<Test>::CollectionNotEmpty
+		);
+
+		// clearing the token URI makes the collection empty again
+		assert_ok!(LivingAssetsModule::set_token_uri(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			asset_id,
+			BaseURI::default()
+		));
+		assert_ok!(LivingAssetsModule::destroy_collection(RuntimeOrigin::signed(ALICE), 0));
+	});
+}
+
+#[test]
+fn freeze_metadata_should_block_uri_changes() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(LivingAssetsModule::create_collection(
+			RuntimeOrigin::signed(ALICE),
+			BaseURI::default()
+		));
+		assert_ok!(LivingAssetsModule::freeze_metadata(RuntimeOrigin::signed(ALICE), 0));
+		System::assert_last_event(Event::MetadataFrozen { collection_id: 0 }.into());
+		assert!(LivingAssetsModule::is_metadata_frozen(0));
+
+		assert_noop!(
+			LivingAssetsModule::set_base_uri(RuntimeOrigin::signed(ALICE), 0, BaseURI::default()),
+			Error::<Test>::MetadataIsFrozen
+		);
+		assert_noop!(
+			LivingAssetsModule::set_token_uri(
+				RuntimeOrigin::signed(ALICE),
+				0,
+				U256::from(BOB),
+				BaseURI::default()
+			),
+			Error::<Test>::MetadataIsFrozen
+		);
+		assert_noop!(
+			LivingAssetsModule::freeze_metadata(RuntimeOrigin::signed(ALICE), 0),
+			Error::<Test>::MetadataIsFrozen
+		);
+		assert_noop!(
+			LivingAssetsModule::destroy_collection(RuntimeOrigin::signed(ALICE), 0),
+			Error::<Test>::MetadataIsFrozen
+		);
+	});
+}
+
+#[test]
+fn freeze_metadata_by_non_owner_should_fail() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LivingAssetsModule::create_collection(
+			RuntimeOrigin::signed(ALICE),
+			BaseURI::default()
+		));
+		assert_noop!(
+			LivingAssetsModule::freeze_metadata(RuntimeOrigin::signed(BOB), 0),
+			Error::<Test>::NoPermission
+		);
+	});
+}
+
+#[test]
+fn make_soulbound_should_block_transfers() {
+	let asset_id = U256::from(ALICE);
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(LivingAssetsModule::create_collection(
+			RuntimeOrigin::signed(ALICE),
+			BaseURI::default()
+		));
+		assert_ok!(LivingAssetsModule::make_soulbound(RuntimeOrigin::signed(ALICE), 0));
+		System::assert_last_event(Event::CollectionMadeSoulbound { collection_id: 0 }.into());
+		assert!(LivingAssetsModule::is_soulbound(0));
+
+		assert_noop!(
+			LivingAssetsModule::transfer(RuntimeOrigin::signed(ALICE), 0, BOB, asset_id),
+			Error::<Test>::AssetNotTransferable
+		);
+		AssetApproval::<Test>::insert(0, asset_id, H160::from_low_u64_be(BOB));
+		assert_noop!(
+			LivingAssetsModule::transfer_from(RuntimeOrigin::signed(BOB), 0, ALICE, BOB, asset_id),
+			Error::<Test>::AssetNotTransferable
+		);
+		assert_noop!(
+			LivingAssetsModule::make_soulbound(RuntimeOrigin::signed(ALICE), 0),
+			Error::<Test>::CollectionIsSoulbound
+		);
+	});
+}
+
+#[test]
+fn set_royalty_should_update_it_and_emit_event() {
+	let receiver = H160::from_low_u64_be(BOB);
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(LivingAssetsModule::create_collection(
+			RuntimeOrigin::signed(ALICE),
+			BaseURI::default()
+		));
+
+		assert_ok!(LivingAssetsModule::set_default_royalty(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			receiver,
+			250
+		));
+		System::assert_last_event(
+			Event::RoyaltyUpdated { collection_id: 0, asset_id: None, receiver, basis_points: 250 }
+				.into(),
+		);
+		assert_eq!(LivingAssetsModule::collection_royalty(0), Some((receiver, 250)));
+
+		assert_ok!(LivingAssetsModule::set_token_royalty(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			U256::from(7),
+			receiver,
+			1_000
+		));
+		System::assert_last_event(
+			Event::RoyaltyUpdated {
+				collection_id: 0,
+				asset_id: Some(U256::from(7)),
+				receiver,
+				basis_points: 1_000,
+			}
+			.into(),
+		);
+		assert_eq!(LivingAssetsModule::asset_royalty(0, U256::from(7)), Some((receiver, 1_000)));
+
+		// a zero receiver removes the royalty
+		assert_ok!(LivingAssetsModule::set_default_royalty(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			H160::zero(),
+			0
+		));
+		assert_eq!(LivingAssetsModule::collection_royalty(0), None);
+	});
+}
+
+#[test]
+fn set_royalty_should_fail_if_too_high_or_not_the_owner() {
+	let receiver = H160::from_low_u64_be(BOB);
+	new_test_ext().execute_with(|| {
+		assert_ok!(LivingAssetsModule::create_collection(
+			RuntimeOrigin::signed(ALICE),
+			BaseURI::default()
+		));
+		assert_noop!(
+			LivingAssetsModule::set_default_royalty(
+				RuntimeOrigin::signed(ALICE),
+				0,
+				receiver,
+				10_001
+			),
+			Error::<Test>::RoyaltyTooHigh
+		);
+		assert_noop!(
+			LivingAssetsModule::set_token_royalty(
+				RuntimeOrigin::signed(BOB),
+				0,
+				U256::from(7),
+				receiver,
+				250
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			LivingAssetsModule::set_default_royalty(RuntimeOrigin::signed(ALICE), 1, receiver, 250),
+			Error::<Test>::CollectionDoesNotExist
+		);
+	});
+}
+
+#[test]
+fn destroy_collection_with_token_royalties_should_fail() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LivingAssetsModule::create_collection(
+			RuntimeOrigin::signed(ALICE),
+			BaseURI::default()
+		));
+		assert_ok!(LivingAssetsModule::set_default_royalty(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			H160::from_low_u64_be(BOB),
+			250
+		));
+		assert_ok!(LivingAssetsModule::set_token_royalty(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			U256::from(7),
+			H160::from_low_u64_be(BOB),
+			250
+		));
+		assert_noop!(
+			LivingAssetsModule::destroy_collection(RuntimeOrigin::signed(ALICE), 0),
+			Error::<Test>::CollectionNotEmpty
+		);
+
+		assert_ok!(LivingAssetsModule::set_token_royalty(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			U256::from(7),
+			H160::zero(),
+			0
+		));
+		assert_ok!(LivingAssetsModule::destroy_collection(RuntimeOrigin::signed(ALICE), 0));
+		assert_eq!(LivingAssetsModule::collection_royalty(0), None);
+	});
+}
+
+#[test]
+fn destroy_collection_after_burning_an_asset_with_royalty_should_work() {
+	use crate::{traits::Erc721, AssetRoyalty};
+
+	let asset_id = U256::from(7);
+	new_test_ext().execute_with(|| {
+		assert_ok!(LivingAssetsModule::create_collection(
+			RuntimeOrigin::signed(ALICE),
+			BaseURI::default()
+		));
+		assert_ok!(LivingAssetsModule::set_token_royalty(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			asset_id,
+			H160::from_low_u64_be(BOB),
+			250
+		));
+		assert_ok!(<LivingAssetsModule as Erc721>::burn(H160::from_low_u64_be(7), 0, asset_id));
+		assert_eq!(AssetRoyalty::<Test>::get(0, asset_id), None);
+
+		assert_ok!(LivingAssetsModule::destroy_collection(RuntimeOrigin::signed(ALICE), 0));
+	});
+}
+
+#[test]
+fn make_soulbound_by_non_owner_should_fail() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			LivingAssetsModule::make_soulbound(RuntimeOrigin::signed(ALICE), 0),
+			Error::<Test>::CollectionDoesNotExist
+		);
+		assert_ok!(LivingAssetsModule::create_collection(
+			RuntimeOrigin::signed(ALICE),
+			BaseURI::default()
+		));
+		assert_noop!(
+			LivingAssetsModule::make_soulbound(RuntimeOrigin::signed(BOB), 0),
+			Error::<Test>::NoPermission
+		);
+	});
+}
+
+#[test]
+fn pause_should_block_transfers_until_unpaused() {
+	let asset_id = U256::from(ALICE);
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(LivingAssetsModule::create_collection(
+			RuntimeOrigin::signed(ALICE),
+			BaseURI::default()
+		));
+		assert_ok!(LivingAssetsModule::pause(RuntimeOrigin::signed(ALICE), 0));
+		System::assert_last_event(
+			Event::CollectionPaused { collection_id: 0, who: Some(ALICE) }.into(),
+		);
+		assert!(LivingAssetsModule::is_paused(0));
+		assert_noop!(
+			LivingAssetsModule::pause(RuntimeOrigin::signed(ALICE), 0),
+			Error::<Test>::CollectionIsPaused
+		);
+
+		assert_noop!(
+			LivingAssetsModule::transfer(RuntimeOrigin::signed(ALICE), 0, BOB, asset_id),
+			Error::<Test>::CollectionIsPaused
+		);
+		AssetApproval::<Test>::insert(0, asset_id, H160::from_low_u64_be(BOB));
+		assert_noop!(
+			LivingAssetsModule::transfer_from(RuntimeOrigin::signed(BOB), 0, ALICE, BOB, asset_id),
+			Error::<Test>::CollectionIsPaused
+		);
+		let transfers = BatchTransfers::<Test>::try_from(vec![(0, BOB, asset_id)]).unwrap();
+		assert_noop!(
+			LivingAssetsModule::batch_transfer(RuntimeOrigin::signed(ALICE), ALICE, transfers),
+			Error::<Test>::CollectionIsPaused
+		);
+
+		assert_ok!(LivingAssetsModule::unpause(RuntimeOrigin::signed(ALICE), 0));
+		System::assert_last_event(
+			Event::CollectionUnpaused { collection_id: 0, who: Some(ALICE) }.into(),
+		);
+		assert!(!LivingAssetsModule::is_paused(0));
+		assert_noop!(
+			LivingAssetsModule::unpause(RuntimeOrigin::signed(ALICE), 0),
+			Error::<Test>::CollectionNotPaused
+		);
+		assert_ok!(LivingAssetsModule::transfer(RuntimeOrigin::signed(ALICE), 0, BOB, asset_id));
+	});
+}
+
+#[test]
+fn pause_by_non_owner_should_fail() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			LivingAssetsModule::pause(RuntimeOrigin::signed(ALICE), 0),
+			Error::<Test>::CollectionDoesNotExist
+		);
+		assert_ok!(LivingAssetsModule::create_collection(
+			RuntimeOrigin::signed(ALICE),
+			BaseURI::default()
+		));
+		assert_noop!(
+			LivingAssetsModule::pause(RuntimeOrigin::signed(BOB), 0),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(LivingAssetsModule::pause(RuntimeOrigin::signed(ALICE), 0));
+		assert_noop!(
+			LivingAssetsModule::unpause(RuntimeOrigin::signed(BOB), 0),
+			Error::<Test>::NoPermission
+		);
+	});
+}
+
+#[test]
+fn root_should_pause_and_unpause_any_collection() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			LivingAssetsModule::pause(RuntimeOrigin::root(), 0),
+			Error::<Test>::CollectionDoesNotExist
+		);
+		assert_ok!(LivingAssetsModule::create_collection(
+			RuntimeOrigin::signed(ALICE),
+			BaseURI::default()
+		));
+		assert_ok!(LivingAssetsModule::renounce_collection_ownership(
+			RuntimeOrigin::signed(ALICE),
+			0
+		));
+
+		assert_ok!(LivingAssetsModule::pause(RuntimeOrigin::root(), 0));
+		System::assert_last_event(Event::CollectionPaused { collection_id: 0, who: None }.into());
+		assert!(LivingAssetsModule::is_paused(0));
+
+		assert_ok!(LivingAssetsModule::unpause(RuntimeOrigin::root(), 0));
+		System::assert_last_event(Event::CollectionUnpaused { collection_id: 0, who: None }.into());
+		assert!(!LivingAssetsModule::is_paused(0));
+	});
+}
+
+#[test]
+fn grant_role_should_allow_the_operations_of_the_role_only() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(LivingAssetsModule::create_collection(
+			RuntimeOrigin::signed(ALICE),
+			BaseURI::default()
+		));
+		assert_noop!(
+			LivingAssetsModule::set_base_uri(RuntimeOrigin::signed(BOB), 0, BaseURI::default()),
+			Error::<Test>::NoPermission
+		);
+
+		assert_ok!(LivingAssetsModule::grant_role(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			CollectionRole::MetadataManager,
+			BOB
+		));
+		System::assert_last_event(
+			Event::RoleGranted {
+				collection_id: 0,
+				role: CollectionRole::MetadataManager,
+				account: BOB,
+				sender: ALICE,
+			}
+			.into(),
+		);
+		assert_ok!(LivingAssetsModule::set_base_uri(
+			RuntimeOrigin::signed(BOB),
+			0,
+			BaseURI::try_from(b"ipfs://b".to_vec()).unwrap()
+		));
+		assert_ok!(LivingAssetsModule::freeze_metadata(RuntimeOrigin::signed(BOB), 0));
+
+		assert_noop!(
+			LivingAssetsModule::set_default_royalty(
+				RuntimeOrigin::signed(BOB),
+				0,
+				H160::from_low_u64_be(BOB),
+				100
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			LivingAssetsModule::pause(RuntimeOrigin::signed(BOB), 0),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			LivingAssetsModule::make_soulbound(RuntimeOrigin::signed(BOB), 0),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			LivingAssetsModule::grant_role(
+				RuntimeOrigin::signed(BOB),
+				0,
+				CollectionRole::Pauser,
+				BOB
+			),
+			Error::<Test>::NoPermission
+		);
+		// the ownership itself is not a role
+		assert_noop!(
+			LivingAssetsModule::transfer_collection_ownership(RuntimeOrigin::signed(BOB), 0, BOB),
+			Error::<Test>::NoPermission
+		);
+	});
+}
+
+#[test]
+fn admin_should_grant_and_revoke_roles() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(LivingAssetsModule::create_collection(
+			RuntimeOrigin::signed(ALICE),
+			BaseURI::default()
+		));
+		assert_ok!(LivingAssetsModule::grant_role(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			CollectionRole::Admin,
+			BOB
+		));
+		assert_ok!(LivingAssetsModule::renounce_collection_ownership(
+			RuntimeOrigin::signed(ALICE),
+			0
+		));
+
+		assert_ok!(LivingAssetsModule::grant_role(
+			RuntimeOrigin::signed(BOB),
+			0,
+			CollectionRole::Pauser,
+			ALICE
+		));
+		assert_ok!(LivingAssetsModule::pause(RuntimeOrigin::signed(ALICE), 0));
+		// granting a role twice does nothing
+		let events = System::events().len();
+		assert_ok!(LivingAssetsModule::grant_role(
+			RuntimeOrigin::signed(BOB),
+			0,
+			CollectionRole::Pauser,
+			ALICE
+		));
+		assert_eq!(System::events().len(), events);
+
+		assert_ok!(LivingAssetsModule::revoke_role(
+			RuntimeOrigin::signed(BOB),
+			0,
+			CollectionRole::Pauser,
+			ALICE
+		));
+		System::assert_last_event(
+			Event::RoleRevoked {
+				collection_id: 0,
+				role: CollectionRole::Pauser,
+				account: ALICE,
+				sender: BOB,
+			}
+			.into(),
+		);
+		assert_noop!(
+			LivingAssetsModule::unpause(RuntimeOrigin::signed(ALICE), 0),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			LivingAssetsModule::revoke_role(
+				RuntimeOrigin::signed(ALICE),
+				0,
+				CollectionRole::Admin,
+				BOB
+			),
+			Error::<Test>::NoPermission
+		);
+	});
+}
+
+#[test]
+fn renounce_role_should_remove_it_from_the_caller() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			LivingAssetsModule::renounce_role(
+				RuntimeOrigin::signed(BOB),
+				0,
+				CollectionRole::Pauser
+			),
+			Error::<Test>::CollectionDoesNotExist
+		);
+		assert_ok!(LivingAssetsModule::create_collection(
+			RuntimeOrigin::signed(ALICE),
+			BaseURI::default()
+		));
+		assert_ok!(LivingAssetsModule::grant_role(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			CollectionRole::Pauser,
+			BOB
+		));
+
+		assert_ok!(LivingAssetsModule::renounce_role(
+			RuntimeOrigin::signed(BOB),
+			0,
+			CollectionRole::Pauser
+		));
+		System::assert_last_event(
+			Event::RoleRevoked {
+				collection_id: 0,
+				role: CollectionRole::Pauser,
+				account: BOB,
+				sender: BOB,
+			}
+			.into(),
+		);
+		assert_noop!(
+			LivingAssetsModule::pause(RuntimeOrigin::signed(BOB), 0),
+			Error::<Test>::NoPermission
+		);
+	});
+}
+
+#[test]
+fn create_collection_with_mode_should_only_store_explicit_modes() {
+	let explicit = MintingMode::Explicit { max_supply: Some(10) };
+	new_test_ext().execute_with(|| {
+		assert_ok!(LivingAssetsModule::create_collection_with_mode(
+			RuntimeOrigin::signed(ALICE),
+			Default::default(),
+			Default::default(),
+			BaseURI::default(),
+			explicit
+		));
+		assert_ok!(LivingAssetsModule::create_collection_with_mode(
+			RuntimeOrigin::signed(ALICE),
+			Default::default(),
+			Default::default(),
+			BaseURI::default(),
+			MintingMode::Implicit
+		));
+		assert_eq!(LivingAssetsModule::minting_mode(0), explicit);
+		assert_eq!(LivingAssetsModule::minting_mode(1), MintingMode::Implicit);
+		assert!(!crate::CollectionMintingMode::<Test>::contains_key(1));
+	});
+}
+
+#[test]
+fn mint_should_be_restricted_to_minters() {
+	let asset_id = U256::from(5);
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(LivingAssetsModule::create_collection_with_mode(
+			RuntimeOrigin::signed(ALICE),
+			Default::default(),
+			Default::default(),
+			BaseURI::default(),
+			MintingMode::Explicit { max_supply: None }
+		));
+		assert_noop!(
+			LivingAssetsModule::mint(RuntimeOrigin::signed(BOB), 0, BOB, asset_id),
+			Error::<Test>::NoPermission
+		);
+
+		assert_ok!(LivingAssetsModule::grant_role(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			CollectionRole::Minter,
+			BOB
+		));
+		assert_ok!(LivingAssetsModule::mint(RuntimeOrigin::signed(BOB), 0, ALICE, asset_id));
+		System::assert_last_event(
+			Event::AssetMinted { collection_id: 0, asset_id, owner: ALICE }.into(),
+		);
+		assert_eq!(AssetOwner::<Test>::get(0, asset_id), Some(H160::from_low_u64_be(ALICE)));
+		assert_eq!(OwnerAssetCount::<Test>::get(0, H160::from_low_u64_be(ALICE)), 1);
+		assert_noop!(
+			LivingAssetsModule::mint(RuntimeOrigin::signed(BOB), 0, BOB, asset_id),
+			Error::<Test>::AssetAlreadyExists
+		);
+	});
+}
+
+#[test]
+fn mint_in_implicit_collection_should_fail() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LivingAssetsModule::create_collection(
+			RuntimeOrigin::signed(ALICE),
+			BaseURI::default()
+		));
+		assert_noop!(
+			LivingAssetsModule::mint(RuntimeOrigin::signed(ALICE), 0, ALICE, U256::from(5)),
+			Error::<Test>::CollectionIsImplicit
+		);
+		assert_noop!(
+			LivingAssetsModule::mint(RuntimeOrigin::signed(ALICE), 1, ALICE, U256::from(5)),
+			Error::<Test>::CollectionDoesNotExist
+		);
+	});
+}
+
+#[test]
+fn mint_past_max_supply_should_fail() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LivingAssetsModule::create_collection_with_mode(
+			RuntimeOrigin::signed(ALICE),
+			Default::default(),
+			Default::default(),
+			BaseURI::default(),
+			MintingMode::Explicit { max_supply: Some(1) }
+		));
+		assert_ok!(LivingAssetsModule::mint(RuntimeOrigin::signed(ALICE), 0, BOB, U256::from(1)));
+		assert_noop!(
+			LivingAssetsModule::mint(RuntimeOrigin::signed(ALICE), 0, BOB, U256::from(2)),
+			Error::<Test>::MaxSupplyReached
+		);
+	});
+}
+
+#[test]
+fn genesis_config_should_create_collections_and_set_asset_owners() {
+	let asset_id = U256::from(ALICE);
+	let mut ext: sp_io::TestExternalities = RuntimeGenesisConfig {
+		balances: pallet_balances::GenesisConfig { balances: vec![(ALICE, INITIAL_BALANCE)] },
+		living_assets_module: GenesisConfig {
+			collections: vec![(ALICE, b"ipfs://a".to_vec()), (BOB, b"ipfs://b".to_vec())],
+			asset_owners: vec![(1, asset_id, BOB), (0, asset_id, ALICE)],
+		},
+		..Default::default()
+	}
+	.build_storage()
+	.unwrap()
+	.into();
+
+	ext.execute_with(|| {
+		assert_eq!(LivingAssetsModule::collection_counter(), 2);
+		assert_eq!(LivingAssetsModule::collection_owner(0), Some(ALICE));
+		assert_eq!(LivingAssetsModule::collection_owner(1), Some(BOB));
+		assert_eq!(LivingAssetsModule::collection_base_uri(1).unwrap().to_vec(), b"ipfs://b");
+		assert_eq!(AssetOwner::<Test>::get(1, asset_id), Some(H160::from_low_u64_be(BOB)));
+		// ALICE is the initial owner of the asset, so it is not stored
+		assert_eq!(AssetOwner::<Test>::get(0, asset_id), None);
+		assert_eq!(OwnerAssetCount::<Test>::get(1, H160::from_low_u64_be(BOB)), 1);
+		assert_eq!(
+			OwnerAssetByIndex::<Test>::get((1, H160::from_low_u64_be(BOB), 0)),
+			Some(asset_id)
+		);
+
+		// new collections follow the ones created at genesis
+		assert_ok!(LivingAssetsModule::create_collection(
+			RuntimeOrigin::signed(ALICE),
+			BaseURI::default()
+		));
+		assert_eq!(LivingAssetsModule::collection_owner(2), Some(ALICE));
+	});
+}
+
+#[test]
+#[should_panic(expected = "genesis asset owner of unexistent collection 0")]
+fn genesis_config_with_asset_owner_of_unexistent_collection_should_panic() {
+	let _ = RuntimeGenesisConfig {
+		living_assets_module: GenesisConfig {
+			collections: vec![],
+			asset_owners: vec![(0, U256::from(ALICE), BOB)],
+		},
+		..Default::default()
+	}
+	.build_storage();
+}
+
+#[test]
+#[should_panic(expected = "duplicate genesis asset owner of asset 7 of collection 0")]
+fn genesis_config_with_duplicate_asset_owner_should_panic() {
+	let _ = RuntimeGenesisConfig {
+		living_assets_module: GenesisConfig {
+			collections: vec![(ALICE, b"ipfs://a".to_vec())],
+			asset_owners: vec![(0, U256::from(7), BOB), (0, U256::from(7), ALICE)],
+		},
+		..Default::default()
+	}
+	.build_storage();
+}
+
+#[test]
+fn test_collection_id_to_address() {
+	let collection_id = 5;
+	let expected_address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+	assert_eq!(collection_id_to_address(collection_id), expected_address);
+}
+
+#[test]
+fn invalid_collection_address_should_error() {
+	let address = H160::from_str("8000000000000000000000000000000000000005").unwrap();
+	let error = address_to_collection_id(address).unwrap_err();
+	assert_eq!(error, CollectionError::InvalidPrefix);
+}
+
+#[test]
+fn valid_collection_address_should_return_collection_id() {
+	let address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+	let collection_id = address_to_collection_id(address).unwrap();
+	assert_eq!(collection_id, 5);
+}
+
+#[test]
+fn test_is_collection_address_valid() {
+	let collection_id = 1234567890;
+	let address = collection_id_to_address(collection_id);
+
+	assert!(is_collection_address(address));
+}
+
+#[test]
+fn test_is_collection_address_invalid() {
+	let invalid_address = H160([0u8; 20]);
+
+	assert!(!is_collection_address(invalid_address));
+}
+
+mod traits {
+	use super::*;
+	use crate::{
+		traits::{AssetLockManager, CollectionManager, Erc721},
+		Error, Event,
+	};
+	use frame_support::{assert_err, assert_noop, assert_ok, traits::Get};
+	use sp_core::U256;
+
+	#[test]
+	fn base_uri_of_unexistent_collection_is_none() {
+		new_test_ext().execute_with(|| {
+			assert_eq!(<LivingAssetsModule as CollectionManager>::base_uri(0), None);
+			assert_eq!(<LivingAssetsModule as CollectionManager>::base_uri(1), None);
+		});
+	}
+
+	#[test]
+	fn create_new_collection_should_emit_an_event() {
+		new_test_ext().execute_with(|| {
+			// Go past genesis block so events get deposited
+			System::set_block_number(1);
+
+			assert_ok!(<LivingAssetsModule as CollectionManager>::create_collection(
+				ALICE,
+				BaseURI::default(),
+			));
+			System::assert_last_event(
+				Event::CollectionCreated { collection_id: 0, who: ALICE }.into(),
+			);
+		});
+	}
+
+	#[test]
+	fn living_assets_ownership_trait_id_of_new_collection_should_be_consecutive() {
+		new_test_ext().execute_with(|| {
+			assert_eq!(
+				<LivingAssetsModule as CollectionManager>::create_collection(
+					ALICE,
+					BaseURI::default()
+				)
+				.unwrap(),
+				0
+			);
+			assert_eq!(
+				<LivingAssetsModule as CollectionManager>::create_collection(
+					ALICE,
+					BaseURI::default()
+				)
+				.unwrap(),
+				1
+			);
+			assert_eq!(
+				<LivingAssetsModule as CollectionManager>::create_collection(
+					ALICE,
+					BaseURI::default()
+				)
+				.unwrap(),
+				2
+			);
+			assert_eq!(
+				<LivingAssetsModule as CollectionManager>::create_collection(
+					ALICE,
+					BaseURI::default()
+				)
+				.unwrap(),
+				3
+			);
+			assert_eq!(
+				<LivingAssetsModule as CollectionManager>::create_collection(
+					ALICE,
+					BaseURI::default()
+				)
+				.unwrap(),
+				4
+			);
+			assert_eq!(
+				<LivingAssetsModule as CollectionManager>::create_collection(
+					ALICE,
+					BaseURI::default()
+				)
+				.unwrap(),
+				5
+			);
+		});
+	}
+
+	#[test]
+	fn living_assets_ownership_trait_should_set_base_uri_when_creating_new_collection() {
+		let base_uri = BaseURI::try_from("https://example.com/".as_bytes().to_vec()).unwrap();
+
+		new_test_ext().execute_with(|| {
+			assert_ok!(<LivingAssetsModule as CollectionManager>::create_collection(
+				ALICE,
+				base_uri.clone()
+			));
+			assert_eq!(LivingAssetsModule::collection_base_uri(0).unwrap(), base_uri);
+		});
+	}
+
+	#[test]
+	fn owner_of_asset_of_unexistent_collection_should_error() {
+		new_test_ext().execute_with(|| {
+			let result = <LivingAssetsModule as Erc721>::owner_of(0, 2.into());
+			assert_err!(result, Error::CollectionDoesNotExist);
+		});
+	}
+
+	#[test]
+	fn verify_different_owners_for_same_asset_across_different_collections() {
+		let asset_id = U256::from(
+			hex::decode("03C0F0f4ab324C46e55D02D0033343B4Be8A55532d").unwrap().as_slice(),
+		);
+		let sender = H160::from_str("0000000000000000000000003343b4be8a55532d").unwrap();
+		let receiver = H160::from_low_u64_be(BOB);
+		new_test_ext().execute_with(|| {
+			CollectionBaseURI::<Test>::insert(1, BaseURI::default());
+			CollectionBaseURI::<Test>::insert(2, BaseURI::default());
+			assert_eq!(<LivingAssetsModule as Erc721>::owner_of(1, asset_id).unwrap(), sender);
+			assert_ok!(<LivingAssetsModule as Erc721>::transfer_from(
+				sender, 1, sender, receiver, asset_id,
+			));
+			assert_eq!(<LivingAssetsModule as Erc721>::owner_of(1, asset_id).unwrap(), receiver);
+			assert_eq!(<LivingAssetsModule as Erc721>::owner_of(2, asset_id).unwrap(), sender);
+		});
+	}
+
+	#[test]
+	fn erc721_owner_of_asset_of_collection() {
+		new_test_ext().execute_with(|| {
+			let collection_id = <LivingAssetsModule as CollectionManager>::create_collection(
+				ALICE,
+				BaseURI::default(),
+			)
+			.unwrap();
+			assert_eq!(
+				<LivingAssetsModule as Erc721>::owner_of(collection_id, 2.into()).unwrap(),
+				H160::from_low_u64_be(0x0000000000000002)
+			);
+		});
+	}
+
+	#[test]
+	fn caller_is_not_current_owner_should_fail() {
+		let asset_id = U256::from(5);
+		let sender = H160::from_str("0000000000000000000000000000000000000006").unwrap();
+		let receiver = H160::from_low_u64_be(BOB);
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			assert!(AssetOwner::<Test>::get(0, asset_id).is_none());
+			CollectionBaseURI::<Test>::insert(1, BaseURI::default());
+			assert_noop!(
+				<LivingAssetsModule as Erc721>::transfer_from(
+					H160::from_low_u64_be(ALICE),
+					1,
+					sender,
+					receiver,
+					asset_id,
+				),
+				Error::<Test>::NoPermission
+			);
+		});
+	}
+
+	#[test]
+	fn sender_is_not_current_owner_should_fail() {
+		let asset_id = U256::from(5);
+		let sender = H160::from_str("0000000000000000000000000000000000000006").unwrap();
+		let receiver = H160::from_low_u64_be(BOB);
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			assert!(AssetOwner::<Test>::get(0, asset_id).is_none());
+			CollectionBaseURI::<Test>::insert(1, BaseURI::default());
+			assert_noop!(
+				<LivingAssetsModule as Erc721>::transfer_from(
+					sender, 1, sender, receiver, asset_id,
+				),
+				Error::<Test>::NoPermission
+			);
+		});
+	}
+
+	#[test]
+	fn same_sender_and_receiver_should_fail() {
+		let asset_id = U256::from(5);
+		let sender = H160::from_str("0000000000000000000000000000000000000005").unwrap();
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			assert!(AssetOwner::<Test>::get(0, asset_id).is_none());
+			CollectionBaseURI::<Test>::insert(1, BaseURI::default());
+			assert_noop!(
+				<LivingAssetsModule as Erc721>::transfer_from(sender, 1, sender, sender, asset_id,),
+				Error::<Test>::CannotTransferSelf
+			);
+		});
+	}
+
+	#[test]
+	fn receiver_is_the_zero_address_should_fail() {
+		let asset_id = U256::from(5);
+		let sender = H160::from_str("0000000000000000000000000000000000000005").unwrap();
+		let receiver = H160::from_str("0000000000000000000000000000000000000000").unwrap();
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			assert!(AssetOwner::<Test>::get(0, asset_id).is_none());
+			CollectionBaseURI::<Test>::insert(1, BaseURI::default());
+			assert_noop!(
+				<LivingAssetsModule as Erc721>::transfer_from(
+					sender, 1, sender, receiver, asset_id,
+				),
+				Error::<Test>::TransferToNullAddress
+			);
+		});
+	}
+
+	#[test]
+	fn unexistent_collection_when_transfer_from_should_fail() {
+		let asset_id = U256::from(5);
+		let sender = H160::from_str("0000000000000000000000000000000000000005").unwrap();
+		let receiver = H160::from_low_u64_be(BOB);
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			assert!(AssetOwner::<Test>::get(0, asset_id).is_none());
+			assert_noop!(
+				<LivingAssetsModule as Erc721>::transfer_from(
+					sender, 1, sender, receiver, asset_id,
+				),
+				Error::<Test>::CollectionDoesNotExist
+			);
+		});
+	}
+
+	#[test]
+	fn sucessful_transfer_from_trait_should_work() {
+		let asset_id = U256::from(
+			hex::decode("03C0F0f4ab324C46e55D02D0033343B4Be8A55532d").unwrap().as_slice(),
+		);
+		let sender = H160::from_str("0000000000000000000000003343b4be8a55532d").unwrap();
+		let receiver = H160::from_low_u64_be(BOB);
+		let collection_id = 1;
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			CollectionBaseURI::<Test>::insert(collection_id, BaseURI::default());
+			assert!(AssetOwner::<Test>::get(collection_id, asset_id).is_none());
 			assert_eq!(<LivingAssetsModule as Erc721>::owner_of(1, asset_id).unwrap(), sender);
 			assert_ok!(<LivingAssetsModule as Erc721>::transfer_from(
-				sender, 1, sender, receiver, asset_id,
+				sender, 1, sender, receiver, asset_id,
+			));
+			assert_eq!(AssetOwner::<Test>::get(collection_id, asset_id), Some(receiver));
+			assert_eq!(<LivingAssetsModule as Erc721>::owner_of(1, asset_id).unwrap(), receiver);
+			System::assert_last_event(
+				Event::AssetTransferred { collection_id, asset_id, to: BOB }.into(),
+			);
+		});
+	}
+
+	#[test]
+	fn token_uri_of_unexistent_collection() {
+		new_test_ext().execute_with(|| {
+			let result = <LivingAssetsModule as Erc721>::token_uri(0, 2.into());
+			assert_err!(result, Error::CollectionDoesNotExist);
+		});
+	}
+
+	#[test]
+	fn token_uri_should_concatenate_the_base_uri_with_the_token_id() {
+		let base_uri = BaseURI::try_from("https://example.com".as_bytes().to_vec()).unwrap();
+
+		new_test_ext().execute_with(|| {
+			let collection_id = <LivingAssetsModule as CollectionManager>::create_collection(
+				ALICE,
+				base_uri.clone(),
+			)
+			.unwrap();
+			assert_eq!(
+				<LivingAssetsModule as Erc721>::token_uri(collection_id, 2.into()).unwrap(),
+				"https://example.com/2".as_bytes().to_vec()
+			);
+		});
+	}
+
+	#[test]
+	fn approve_should_set_approved_address_and_emit_event() {
+		let asset_id = U256::from(5);
+		let owner = H160::from_low_u64_be(5);
+		let approved = H160::from_low_u64_be(BOB);
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			CollectionBaseURI::<Test>::insert(1, BaseURI::default());
+			assert_eq!(
+				<LivingAssetsModule as Erc721>::get_approved(1, asset_id).unwrap(),
+				H160::zero()
+			);
+			assert_ok!(<LivingAssetsModule as Erc721>::approve(owner, 1, approved, asset_id));
+			assert_eq!(
+				<LivingAssetsModule as Erc721>::get_approved(1, asset_id).unwrap(),
+				approved
+			);
+			System::assert_last_event(
+				Event::AssetApproved { collection_id: 1, asset_id, owner, approved }.into(),
+			);
+		});
+	}
+
+	#[test]
+	fn approve_zero_address_should_clear_approval() {
+		let asset_id = U256::from(5);
+		let owner = H160::from_low_u64_be(5);
+		new_test_ext().execute_with(|| {
+			CollectionBaseURI::<Test>::insert(1, BaseURI::default());
+			assert_ok!(<LivingAssetsModule as Erc721>::approve(
+				owner,
+				1,
+				H160::from_low_u64_be(BOB),
+				asset_id
+			));
+			assert_ok!(<LivingAssetsModule as Erc721>::approve(owner, 1, H160::zero(), asset_id));
+			assert!(AssetApproval::<Test>::get(1, asset_id).is_none());
+		});
+	}
+
+	#[test]
+	fn approve_by_non_owner_should_fail() {
+		let asset_id = U256::from(5);
+		new_test_ext().execute_with(|| {
+			CollectionBaseURI::<Test>::insert(1, BaseURI::default());
+			assert_noop!(
+				<LivingAssetsModule as Erc721>::approve(
+					H160::from_low_u64_be(ALICE),
+					1,
+					H160::from_low_u64_be(BOB),
+					asset_id
+				),
+				Error::<Test>::NoPermission
+			);
+		});
+	}
+
+	#[test]
+	fn approve_to_current_owner_should_fail() {
+		let asset_id = U256::from(5);
+		let owner = H160::from_low_u64_be(5);
+		new_test_ext().execute_with(|| {
+			CollectionBaseURI::<Test>::insert(1, BaseURI::default());
+			assert_noop!(
+				<LivingAssetsModule as Erc721>::approve(owner, 1, owner, asset_id),
+				Error::<Test>::ApprovalToCurrentOwner
+			);
+		});
+	}
+
+	#[test]
+	fn approve_of_unexistent_collection_should_fail() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				<LivingAssetsModule as Erc721>::approve(
+					H160::from_low_u64_be(5),
+					1,
+					H160::from_low_u64_be(BOB),
+					5.into()
+				),
+				Error::<Test>::CollectionDoesNotExist
+			);
+			assert_err!(
+				<LivingAssetsModule as Erc721>::get_approved(1, 5.into()),
+				Error::<Test>::CollectionDoesNotExist
+			);
+		});
+	}
+
+	#[test]
+	fn approved_address_should_transfer_and_approval_should_be_cleared() {
+		let asset_id = U256::from(5);
+		let owner = H160::from_low_u64_be(5);
+		let approved = H160::from_low_u64_be(ALICE);
+		let receiver = H160::from_low_u64_be(BOB);
+		new_test_ext().execute_with(|| {
+			CollectionBaseURI::<Test>::insert(1, BaseURI::default());
+			assert_ok!(<LivingAssetsModule as Erc721>::approve(owner, 1, approved, asset_id));
+			assert_ok!(<LivingAssetsModule as Erc721>::transfer_from(
+				approved, 1, owner, receiver, asset_id,
+			));
+			assert_eq!(<LivingAssetsModule as Erc721>::owner_of(1, asset_id).unwrap(), receiver);
+			assert_eq!(
+				<LivingAssetsModule as Erc721>::get_approved(1, asset_id).unwrap(),
+				H160::zero()
+			);
+			assert_noop!(
+				<LivingAssetsModule as Erc721>::transfer_from(
+					approved, 1, receiver, owner, asset_id,
+				),
+				Error::<Test>::NoPermission
+			);
+		});
+	}
+
+	#[test]
+	fn set_approval_for_all_should_emit_event() {
+		let owner = H160::from_low_u64_be(5);
+		let operator = H160::from_low_u64_be(ALICE);
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			CollectionBaseURI::<Test>::insert(1, BaseURI::default());
+			assert!(
+				!<LivingAssetsModule as Erc721>::is_approved_for_all(1, owner, operator).unwrap()
+			);
+			assert_ok!(<LivingAssetsModule as Erc721>::set_approval_for_all(
+				owner, 1, operator, true
+			));
+			assert!(
+				<LivingAssetsModule as Erc721>::is_approved_for_all(1, owner, operator).unwrap()
+			);
+			System::assert_last_event(
+				Event::ApprovalForAll { collection_id: 1, owner, operator, approved: true }.into(),
+			);
+			assert_ok!(<LivingAssetsModule as Erc721>::set_approval_for_all(
+				owner, 1, operator, false
+			));
+			assert!(
+				!<LivingAssetsModule as Erc721>::is_approved_for_all(1, owner, operator).unwrap()
+			);
+		});
+	}
+
+	#[test]
+	fn set_approval_for_all_to_caller_should_fail() {
+		let owner = H160::from_low_u64_be(5);
+		new_test_ext().execute_with(|| {
+			CollectionBaseURI::<Test>::insert(1, BaseURI::default());
+			assert_noop!(
+				<LivingAssetsModule as Erc721>::set_approval_for_all(owner, 1, owner, true),
+				Error::<Test>::ApproveToCaller
+			);
+		});
+	}
+
+	#[test]
+	fn operator_should_transfer_and_approve_assets_of_the_owner() {
+		let owner = H160::from_low_u64_be(5);
+		let operator = H160::from_low_u64_be(ALICE);
+		let receiver = H160::from_low_u64_be(BOB);
+		new_test_ext().execute_with(|| {
+			CollectionBaseURI::<Test>::insert(1, BaseURI::default());
+			CollectionBaseURI::<Test>::insert(2, BaseURI::default());
+			assert_ok!(<LivingAssetsModule as Erc721>::set_approval_for_all(
+				owner, 1, operator, true
+			));
+			assert_ok!(<LivingAssetsModule as Erc721>::approve(operator, 1, receiver, 5.into()));
+			assert_ok!(<LivingAssetsModule as Erc721>::transfer_from(
+				operator,
+				1,
+				owner,
+				receiver,
+				5.into(),
+			));
+			assert_eq!(<LivingAssetsModule as Erc721>::owner_of(1, 5.into()).unwrap(), receiver);
+
+			// operator approvals are scoped to the collection
+			assert_noop!(
+				<LivingAssetsModule as Erc721>::transfer_from(
+					operator,
+					2,
+					owner,
+					receiver,
+					5.into(),
+				),
+				Error::<Test>::NoPermission
+			);
+		});
+	}
+
+	#[test]
+	fn owner_of_collection_should_return_the_creator() {
+		new_test_ext().execute_with(|| {
+			assert_eq!(<LivingAssetsModule as CollectionManager>::owner_of_collection(0), None);
+			let collection_id = <LivingAssetsModule as CollectionManager>::create_collection(
+				ALICE,
+				BaseURI::default(),
+			)
+			.unwrap();
+			assert_eq!(
+				<LivingAssetsModule as CollectionManager>::owner_of_collection(collection_id),
+				Some(ALICE)
+			);
+		});
+	}
+
+	#[test]
+	fn transfer_collection_ownership_trait_should_work() {
+		new_test_ext().execute_with(|| {
+			let collection_id = <LivingAssetsModule as CollectionManager>::create_collection(
+				ALICE,
+				BaseURI::default(),
+			)
+			.unwrap();
+			assert_noop!(
+				<LivingAssetsModule as CollectionManager>::transfer_collection_ownership(
+					BOB,
+					collection_id,
+					BOB
+				),
+				Error::<Test>::NoPermission
+			);
+			assert_ok!(<LivingAssetsModule as CollectionManager>::transfer_collection_ownership(
+				ALICE,
+				collection_id,
+				BOB
+			));
+			assert_eq!(
+				<LivingAssetsModule as CollectionManager>::owner_of_collection(collection_id),
+				Some(BOB)
+			);
+			assert_ok!(<LivingAssetsModule as CollectionManager>::renounce_collection_ownership(
+				BOB,
+				collection_id
+			));
+			assert_eq!(
+				<LivingAssetsModule as CollectionManager>::owner_of_collection(collection_id),
+				None
+			);
+		});
+	}
+
+	#[test]
+	fn set_base_uri_should_change_the_token_uri() {
+		let base_uri = BaseURI::try_from("https://example.com".as_bytes().to_vec()).unwrap();
+		let new_base_uri =
+			BaseURI::try_from("https://new.example.com".as_bytes().to_vec()).unwrap();
+
+		new_test_ext().execute_with(|| {
+			let collection_id =
+				<LivingAssetsModule as CollectionManager>::create_collection(ALICE, base_uri)
+					.unwrap();
+			assert_ok!(<LivingAssetsModule as CollectionManager>::set_base_uri(
+				ALICE,
+				collection_id,
+				new_base_uri.clone()
+			));
+			assert_eq!(
+				<LivingAssetsModule as CollectionManager>::base_uri(collection_id),
+				Some(new_base_uri)
+			);
+			assert_eq!(
+				<LivingAssetsModule as Erc721>::token_uri(collection_id, 2.into()).unwrap(),
+				"https://new.example.com/2".as_bytes().to_vec()
+			);
+		});
+	}
+
+	#[test]
+	fn token_uri_should_prefer_the_asset_token_uri() {
+		let base_uri = BaseURI::try_from("https://example.com".as_bytes().to_vec()).unwrap();
+		let token_uri = BaseURI::try_from("ipfs://QmToken".as_bytes().to_vec()).unwrap();
+
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			let collection_id =
+				<LivingAssetsModule as CollectionManager>::create_collection(ALICE, base_uri)
+					.unwrap();
+			assert_ok!(<LivingAssetsModule as CollectionManager>::set_token_uri(
+				ALICE,
+				collection_id,
+				2.into(),
+				token_uri.clone()
+			));
+			System::assert_last_event(
+				Event::TokenURIUpdated {
+					collection_id,
+					asset_id: 2.into(),
+					token_uri: token_uri.clone(),
+				}
+				.into(),
+			);
+			assert_eq!(
+				<LivingAssetsModule as Erc721>::token_uri(collection_id, 2.into()).unwrap(),
+				token_uri.to_vec()
+			);
+			assert_eq!(
+				<LivingAssetsModule as Erc721>::token_uri(collection_id, 3.into()).unwrap(),
+				"https://example.com/3".as_bytes().to_vec()
+			);
+		});
+	}
+
+	#[test]
+	fn empty_token_uri_should_restore_the_base_uri() {
+		let base_uri = BaseURI::try_from("https://example.com".as_bytes().to_vec()).unwrap();
+		let token_uri = BaseURI::try_from("ipfs://QmToken".as_bytes().to_vec()).unwrap();
+
+		new_test_ext().execute_with(|| {
+			let collection_id =
+				<LivingAssetsModule as CollectionManager>::create_collection(ALICE, base_uri)
+					.unwrap();
+			assert_ok!(<LivingAssetsModule as CollectionManager>::set_token_uri(
+				ALICE,
+				collection_id,
+				2.into(),
+				token_uri
+			));
+			assert_ok!(<LivingAssetsModule as CollectionManager>::set_token_uri(
+				ALICE,
+				collection_id,
+				2.into(),
+				BaseURI::default()
+			));
+			assert_eq!(LivingAssetsModule::asset_token_uri(collection_id, U256::from(2)), None);
+			assert_eq!(
+				<LivingAssetsModule as Erc721>::token_uri(collection_id, 2.into()).unwrap(),
+				"https://example.com/2".as_bytes().to_vec()
+			);
+		});
+	}
+
+	#[test]
+	fn set_token_uri_by_non_owner_should_fail() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				<LivingAssetsModule as CollectionManager>::set_token_uri(
+					ALICE,
+					0,
+					2.into(),
+					BaseURI::default()
+				),
+				Error::<Test>::CollectionDoesNotExist
+			);
+			let collection_id = <LivingAssetsModule as CollectionManager>::create_collection(
+				ALICE,
+				BaseURI::default(),
+			)
+			.unwrap();
+			assert_noop!(
+				LivingAssetsModule::set_token_uri(
+					RuntimeOrigin::signed(BOB),
+					collection_id,
+					2.into(),
+					BaseURI::default()
+				),
+				Error::<Test>::NoPermission
+			);
+		});
+	}
+
+	#[test]
+	fn burn_should_remove_the_asset_and_emit_event() {
+		let asset_id = U256::from(5);
+		let owner = H160::from_low_u64_be(5);
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			CollectionBaseURI::<Test>::insert(1, BaseURI::default());
+			assert_ok!(<LivingAssetsModule as Erc721>::burn(owner, 1, asset_id));
+			System::assert_last_event(
+				Event::AssetBurned { collection_id: 1, asset_id, owner: 5 }.into(),
+			);
+			assert_err!(
+				<LivingAssetsModule as Erc721>::owner_of(1, asset_id),
+				Error::<Test>::AssetDoesNotExist
+			);
+			assert_err!(
+				<LivingAssetsModule as Erc721>::token_uri(1, asset_id),
+				Error::<Test>::AssetDoesNotExist
+			);
+			assert_err!(
+				<LivingAssetsModule as Erc721>::get_approved(1, asset_id),
+				Error::<Test>::AssetDoesNotExist
+			);
+		});
+	}
+
+	#[test]
+	fn burned_asset_should_not_be_transferred_or_burned_again() {
+		let asset_id = U256::from(5);
+		let owner = H160::from_low_u64_be(5);
+		new_test_ext().execute_with(|| {
+			CollectionBaseURI::<Test>::insert(1, BaseURI::default());
+			assert_ok!(<LivingAssetsModule as Erc721>::burn(owner, 1, asset_id));
+			assert_noop!(
+				<LivingAssetsModule as Erc721>::transfer_from(
+					owner,
+					1,
+					owner,
+					H160::from_low_u64_be(BOB),
+					asset_id,
+				),
+				Error::<Test>::AssetDoesNotExist
+			);
+			assert_noop!(
+				<LivingAssetsModule as Erc721>::approve(
+					owner,
+					1,
+					H160::from_low_u64_be(BOB),
+					asset_id
+				),
+				Error::<Test>::AssetDoesNotExist
+			);
+			assert_noop!(
+				<LivingAssetsModule as Erc721>::burn(owner, 1, asset_id),
+				Error::<Test>::AssetDoesNotExist
+			);
+		});
+	}
+
+	#[test]
+	fn burn_by_approved_address_should_work() {
+		let asset_id = U256::from(5);
+		let owner = H160::from_low_u64_be(5);
+		let approved = H160::from_low_u64_be(ALICE);
+		new_test_ext().execute_with(|| {
+			CollectionBaseURI::<Test>::insert(1, BaseURI::default());
+			assert_ok!(<LivingAssetsModule as Erc721>::approve(owner, 1, approved, asset_id));
+			assert_ok!(<LivingAssetsModule as Erc721>::burn(approved, 1, asset_id));
+			assert_eq!(AssetApproval::<Test>::get(1, asset_id), None);
+		});
+	}
+
+	#[test]
+	fn burn_by_non_owner_should_fail() {
+		let asset_id = U256::from(5);
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				<LivingAssetsModule as Erc721>::burn(H160::from_low_u64_be(BOB), 1, asset_id),
+				Error::<Test>::CollectionDoesNotExist
+			);
+			CollectionBaseURI::<Test>::insert(1, BaseURI::default());
+			assert_noop!(
+				<LivingAssetsModule as Erc721>::burn(H160::from_low_u64_be(BOB), 1, asset_id),
+				Error::<Test>::NoPermission
+			);
+		});
+	}
+
+	#[test]
+	fn locked_should_report_whether_the_collection_is_soulbound() {
+		let asset_id = U256::from(5);
+		let owner = H160::from_low_u64_be(5);
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				<LivingAssetsModule as Erc721>::locked(0, asset_id),
+				Error::<Test>::CollectionDoesNotExist
+			);
+			assert_ok!(<LivingAssetsModule as CollectionManager>::create_collection(
+				ALICE,
+				BaseURI::default()
+			));
+			assert_eq!(<LivingAssetsModule as Erc721>::locked(0, asset_id), Ok(false));
+			assert_ok!(<LivingAssetsModule as CollectionManager>::make_soulbound(ALICE, 0));
+			assert_eq!(<LivingAssetsModule as Erc721>::locked(0, asset_id), Ok(true));
+
+			// soulbound assets can still be burned
+			assert_ok!(<LivingAssetsModule as Erc721>::burn(owner, 0, asset_id));
+			assert_noop!(
+				<LivingAssetsModule as Erc721>::locked(0, asset_id),
+				Error::<Test>::AssetDoesNotExist
+			);
+		});
+	}
+
+	#[test]
+	fn has_role_should_hold_for_the_owner_and_granted_accounts() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				<LivingAssetsModule as CollectionManager>::has_role(
+					0,
+					CollectionRole::Admin,
+					ALICE
+				),
+				Error::<Test>::CollectionDoesNotExist
+			);
+			assert_ok!(<LivingAssetsModule as CollectionManager>::create_collection(
+				ALICE,
+				BaseURI::default()
+			));
+			assert_eq!(
+				<LivingAssetsModule as CollectionManager>::has_role(
+					0,
+					CollectionRole::RoyaltyManager,
+					ALICE
+				),
+				Ok(true)
+			);
+			assert_eq!(
+				<LivingAssetsModule as CollectionManager>::has_role(
+					0,
+					CollectionRole::RoyaltyManager,
+					BOB
+				),
+				Ok(false)
+			);
+
+			assert_eq!(
+				<LivingAssetsModule as CollectionManager>::grant_role(
+					ALICE,
+					0,
+					CollectionRole::RoyaltyManager,
+					BOB
+				),
+				Ok(true)
+			);
+			assert_eq!(
+				<LivingAssetsModule as CollectionManager>::grant_role(
+					ALICE,
+					0,
+					CollectionRole::RoyaltyManager,
+					BOB
+				),
+				Ok(false)
+			);
+			assert_eq!(
+				<LivingAssetsModule as CollectionManager>::has_role(
+					0,
+					CollectionRole::RoyaltyManager,
+					BOB
+				),
+				Ok(true)
+			);
+			assert_ok!(<LivingAssetsModule as CollectionManager>::set_default_royalty(
+				BOB,
+				0,
+				H160::from_low_u64_be(BOB),
+				100
+			));
+
+			// the roles of the owner are implicit, so they can not be revoked
+			assert_eq!(
+				<LivingAssetsModule as CollectionManager>::revoke_role(
+					BOB,
+					0,
+					CollectionRole::RoyaltyManager,
+					ALICE
+				),
+				Err(Error::<Test>::NoPermission)
+			);
+			assert_eq!(
+				<LivingAssetsModule as CollectionManager>::revoke_role(
+					ALICE,
+					0,
+					CollectionRole::RoyaltyManager,
+					ALICE
+				),
+				Ok(false)
+			);
+			assert_eq!(
+				<LivingAssetsModule as CollectionManager>::renounce_role(
+					BOB,
+					0,
+					CollectionRole::RoyaltyManager
+				),
+				Ok(true)
+			);
+			assert_eq!(
+				<LivingAssetsModule as CollectionManager>::has_role(
+					0,
+					CollectionRole::RoyaltyManager,
+					BOB
+				),
+				Ok(false)
+			);
+		});
+	}
+
+	#[test]
+	fn paused_collection_should_block_transfers_and_burns() {
+		let asset_id = U256::from(5);
+		let owner = H160::from_low_u64_be(5);
+		let bob = H160::from_low_u64_be(BOB);
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				<LivingAssetsModule as Erc721>::paused(0),
+				Error::<Test>::CollectionDoesNotExist
+			);
+			assert_ok!(<LivingAssetsModule as CollectionManager>::create_collection(
+				ALICE,
+				BaseURI::default()
+			));
+			assert_eq!(<LivingAssetsModule as Erc721>::paused(0), Ok(false));
+			assert_noop!(
+				<LivingAssetsModule as CollectionManager>::pause(BOB, 0),
+				Error::<Test>::NoPermission
+			);
+			assert_ok!(<LivingAssetsModule as CollectionManager>::pause(ALICE, 0));
+			assert_eq!(<LivingAssetsModule as Erc721>::paused(0), Ok(true));
+
+			assert_noop!(
+				<LivingAssetsModule as Erc721>::transfer_from(owner, 0, owner, bob, asset_id),
+				Error::<Test>::CollectionIsPaused
+			);
+			assert_noop!(
+				<LivingAssetsModule as Erc721>::batch_transfer_from(
+					owner,
+					owner,
+					vec![(0, bob, asset_id)]
+				),
+				Error::<Test>::CollectionIsPaused
+			);
+			assert_noop!(
+				<LivingAssetsModule as Erc721>::burn(owner, 0, asset_id),
+				Error::<Test>::CollectionIsPaused
+			);
+
+			assert_ok!(<LivingAssetsModule as CollectionManager>::unpause(ALICE, 0));
+			assert_eq!(<LivingAssetsModule as Erc721>::paused(0), Ok(false));
+			assert_ok!(<LivingAssetsModule as Erc721>::burn(owner, 0, asset_id));
+		});
+	}
+
+	#[test]
+	fn set_lock_should_emit_an_event_and_block_transfers_and_burns() {
+		let asset_id = U256::from(5);
+		let owner = H160::from_low_u64_be(5);
+		let bob = H160::from_low_u64_be(BOB);
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			assert_ok!(<LivingAssetsModule as CollectionManager>::create_collection(
+				ALICE,
+				BaseURI::default()
+			));
+			assert_eq!(<LivingAssetsModule as Erc721>::is_locked(0, asset_id), Ok(false));
+
+			assert_ok!(<LivingAssetsModule as AssetLockManager>::set_lock(
+				*b"staking ",
+				0,
+				asset_id
+			));
+			System::assert_last_event(
+				Event::AssetLocked { collection_id: 0, asset_id, id: *b"staking " }.into(),
+			);
+			assert!(<LivingAssetsModule as AssetLockManager>::is_locked(0, asset_id));
+			assert_eq!(<LivingAssetsModule as Erc721>::is_locked(0, asset_id), Ok(true));
+
+			assert_noop!(
+				<LivingAssetsModule as Erc721>::transfer_from(owner, 0, owner, bob, asset_id),
+				Error::<Test>::AssetIsLocked
+			);
+			assert_noop!(
+				<LivingAssetsModule as Erc721>::batch_transfer_from(
+					owner,
+					owner,
+					vec![(0, bob, U256::from(5) + (U256::from(2) << 128)), (0, bob, asset_id)]
+				),
+				Error::<Test>::AssetIsLocked
+			);
+			assert_noop!(
+				<LivingAssetsModule as Erc721>::burn(owner, 0, asset_id),
+				Error::<Test>::AssetIsLocked
+			);
+			// other assets of the collection are not affected
+			assert_ok!(<LivingAssetsModule as Erc721>::transfer_from(
+				owner,
+				0,
+				owner,
+				bob,
+				U256::from(5) + (U256::from(1) << 128)
+			));
+		});
+	}
+
+	#[test]
+	fn asset_should_stay_locked_until_every_lock_is_removed() {
+		let asset_id = U256::from(5);
+		let owner = H160::from_low_u64_be(5);
+		let bob = H160::from_low_u64_be(BOB);
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			assert_ok!(<LivingAssetsModule as CollectionManager>::create_collection(
+				ALICE,
+				BaseURI::default()
+			));
+			assert_ok!(<LivingAssetsModule as AssetLockManager>::set_lock(
+				*b"staking ",
+				0,
+				asset_id
+			));
+			assert_ok!(<LivingAssetsModule as AssetLockManager>::set_lock(
+				*b"market  ",
+				0,
+				asset_id
+			));
+			// placing a lock twice does nothing
+			let events = System::events().len();
+			assert_ok!(<LivingAssetsModule as AssetLockManager>::set_lock(
+				*b"staking ",
+				0,
+				asset_id
+			));
+			assert_eq!(System::events().len(), events);
+
+			assert_ok!(<LivingAssetsModule as AssetLockManager>::remove_lock(
+				*b"staking ",
+				0,
+				asset_id
+			));
+			System::assert_last_event(
+				Event::AssetUnlocked { collection_id: 0, asset_id, id: *b"staking " }.into(),
+			);
+			assert_eq!(<LivingAssetsModule as Erc721>::is_locked(0, asset_id), Ok(true));
+			assert_noop!(
+				<LivingAssetsModule as Erc721>::transfer_from(owner, 0, owner, bob, asset_id),
+				Error::<Test>::AssetIsLocked
+			);
+
+			assert_ok!(<LivingAssetsModule as AssetLockManager>::remove_lock(
+				*b"market  ",
+				0,
+				asset_id
+			));
+			assert_eq!(<LivingAssetsModule as Erc721>::is_locked(0, asset_id), Ok(false));
+			// removing a lock the asset does not hold does nothing
+			let events = System::events().len();
+			assert_ok!(<LivingAssetsModule as AssetLockManager>::remove_lock(
+				*b"market  ",
+				0,
+				asset_id
+			));
+			assert_eq!(System::events().len(), events);
+
+			assert_ok!(<LivingAssetsModule as Erc721>::transfer_from(
+				owner, 0, owner, bob, asset_id
+			));
+		});
+	}
+
+	#[test]
+	fn set_lock_should_fail_on_unexistent_assets_and_past_max_locks() {
+		let asset_id = U256::from(5);
+		let owner = H160::from_low_u64_be(5);
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				<LivingAssetsModule as AssetLockManager>::set_lock(*b"staking ", 0, asset_id),
+				Error::<Test>::CollectionDoesNotExist
+			);
+			assert_ok!(<LivingAssetsModule as CollectionManager>::create_collection(
+				ALICE,
+				BaseURI::default()
+			));
+
+			let max_locks = <Test as crate::Config>::MaxLocksPerAsset::get();
+			for i in 0..max_locks {
+				assert_ok!(<LivingAssetsModule as AssetLockManager>::set_lock(
+					[i as u8; 8],
+					0,
+					asset_id
+				));
+			}
+			assert_noop!(
+				<LivingAssetsModule as AssetLockManager>::set_lock(*b"staking ", 0, asset_id),
+				Error::<Test>::TooManyLocks
+			);
+
+			let burned = U256::from(5) + (U256::from(1) << 128);
+			assert_ok!(<LivingAssetsModule as Erc721>::burn(owner, 0, burned));
+			assert_noop!(
+				<LivingAssetsModule as AssetLockManager>::set_lock(*b"staking ", 0, burned),
+				Error::<Test>::AssetDoesNotExist
+			);
+			assert_noop!(
+				<LivingAssetsModule as Erc721>::is_locked(0, burned),
+				Error::<Test>::AssetDoesNotExist
+			);
+		});
+	}
+
+	#[test]
+	fn destroy_collection_with_locked_assets_should_fail() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(<LivingAssetsModule as CollectionManager>::create_collection(
+				ALICE,
+				BaseURI::default()
+			));
+			assert_ok!(<LivingAssetsModule as AssetLockManager>::set_lock(
+				*b"staking ",
+				0,
+				U256::from(5)
+			));
+			assert_noop!(
+				<LivingAssetsModule as CollectionManager>::destroy_collection(ALICE, 0),
+				Error::<Test>::CollectionNotEmpty
+			);
+
+			assert_ok!(<LivingAssetsModule as AssetLockManager>::remove_lock(
+				*b"staking ",
+				0,
+				U256::from(5)
+			));
+			assert_ok!(<LivingAssetsModule as CollectionManager>::destroy_collection(ALICE, 0));
+		});
+	}
+
+	#[test]
+	fn set_user_should_emit_an_event_and_expire() {
+		let asset_id = U256::from(5);
+		let owner = H160::from_low_u64_be(5);
+		let user = H160::from_low_u64_be(BOB);
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			CollectionBaseURI::<Test>::insert(1, BaseURI::default());
+			assert_eq!(<LivingAssetsModule as Erc721>::user_of(1, asset_id).unwrap(), H160::zero());
+			assert_eq!(<LivingAssetsModule as Erc721>::user_expires(1, asset_id).unwrap(), 0);
+
+			assert_ok!(<LivingAssetsModule as Erc721>::set_user(owner, 1, asset_id, user, 10));
+			System::assert_last_event(
+				Event::AssetUserUpdated { collection_id: 1, asset_id, user, expires: 10 }.into(),
+			);
+			assert_eq!(<LivingAssetsModule as Erc721>::user_of(1, asset_id).unwrap(), user);
+			assert_eq!(<LivingAssetsModule as Erc721>::user_expires(1, asset_id).unwrap(), 10);
+
+			// the user can use the asset until the second it expires, included
+			Timestamp::set_timestamp(10_999);
+			assert_eq!(<LivingAssetsModule as Erc721>::user_of(1, asset_id).unwrap(), user);
+			Timestamp::set_timestamp(11_000);
+			assert_eq!(<LivingAssetsModule as Erc721>::user_of(1, asset_id).unwrap(), H160::zero());
+			assert_eq!(<LivingAssetsModule as Erc721>::user_expires(1, asset_id).unwrap(), 10);
+		});
+	}
+
+	#[test]
+	fn set_user_by_non_owner_should_fail() {
+		let asset_id = U256::from(5);
+		let user = H160::from_low_u64_be(BOB);
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				<LivingAssetsModule as Erc721>::set_user(user, 1, asset_id, user, 10),
+				Error::<Test>::CollectionDoesNotExist
+			);
+			CollectionBaseURI::<Test>::insert(1, BaseURI::default());
+			assert_noop!(
+				<LivingAssetsModule as Erc721>::set_user(user, 1, asset_id, user, 10),
+				Error::<Test>::NoPermission
+			);
+
+			// the address approved for the asset can set its user
+			assert_ok!(<LivingAssetsModule as Erc721>::approve(
+				H160::from_low_u64_be(5),
+				1,
+				user,
+				asset_id
+			));
+			assert_ok!(<LivingAssetsModule as Erc721>::set_user(user, 1, asset_id, user, 10));
+		});
+	}
+
+	#[test]
+	fn transfer_should_reset_the_user() {
+		let asset_id = U256::from(5);
+		let owner = H160::from_low_u64_be(5);
+		let user = H160::from_low_u64_be(BOB);
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			CollectionBaseURI::<Test>::insert(1, BaseURI::default());
+			assert_ok!(<LivingAssetsModule as Erc721>::set_user(owner, 1, asset_id, user, 10));
+			assert_ok!(<LivingAssetsModule as Erc721>::transfer_from(
+				owner,
+				1,
+				owner,
+				H160::from_low_u64_be(ALICE),
+				asset_id,
+			));
+			System::assert_has_event(
+				Event::AssetUserUpdated {
+					collection_id: 1,
+					asset_id,
+					user: H160::zero(),
+					expires: 0,
+				}
+				.into(),
+			);
+			assert_eq!(<LivingAssetsModule as Erc721>::user_of(1, asset_id).unwrap(), H160::zero());
+			assert_eq!(<LivingAssetsModule as Erc721>::user_expires(1, asset_id).unwrap(), 0);
+		});
+	}
+
+	#[test]
+	fn burn_should_silently_remove_an_expired_user() {
+		let asset_id = U256::from(5);
+		let owner = H160::from_low_u64_be(5);
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			CollectionBaseURI::<Test>::insert(1, BaseURI::default());
+			assert_ok!(<LivingAssetsModule as Erc721>::set_user(
+				owner,
+				1,
+				asset_id,
+				H160::from_low_u64_be(BOB),
+				10
+			));
+			Timestamp::set_timestamp(11_000);
+			System::reset_events();
+
+			assert_ok!(<LivingAssetsModule as Erc721>::burn(owner, 1, asset_id));
+			assert_eq!(System::events().len(), 1);
+			assert!(AssetUser::<Test>::get(1, asset_id).is_none());
+		});
+	}
+
+	#[test]
+	fn unminted_asset_of_explicit_collection_should_not_exist() {
+		let asset_id = U256::from(5);
+		let initial_owner = H160::from_low_u64_be(5);
+		new_test_ext().execute_with(|| {
+			assert_ok!(<LivingAssetsModule as CollectionManager>::create_collection_with_mode(
+				ALICE,
+				Default::default(),
+				Default::default(),
+				BaseURI::default(),
+				MintingMode::Explicit { max_supply: None },
+			));
+			assert_ok!(<LivingAssetsModule as CollectionManager>::create_collection(
+				ALICE,
+				BaseURI::default()
+			));
+
+			assert_err!(
+				<LivingAssetsModule as Erc721>::owner_of(0, asset_id),
+				Error::<Test>::AssetDoesNotExist
+			);
+			assert_err!(
+				<LivingAssetsModule as Erc721>::token_uri(0, asset_id),
+				Error::<Test>::AssetDoesNotExist
+			);
+			assert_noop!(
+				<LivingAssetsModule as Erc721>::transfer_from(
+					initial_owner,
+					0,
+					initial_owner,
+					H160::from_low_u64_be(BOB),
+					asset_id,
+				),
+				Error::<Test>::AssetDoesNotExist
+			);
+			// implicit collections are unchanged
+			assert_eq!(<LivingAssetsModule as Erc721>::owner_of(1, asset_id), Ok(initial_owner));
+		});
+	}
+
+	#[test]
+	fn total_supply_should_count_minted_assets_that_were_not_burned() {
+		let owner = H160::from_low_u64_be(BOB);
+		new_test_ext().execute_with(|| {
+			assert_ok!(<LivingAssetsModule as CollectionManager>::create_collection_with_mode(
+				ALICE,
+				Default::default(),
+				Default::default(),
+				BaseURI::default(),
+				MintingMode::Explicit { max_supply: Some(2) },
+			));
+			assert_eq!(<LivingAssetsModule as CollectionManager>::total_supply(0), Ok(0));
+			for asset_id in [1, 2] {
+				assert_ok!(<LivingAssetsModule as CollectionManager>::mint(
+					ALICE,
+					0,
+					owner,
+					U256::from(asset_id)
+				));
+			}
+			assert_eq!(<LivingAssetsModule as CollectionManager>::total_supply(0), Ok(2));
+			assert_noop!(
+				<LivingAssetsModule as CollectionManager>::mint(ALICE, 0, owner, U256::from(3)),
+				Error::<Test>::MaxSupplyReached
+			);
+
+			// the cap applies to the assets in existence, but burned ids can not be minted again
+			assert_ok!(<LivingAssetsModule as Erc721>::burn(owner, 0, U256::from(1)));
+			assert_eq!(<LivingAssetsModule as CollectionManager>::total_supply(0), Ok(1));
+			assert_noop!(
+				<LivingAssetsModule as CollectionManager>::mint(ALICE, 0, owner, U256::from(1)),
+				Error::<Test>::AssetAlreadyExists
+			);
+			assert_ok!(<LivingAssetsModule as CollectionManager>::mint(
+				ALICE,
+				0,
+				owner,
+				U256::from(3)
+			));
+		});
+	}
+
+	#[test]
+	fn total_supply_of_implicit_collection_should_fail() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(<LivingAssetsModule as CollectionManager>::create_collection(
+				ALICE,
+				BaseURI::default()
+			));
+			assert_err!(
+				<LivingAssetsModule as CollectionManager>::total_supply(0),
+				Error::<Test>::CollectionIsImplicit
+			);
+			assert_err!(
+				<LivingAssetsModule as CollectionManager>::total_supply(1),
+				Error::<Test>::CollectionDoesNotExist
+			);
+		});
+	}
+
+	#[test]
+	fn transfer_to_initial_owner_in_explicit_collection_should_keep_the_asset() {
+		let asset_id = U256::from(5);
+		let initial_owner = H160::from_low_u64_be(5);
+		let owner = H160::from_low_u64_be(BOB);
+		new_test_ext().execute_with(|| {
+			assert_ok!(<LivingAssetsModule as CollectionManager>::create_collection_with_mode(
+				ALICE,
+				Default::default(),
+				Default::default(),
+				BaseURI::default(),
+				MintingMode::Explicit { max_supply: None },
+			));
+			assert_ok!(<LivingAssetsModule as CollectionManager>::mint(ALICE, 0, owner, asset_id));
+			assert_ok!(<LivingAssetsModule as Erc721>::transfer_from(
+				owner,
+				0,
+				owner,
+				initial_owner,
+				asset_id
+			));
+			assert_eq!(AssetOwner::<Test>::get(0, asset_id), Some(initial_owner));
+			assert_eq!(<LivingAssetsModule as Erc721>::balance_of(0, initial_owner), Ok(1));
+		});
+	}
+
+	#[test]
+	fn name_and_symbol_should_be_empty_unless_given_at_creation() {
+		new_test_ext().execute_with(|| {
+			assert_eq!(<LivingAssetsModule as CollectionManager>::name(0), None);
+			assert_eq!(<LivingAssetsModule as CollectionManager>::symbol(0), None);
+
+			assert_ok!(<LivingAssetsModule as CollectionManager>::create_collection(
+				ALICE,
+				BaseURI::default()
+			));
+			assert_eq!(
+				<LivingAssetsModule as CollectionManager>::name(0),
+				Some(Default::default())
+			);
+			assert_eq!(
+				<LivingAssetsModule as CollectionManager>::symbol(0),
+				Some(Default::default())
+			);
+
+			assert_ok!(<LivingAssetsModule as CollectionManager>::create_collection_with_name(
+				ALICE,
+				"Carbonara".as_bytes().to_vec().try_into().unwrap(),
+				"CRB".as_bytes().to_vec().try_into().unwrap(),
+				BaseURI::default()
+			));
+			assert_eq!(
+				<LivingAssetsModule as CollectionManager>::name(1).map(Into::<Vec<u8>>::into),
+				Some("Carbonara".as_bytes().to_vec())
+			);
+			assert_eq!(
+				<LivingAssetsModule as CollectionManager>::symbol(1).map(Into::<Vec<u8>>::into),
+				Some("CRB".as_bytes().to_vec())
+			);
+		});
+	}
+
+	#[test]
+	fn royalty_info_should_prefer_the_asset_royalty() {
+		let collection_receiver = H160::from_low_u64_be(ALICE);
+		let asset_receiver = H160::from_low_u64_be(BOB);
+		let sale_price = U256::from(10_000);
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				<LivingAssetsModule as Erc721>::royalty_info(0, U256::from(7), sale_price),
+				Error::<Test>::CollectionDoesNotExist
+			);
+			assert_ok!(<LivingAssetsModule as CollectionManager>::create_collection(
+				ALICE,
+				BaseURI::default()
 			));
-			assert_eq!(<LivingAssetsModule as Erc721>::owner_of(1, asset_id).unwrap(), receiver);
-			assert_eq!(<LivingAssetsModule as Erc721>::owner_of(2, asset_id).unwrap(), sender);
+			assert_eq!(
+				<LivingAssetsModule as Erc721>::royalty_info(0, U256::from(7), sale_price),
+				Ok((H160::zero(), U256::zero()))
+			);
+
+			assert_ok!(<LivingAssetsModule as CollectionManager>::set_default_royalty(
+				ALICE,
+				0,
+				collection_receiver,
+				250
+			));
+			assert_ok!(<LivingAssetsModule as CollectionManager>::set_token_royalty(
+				ALICE,
+				0,
+				U256::from(7),
+				asset_receiver,
+				1_000
+			));
+			assert_eq!(
+				<LivingAssetsModule as Erc721>::royalty_info(0, U256::from(6), sale_price),
+				Ok((collection_receiver, U256::from(250)))
+			);
+			assert_eq!(
+				<LivingAssetsModule as Erc721>::royalty_info(0, U256::from(7), sale_price),
+				Ok((asset_receiver, U256::from(1_000)))
+			);
 		});
 	}
 
 	#[test]
-	fn erc721_owner_of_asset_of_collection() {
+	fn royalty_info_should_not_overflow() {
 		new_test_ext().execute_with(|| {
-			let collection_id = <LivingAssetsModule as CollectionManager>::create_collection(
+			assert_ok!(<LivingAssetsModule as CollectionManager>::create_collection(
 				ALICE,
-				BaseURI::default(),
-			)
-			.unwrap();
+				BaseURI::default()
+			));
+			assert_ok!(<LivingAssetsModule as CollectionManager>::set_default_royalty(
+				ALICE,
+				0,
+				H160::from_low_u64_be(ALICE),
+				10_000
+			));
 			assert_eq!(
-				<LivingAssetsModule as Erc721>::owner_of(collection_id, 2.into()).unwrap(),
-				H160::from_low_u64_be(0x0000000000000002)
+				<LivingAssetsModule as Erc721>::royalty_info(0, U256::from(7), U256::MAX),
+				Ok((H160::from_low_u64_be(ALICE), U256::MAX))
 			);
 		});
 	}
 
 	#[test]
-	fn caller_is_not_current_owner_should_fail() {
-		let asset_id = U256::from(5);
-		let sender = H160::from_str("0000000000000000000000000000000000000006").unwrap();
-		let receiver = H160::from_low_u64_be(BOB);
+	fn balance_of_should_only_count_explicitly_owned_assets() {
+		let alice = H160::from_low_u64_be(ALICE);
+		let bob = H160::from_low_u64_be(BOB);
+		// both are initially owned by ALICE
+		let first_asset_id = U256::from(ALICE);
+		let second_asset_id = U256::from(ALICE) + (U256::one() << 128);
 		new_test_ext().execute_with(|| {
-			System::set_block_number(1);
-			assert!(AssetOwner::<Test>::get(0, asset_id).is_none());
-			CollectionBaseURI::<Test>::insert(1, BaseURI::default());
-			assert_noop!(
-				<LivingAssetsModule as Erc721>::transfer_from(
+			assert_ok!(<LivingAssetsModule as CollectionManager>::create_collection(
+				ALICE,
+				BaseURI::default()
+			));
+			assert_eq!(<LivingAssetsModule as Erc721>::balance_of(0, alice), Ok(0));
+			assert_eq!(
+				<LivingAssetsModule as Erc721>::token_of_owner_by_index(0, alice, 0),
+				Err(Error::<Test>::OwnerIndexOutOfBounds)
+			);
+
+			assert_ok!(<LivingAssetsModule as Erc721>::transfer_from(
+				alice,
+				0,
+				alice,
+				bob,
+				first_asset_id
+			));
+			assert_ok!(<LivingAssetsModule as Erc721>::transfer_from(
+				alice,
+				0,
+				alice,
+				bob,
+				second_asset_id
+			));
+			assert_eq!(<LivingAssetsModule as Erc721>::balance_of(0, bob), Ok(2));
+			assert_eq!(
+				<LivingAssetsModule as Erc721>::token_of_owner_by_index(0, bob, 0),
+				Ok(first_asset_id)
+			);
+			assert_eq!(
+				<LivingAssetsModule as Erc721>::token_of_owner_by_index(0, bob, 1),
+				Ok(second_asset_id)
+			);
+			assert_eq!(
+				<LivingAssetsModule as Erc721>::token_of_owner_by_index(0, bob, 2),
+				Err(Error::<Test>::OwnerIndexOutOfBounds)
+			);
+
+			// the last asset takes the index of the one transferred away
+			assert_ok!(<LivingAssetsModule as Erc721>::transfer_from(
+				bob,
+				0,
+				bob,
+				alice,
+				first_asset_id
+			));
+			assert_eq!(<LivingAssetsModule as Erc721>::balance_of(0, bob), Ok(1));
+			assert_eq!(
+				<LivingAssetsModule as Erc721>::token_of_owner_by_index(0, bob, 0),
+				Ok(second_asset_id)
+			);
+			assert_eq!(<LivingAssetsModule as Erc721>::balance_of(0, alice), Ok(1));
+			assert_eq!(
+				<LivingAssetsModule as Erc721>::token_of_owner_by_index(0, alice, 0),
+				Ok(first_asset_id)
+			);
+
+			assert_ok!(<LivingAssetsModule as Erc721>::burn(bob, 0, second_asset_id));
+			assert_eq!(<LivingAssetsModule as Erc721>::balance_of(0, bob), Ok(0));
+			assert_eq!(
+				<LivingAssetsModule as Erc721>::token_of_owner_by_index(0, bob, 0),
+				Err(Error::<Test>::OwnerIndexOutOfBounds)
+			);
+		});
+	}
+
+	#[test]
+	fn balance_of_unexistent_collection_should_fail() {
+		new_test_ext().execute_with(|| {
+			assert_eq!(
+				<LivingAssetsModule as Erc721>::balance_of(0, H160::from_low_u64_be(ALICE)),
+				Err(Error::<Test>::CollectionDoesNotExist)
+			);
+			assert_eq!(
+				<LivingAssetsModule as Erc721>::token_of_owner_by_index(
+					0,
 					H160::from_low_u64_be(ALICE),
-					1,
-					sender,
-					receiver,
-					asset_id,
+					0
 				),
-				Error::<Test>::NoPermission
+				Err(Error::<Test>::CollectionDoesNotExist)
 			);
 		});
 	}
 
 	#[test]
-	fn sender_is_not_current_owner_should_fail() {
-		let asset_id = U256::from(5);
-		let sender = H160::from_str("0000000000000000000000000000000000000006").unwrap();
-		let receiver = H160::from_low_u64_be(BOB);
+	fn owner_should_receive_assets_without_limit() {
+		let alice = H160::from_low_u64_be(ALICE);
+		let bob = H160::from_low_u64_be(BOB);
+		let asset_id = |i: u64| U256::from(ALICE) + (U256::from(i) << 128);
 		new_test_ext().execute_with(|| {
-			System::set_block_number(1);
-			assert!(AssetOwner::<Test>::get(0, asset_id).is_none());
-			CollectionBaseURI::<Test>::insert(1, BaseURI::default());
+			assert_ok!(<LivingAssetsModule as CollectionManager>::create_collection(
+				ALICE,
+				BaseURI::default()
+			));
+			for i in 0..100 {
+				assert_ok!(<LivingAssetsModule as Erc721>::transfer_from(
+					alice,
+					0,
+					alice,
+					bob,
+					asset_id(i)
+				));
+			}
+			assert_eq!(<LivingAssetsModule as Erc721>::balance_of(0, bob), Ok(100));
+			assert_eq!(
+				<LivingAssetsModule as Erc721>::token_of_owner_by_index(0, bob, 99),
+				Ok(asset_id(99))
+			);
+		});
+	}
+
+	#[test]
+	fn batch_transfer_from_should_transfer_nothing_if_a_transfer_fails() {
+		let alice = H160::from_low_u64_be(ALICE);
+		let bob = H160::from_low_u64_be(BOB);
+		new_test_ext().execute_with(|| {
+			assert_ok!(<LivingAssetsModule as CollectionManager>::create_collection(
+				ALICE,
+				BaseURI::default()
+			));
+			// the second asset belongs to BOB
 			assert_noop!(
-				<LivingAssetsModule as Erc721>::transfer_from(
-					sender, 1, sender, receiver, asset_id,
+				<LivingAssetsModule as Erc721>::batch_transfer_from(
+					alice,
+					alice,
+					vec![(0, bob, U256::from(ALICE)), (0, bob, U256::from(BOB))]
 				),
 				Error::<Test>::NoPermission
 			);
+			assert_eq!(<LivingAssetsModule as Erc721>::owner_of(0, U256::from(ALICE)), Ok(alice));
 		});
 	}
 
 	#[test]
-	fn same_sender_and_receiver_should_fail() {
-		let asset_id = U256::from(5);
-		let sender = H160::from_str("0000000000000000000000000000000000000005").unwrap();
+	fn batch_transfer_from_of_too_many_assets_should_fail() {
+		let alice = H160::from_low_u64_be(ALICE);
+		let bob = H160::from_low_u64_be(BOB);
 		new_test_ext().execute_with(|| {
-			System::set_block_number(1);
-			assert!(AssetOwner::<Test>::get(0, asset_id).is_none());
-			CollectionBaseURI::<Test>::insert(1, BaseURI::default());
+			assert_ok!(<LivingAssetsModule as CollectionManager>::create_collection(
+				ALICE,
+				BaseURI::default()
+			));
+			let max_transfers = <Test as crate::Config>::MaxBatchTransfers::get() as u64;
+			let transfers = (0..=max_transfers)
+				.map(|i| (0, bob, U256::from(ALICE) + (U256::from(i) << 128)))
+				.collect();
 			assert_noop!(
-				<LivingAssetsModule as Erc721>::transfer_from(sender, 1, sender, sender, asset_id,),
-				Error::<Test>::CannotTransferSelf
+				<LivingAssetsModule as Erc721>::batch_transfer_from(alice, alice, transfers),
+				Error::<Test>::TooManyTransfers
 			);
 		});
 	}
 
 	#[test]
-	fn receiver_is_the_zero_address_should_fail() {
-		let asset_id = U256::from(5);
-		let sender = H160::from_str("0000000000000000000000000000000000000005").unwrap();
-		let receiver = H160::from_str("0000000000000000000000000000000000000000").unwrap();
+	fn batch_transfer_from_beyond_the_transactional_limit_should_fail() {
+		use frame_support::storage::{
+			transactional::TRANSACTIONAL_LIMIT, with_transaction, TransactionOutcome,
+		};
+
+		fn nested_batch_transfer(depth: u32, from: H160, to: H160) -> Result<(), Error<Test>> {
+			if depth == 0 {
+				return <LivingAssetsModule as Erc721>::batch_transfer_from(
+					from,
+					from,
+					vec![(0, to, U256::from(ALICE))],
+				);
+			}
+			with_transaction::<_, sp_runtime::DispatchError, _>(|| {
+				TransactionOutcome::Commit(Ok(nested_batch_transfer(depth - 1, from, to)))
+			})
+			.unwrap()
+		}
+
+		let alice = H160::from_low_u64_be(ALICE);
+		let bob = H160::from_low_u64_be(BOB);
 		new_test_ext().execute_with(|| {
-			System::set_block_number(1);
-			assert!(AssetOwner::<Test>::get(0, asset_id).is_none());
-			CollectionBaseURI::<Test>::insert(1, BaseURI::default());
-			assert_noop!(
-				<LivingAssetsModule as Erc721>::transfer_from(
-					sender, 1, sender, receiver, asset_id,
-				),
-				Error::<Test>::TransferToNullAddress
+			assert_ok!(<LivingAssetsModule as CollectionManager>::create_collection(
+				ALICE,
+				BaseURI::default()
+			));
+			assert_eq!(
+				nested_batch_transfer(TRANSACTIONAL_LIMIT, alice, bob),
+				Err(Error::<Test>::TransactionalLimitReached)
 			);
+			assert_eq!(<LivingAssetsModule as Erc721>::owner_of(0, U256::from(ALICE)), Ok(alice));
+
+			assert_ok!(nested_batch_transfer(TRANSACTIONAL_LIMIT - 1, alice, bob));
+			assert_eq!(<LivingAssetsModule as Erc721>::owner_of(0, U256::from(ALICE)), Ok(bob));
+		});
+	}
+}
+
+mod migrations {
+	use super::*;
+	use crate::{
+		migrations::{
+			v0,
+			v1::{self, MigrateToV1},
+			AddressMappingProgress, AddressPages, MigrateAddressMapping, MovedAccounts,
+		},
+		traits::Erc721,
+		AssetIndexOfOwner,
+	};
+	use frame_support::traits::{
+		Currency, GetStorageVersion, OnRuntimeUpgrade, ReservableCurrency, StorageVersion,
+	};
+	use parity_scale_codec::{Decode, Encode};
+	use sp_runtime::traits::Convert;
+
+	/// Maps addresses to accounts other than the ones given by `MockH160ToAccountId`.
+	pub struct OldMapping;
+	impl Convert<H160, u64> for OldMapping {
+		fn convert(address: H160) -> u64 {
+			address.to_low_u64_be() | 1 << 48
+		}
+	}
+
+	/// Account without balance.
+	const CHARLIE: u64 = 0x3234;
+
+	/// Pages through the addresses of `ALICE` and `CHARLIE`, the cursor being the index of the
+	/// first address of the page.
+	pub struct Addresses;
+	impl AddressPages for Addresses {
+		fn page(cursor: Option<Vec<u8>>, limit: u32) -> (Vec<H160>, Option<Vec<u8>>) {
+			let addresses = [ALICE, CHARLIE].map(H160::from_low_u64_be);
+			let first = cursor.map_or(0, |cursor| u32::decode(&mut &cursor[..]).unwrap()) as usize;
+			let next = addresses.len().min(first + limit as usize);
+			let cursor = (next < addresses.len()).then(|| (next as u32).encode());
+			(addresses[first..next].to_vec(), cursor)
+		}
+	}
+
+	type Migration = MigrateAddressMapping<Test, OldMapping, Addresses>;
+
+	/// Starts the migration of the address mapping and runs its blocks, one entry per block.
+	fn run_address_migration() -> u32 {
+		Migration::on_runtime_upgrade();
+		let mut blocks = 0;
+		while AddressMappingProgress::<Test>::exists() {
+			Migration::step(1);
+			blocks += 1;
+		}
+		blocks
+	}
+
+	/// Runs the blocks of the migration to v1, one entry per block, and returns how many it took.
+	fn run_migration_to_v1() -> u32 {
+		let mut blocks = 0;
+		while v1::is_migrating::<Test>() {
+			MigrateToV1::<Test>::step(1);
+			blocks += 1;
+		}
+		blocks
+	}
+
+	#[test]
+	fn migration_should_move_collections_and_deposits_to_the_new_accounts() {
+		let old_alice = OldMapping::convert(H160::from_low_u64_be(ALICE));
+
+		new_test_ext().execute_with(|| {
+			Balances::make_free_balance_be(&old_alice, 1_000);
+			assert_ok!(LivingAssetsModule::create_collection(
+				RuntimeOrigin::signed(old_alice),
+				BaseURI::default()
+			));
+			assert_ok!(LivingAssetsModule::create_collection(
+				RuntimeOrigin::signed(BOB),
+				BaseURI::default()
+			));
+
+			// two pages of one address and at least a block for each collection
+			assert!(run_address_migration() >= 4);
+
+			assert_eq!(LivingAssetsModule::collection_owner(0), Some(ALICE));
+			assert_eq!(LivingAssetsModule::collection_deposit_of(0), Some((ALICE, 100)));
+			assert_eq!(Balances::reserved_balance(ALICE), 100);
+			assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE + 900);
+			assert_eq!(Balances::total_balance(&old_alice), 0);
+
+			// accounts not listed are left as they are
+			assert_eq!(LivingAssetsModule::collection_owner(1), Some(BOB));
+			assert_eq!(LivingAssetsModule::collection_deposit_of(1), Some((BOB, 100)));
 		});
 	}
 
 	#[test]
-	fn unexistent_collection_when_transfer_from_should_fail() {
-		let asset_id = U256::from(5);
-		let sender = H160::from_str("0000000000000000000000000000000000000005").unwrap();
-		let receiver = H160::from_low_u64_be(BOB);
+	fn migrated_owner_should_manage_the_collection_through_its_address() {
+		let old_alice = OldMapping::convert(H160::from_low_u64_be(ALICE));
+
 		new_test_ext().execute_with(|| {
-			System::set_block_number(1);
-			assert!(AssetOwner::<Test>::get(0, asset_id).is_none());
-			assert_noop!(
-				<LivingAssetsModule as Erc721>::transfer_from(
-					sender, 1, sender, receiver, asset_id,
-				),
-				Error::<Test>::CollectionDoesNotExist
+			Balances::make_free_balance_be(&old_alice, 1_000);
+			assert_ok!(LivingAssetsModule::create_collection(
+				RuntimeOrigin::signed(old_alice),
+				BaseURI::default()
+			));
+
+			run_address_migration();
+			// running it again does nothing
+			run_address_migration();
+
+			assert_eq!(
+				MockAccountIdToH160::convert(LivingAssetsModule::collection_owner(0).unwrap()),
+				H160::from_low_u64_be(ALICE)
 			);
+			assert_ok!(LivingAssetsModule::destroy_collection(RuntimeOrigin::signed(ALICE), 0));
+			assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE + 1_000);
 		});
 	}
 
 	#[test]
-	fn sucessful_transfer_from_trait_should_work() {
-		let asset_id = U256::from(
-			hex::decode("03C0F0f4ab324C46e55D02D0033343B4Be8A55532d").unwrap().as_slice(),
-		);
-		let sender = H160::from_str("0000000000000000000000003343b4be8a55532d").unwrap();
-		let receiver = H160::from_low_u64_be(BOB);
-		let collection_id = 1;
+	fn migration_should_keep_the_highest_nonce() {
+		let old_alice = OldMapping::convert(H160::from_low_u64_be(ALICE));
+
 		new_test_ext().execute_with(|| {
-			System::set_block_number(1);
-			CollectionBaseURI::<Test>::insert(collection_id, BaseURI::default());
-			assert!(AssetOwner::<Test>::get(collection_id, asset_id).is_none());
-			assert_eq!(<LivingAssetsModule as Erc721>::owner_of(1, asset_id).unwrap(), sender);
-			assert_ok!(<LivingAssetsModule as Erc721>::transfer_from(
-				sender, 1, sender, receiver, asset_id,
+			Balances::make_free_balance_be(&old_alice, 1_000);
+			frame_system::Account::<Test>::mutate(old_alice, |info| info.nonce = 5);
+
+			run_address_migration();
+
+			assert_eq!(System::account_nonce(ALICE), 5);
+		});
+
+		new_test_ext().execute_with(|| {
+			Balances::make_free_balance_be(&old_alice, 1_000);
+			frame_system::Account::<Test>::mutate(old_alice, |info| info.nonce = 2);
+			frame_system::Account::<Test>::mutate(ALICE, |info| info.nonce = 3);
+
+			run_address_migration();
+
+			assert_eq!(System::account_nonce(ALICE), 3);
+		});
+	}
+
+	#[test]
+	fn migration_should_move_a_deposit_to_an_account_that_does_not_exist() {
+		let old_charlie = OldMapping::convert(H160::from_low_u64_be(CHARLIE));
+
+		new_test_ext().execute_with(|| {
+			Balances::make_free_balance_be(&old_charlie, 100);
+			assert_ok!(LivingAssetsModule::create_collection(
+				RuntimeOrigin::signed(old_charlie),
+				BaseURI::default()
 			));
-			assert_eq!(AssetOwner::<Test>::get(collection_id, asset_id).unwrap(), BOB);
-			assert_eq!(<LivingAssetsModule as Erc721>::owner_of(1, asset_id).unwrap(), receiver);
-			System::assert_last_event(
-				Event::AssetTransferred { collection_id, asset_id, to: BOB }.into(),
-			);
+			assert_eq!(Balances::free_balance(old_charlie), 0);
+
+			run_address_migration();
+
+			assert_eq!(LivingAssetsModule::collection_owner(0), Some(CHARLIE));
+			assert_eq!(LivingAssetsModule::collection_deposit_of(0), Some((CHARLIE, 100)));
+			assert_eq!(Balances::reserved_balance(CHARLIE), 100);
+			assert_eq!(Balances::total_balance(&old_charlie), 0);
+			assert_eq!(MovedAccounts::<Test>::iter().count(), 0);
 		});
 	}
 
 	#[test]
-	fn token_uri_of_unexistent_collection() {
+	fn migration_should_not_leave_a_deposit_the_old_account_did_not_hold() {
+		let old_alice = OldMapping::convert(H160::from_low_u64_be(ALICE));
+
 		new_test_ext().execute_with(|| {
-			let result = <LivingAssetsModule as Erc721>::token_uri(0, 2.into());
-			assert_err!(result, Error::CollectionDoesNotExist);
+			Balances::make_free_balance_be(&old_alice, 1_000);
+			assert_ok!(LivingAssetsModule::create_collection(
+				RuntimeOrigin::signed(old_alice),
+				BaseURI::default()
+			));
+			Balances::unreserve(&old_alice, 40);
+
+			run_address_migration();
+
+			assert_eq!(LivingAssetsModule::collection_deposit_of(0), Some((ALICE, 60)));
+			assert_eq!(Balances::reserved_balance(ALICE), 60);
+			assert_eq!(Balances::total_balance(&old_alice), 0);
 		});
 	}
 
 	#[test]
-	fn token_uri_should_concatenate_the_base_uri_with_the_token_id() {
-		let base_uri = BaseURI::try_from("https://example.com".as_bytes().to_vec()).unwrap();
+	fn migration_to_v1_should_store_owners_as_addresses() {
+		let asset_id = U256::from(7);
+		let returned_asset_id = U256::from(ALICE);
 
 		new_test_ext().execute_with(|| {
-			let collection_id = <LivingAssetsModule as CollectionManager>::create_collection(
-				ALICE,
-				base_uri.clone(),
-			)
-			.unwrap();
+			StorageVersion::new(0).put::<LivingAssetsModule>();
+			v0::AssetOwner::<Test>::insert(0, asset_id, BOB);
+			v0::AssetOwner::<Test>::insert(1, asset_id, BOB);
+			// held by its initial owner, so it does not need an entry
+			v0::AssetOwner::<Test>::insert(0, returned_asset_id, ALICE);
+
+			MigrateToV1::<Test>::on_runtime_upgrade();
+			run_migration_to_v1();
+
+			let bob = H160::from_low_u64_be(BOB);
+			assert_eq!(LivingAssetsModule::on_chain_storage_version(), 1);
+			assert_eq!(AssetOwner::<Test>::get(0, asset_id), Some(bob));
+			assert_eq!(AssetOwner::<Test>::get(1, asset_id), Some(bob));
+			assert_eq!(AssetOwner::<Test>::get(0, returned_asset_id), None);
+			assert_eq!(OwnerAssetCount::<Test>::get(0, bob), 1);
+			assert_eq!(OwnerAssetByIndex::<Test>::get((1, bob, 0)), Some(asset_id));
+			assert_eq!(AssetIndexOfOwner::<Test>::get(0, returned_asset_id), None);
+		});
+	}
+
+	#[test]
+	fn migration_to_v1_should_run_over_several_blocks() {
+		let asset_id = U256::from(7);
+
+		new_test_ext().execute_with(|| {
+			for _ in 0..2 {
+				assert_ok!(LivingAssetsModule::create_collection(
+					RuntimeOrigin::signed(ALICE),
+					BaseURI::default()
+				));
+			}
+			StorageVersion::new(0).put::<LivingAssetsModule>();
+			v0::AssetOwner::<Test>::insert(0, asset_id, BOB);
+			v0::AssetOwner::<Test>::insert(1, asset_id, BOB);
+
+			MigrateToV1::<Test>::on_runtime_upgrade();
+
+			let bob = H160::from_low_u64_be(BOB);
+			// owners are read whether they are migrated or not
+			assert_eq!(<LivingAssetsModule as Erc721>::owner_of(0, asset_id), Ok(bob));
 			assert_eq!(
-				<LivingAssetsModule as Erc721>::token_uri(collection_id, 2.into()).unwrap(),
-				"https://example.com/2".as_bytes().to_vec()
+				<LivingAssetsModule as Erc721>::balance_of(0, bob),
+				Err(Error::<Test>::MigrationInProgress)
+			);
+			assert_noop!(
+				<LivingAssetsModule as Erc721>::transfer_from(
+					bob,
+					0,
+					bob,
+					H160::from_low_u64_be(ALICE),
+					asset_id
+				),
+				Error::<Test>::MigrationInProgress
+			);
+
+			// three indexes to clear, then one owner per block and a last block to find none left
+			for _ in 0..4 {
+				MigrateToV1::<Test>::step(1);
+			}
+			// one owner is stored as an address, the other one still as an account
+			assert_eq!(<LivingAssetsModule as Erc721>::owner_of(0, asset_id), Ok(bob));
+			assert_eq!(<LivingAssetsModule as Erc721>::owner_of(1, asset_id), Ok(bob));
+			assert_eq!(
+				<LivingAssetsModule as Erc721>::owner_of(1, U256::from(ALICE)),
+				Ok(H160::from_low_u64_be(ALICE))
+			);
+			assert_eq!(run_migration_to_v1(), 2);
+			assert_eq!(
+				<LivingAssetsModule as Erc721>::owner_of(0, asset_id),
+				Ok(H160::from_low_u64_be(BOB))
+			);
+			assert_eq!(
+				<LivingAssetsModule as Erc721>::balance_of(1, H160::from_low_u64_be(BOB)),
+				Ok(1)
 			);
 		});
 	}
+
+	#[test]
+	fn migration_to_v1_should_only_run_once() {
+		new_test_ext().execute_with(|| {
+			assert_eq!(LivingAssetsModule::on_chain_storage_version(), 1);
+			// the migration would drop an entry of the initial owner
+			v0::AssetOwner::<Test>::insert(0, U256::from(ALICE), ALICE);
+
+			MigrateToV1::<Test>::on_runtime_upgrade();
+
+			assert!(AssetOwner::<Test>::contains_key(0, U256::from(ALICE)));
+		});
+	}
 }
@@ -1,4 +1,5 @@
-use crate::CollectionId;
+use crate::{CollectionId, CollectionRole, MintingMode};
+use frame_support::traits::LockIdentifier;
 use sp_core::{H160, U256};
 use sp_std::vec::Vec;
 
@@ -10,11 +11,27 @@ use sp_std::vec::Vec;
 /// # Methods
 ///
 /// - `owner_of_collection`: Retrieve the owner of a specified collection.
+/// - `name`, `symbol`: Retrieve the name and symbol of a specified collection.
 /// - `create_collection`: Create a new collection and assign it to an owner.
+/// - `create_collection_with_name`: Create a new named collection and assign it to an owner.
+/// - `create_collection_with_mode`: Create a new named collection with the given minting mode.
+/// - `mint`, `total_supply`: Mint the assets of a collection with explicit minting and count them.
+/// - `transfer_collection_ownership`: Transfer the ownership of a collection to a new owner.
+/// - `renounce_collection_ownership`: Leave a collection without owner.
+/// - `set_base_uri`: Update the base URI of a collection.
+/// - `set_token_uri`: Override the token URI of a single asset of a collection.
+/// - `destroy_collection`: Remove a collection that holds no assets.
+/// - `freeze_metadata`: Irreversibly prevent any change to the URIs of a collection.
+/// - `make_soulbound`: Irreversibly prevent the assets of a collection from being transferred.
+/// - `pause`, `unpause`: Halt and resume the transfers and burns of the assets of a collection.
+/// - `set_default_royalty`, `set_token_royalty`: Manage the royalties of the assets of a collection.
+/// - `has_role`, `grant_role`, `revoke_role`, `renounce_role`: Manage the roles of a collection.
 pub trait CollectionManager {
 	type Error: AsRef<[u8]> + PartialEq;
 	type AccountId;
-	type BaseURI: TryFrom<Vec<u8>>;
+	type BaseURI: TryFrom<Vec<u8>> + Into<Vec<u8>>;
+	type Name: TryFrom<Vec<u8>> + Into<Vec<u8>>;
+	type Symbol: TryFrom<Vec<u8>> + Into<Vec<u8>>;
 
 	/// Retrieves the base uri of the specified collection.
 	///
@@ -27,8 +44,25 @@ pub trait CollectionManager {
 	/// The base URI associated with the specified collection or `None` if the collection doesn't exist.
 	fn base_uri(collection_id: CollectionId) -> Option<Self::BaseURI>;
 
+	/// Retrieves the name of the specified collection.
+	///
+	/// # Returns
+	///
+	/// The name of the collection, empty if it has none, or `None` if the collection doesn't exist.
+	fn name(collection_id: CollectionId) -> Option<Self::Name>;
+
+	/// Retrieves the symbol of the specified collection.
+	///
+	/// # Returns
+	///
+	/// The symbol of the collection, empty if it has none, or `None` if the collection doesn't
+	/// exist.
+	fn symbol(collection_id: CollectionId) -> Option<Self::Symbol>;
+
 	/// Creates a new collection and assigns it to the specified owner.
 	///
+	/// A deposit proportional to the length of the base URI is reserved from the owner.
+	///
 	/// # Arguments
 	///
 	/// * `owner` - The account ID of the new collection's owner.
@@ -40,6 +74,303 @@ pub trait CollectionManager {
 		owner: Self::AccountId,
 		base_uri: Self::BaseURI,
 	) -> Result<CollectionId, Self::Error>;
+
+	/// Creates a new collection with a name and a symbol and assigns it to the specified owner.
+	///
+	/// # Arguments
+	///
+	/// * `owner` - The account ID of the new collection's owner.
+	/// * `name` - The name of the collection.
+	/// * `symbol` - The symbol of the collection.
+	/// * `base_uri` - The base URI of the collection.
+	///
+	/// # Returns
+	///
+	/// A result containing the `collection_id` of the newly created collection or an error.
+	fn create_collection_with_name(
+		owner: Self::AccountId,
+		name: Self::Name,
+		symbol: Self::Symbol,
+		base_uri: Self::BaseURI,
+	) -> Result<CollectionId, Self::Error>;
+
+	/// Creates a new collection with a name, a symbol and a minting mode and assigns it to the
+	/// specified owner.
+	///
+	/// # Arguments
+	///
+	/// * `owner` - The account ID of the new collection's owner.
+	/// * `name` - The name of the collection.
+	/// * `symbol` - The symbol of the collection.
+	/// * `base_uri` - The base URI of the collection.
+	/// * `mode` - Whether its assets exist implicitly or must be minted, up to an optional max
+	///   supply.
+	///
+	/// # Returns
+	///
+	/// A result containing the `collection_id` of the newly created collection or an error.
+	fn create_collection_with_mode(
+		owner: Self::AccountId,
+		name: Self::Name,
+		symbol: Self::Symbol,
+		base_uri: Self::BaseURI,
+		mode: MintingMode,
+	) -> Result<CollectionId, Self::Error>;
+
+	/// Mints an asset of a collection with explicit minting.
+	///
+	/// # Arguments
+	///
+	/// * `origin` - The account ID of the caller, which must be the owner or a minter.
+	/// * `collection_id` - The unique identifier of the collection.
+	/// * `to` - The owner of the new asset.
+	/// * `asset_id` - The unique identifier of the asset, which must not have been minted before.
+	///
+	/// # Returns
+	///
+	/// A result indicating success or an error, also when the max supply has been reached.
+	fn mint(
+		origin: Self::AccountId,
+		collection_id: CollectionId,
+		to: H160,
+		asset_id: U256,
+	) -> Result<(), Self::Error>;
+
+	/// Retrieves the number of minted assets of a collection with explicit minting, not counting
+	/// the burned ones.
+	///
+	/// # Returns
+	///
+	/// The supply, or an error if the collection doesn't exist or its assets exist implicitly.
+	fn total_supply(collection_id: CollectionId) -> Result<u64, Self::Error>;
+
+	/// Retrieves the owner of the specified collection.
+	///
+	/// # Arguments
+	///
+	/// * `collection_id` - The unique identifier of the collection.
+	///
+	/// # Returns
+	///
+	/// The account ID of the owner or `None` if the collection doesn't exist or has no owner.
+	fn owner_of_collection(collection_id: CollectionId) -> Option<Self::AccountId>;
+
+	/// Transfers the ownership of the specified collection, along with its deposit.
+	///
+	/// If the new owner does not exist, and so can not hold the deposit, the deposit stays with
+	/// the account that reserved it.
+	///
+	/// # Arguments
+	///
+	/// * `origin` - The account ID of the caller, which must be the current owner.
+	/// * `collection_id` - The unique identifier of the collection.
+	/// * `new_owner` - The account ID of the new owner.
+	fn transfer_collection_ownership(
+		origin: Self::AccountId,
+		collection_id: CollectionId,
+		new_owner: Self::AccountId,
+	) -> Result<(), Self::Error>;
+
+	/// Leaves the specified collection without owner, disabling any functionality that is only
+	/// available to the owner. The deposit of the collection is released.
+	///
+	/// # Arguments
+	///
+	/// * `origin` - The account ID of the caller, which must be the current owner.
+	/// * `collection_id` - The unique identifier of the collection.
+	fn renounce_collection_ownership(
+		origin: Self::AccountId,
+		collection_id: CollectionId,
+	) -> Result<(), Self::Error>;
+
+	/// Replaces the base URI of the specified collection.
+	///
+	/// The deposit of the collection is adjusted to the new length and held by its owner from then
+	/// on, releasing the one held by the previous depositor. Collections without owner keep their
+	/// depositor, or take the caller as such if they have none.
+	///
+	/// # Arguments
+	///
+	/// * `origin` - The account ID of the caller, which must be the owner or a metadata manager.
+	/// * `collection_id` - The unique identifier of the collection.
+	/// * `base_uri` - The new base URI.
+	fn set_base_uri(
+		origin: Self::AccountId,
+		collection_id: CollectionId,
+		base_uri: Self::BaseURI,
+	) -> Result<(), Self::Error>;
+
+	/// Sets the token URI of a specific asset, which takes precedence over the one derived from
+	/// the base URI of the collection. An empty `token_uri` removes the override.
+	///
+	/// # Arguments
+	///
+	/// * `origin` - The account ID of the caller, which must be the owner or a metadata manager.
+	/// * `collection_id` - The unique identifier of the collection.
+	/// * `asset_id` - The unique identifier for the asset within the collection.
+	/// * `token_uri` - The token URI of the asset.
+	fn set_token_uri(
+		origin: Self::AccountId,
+		collection_id: CollectionId,
+		asset_id: U256,
+		token_uri: Self::BaseURI,
+	) -> Result<(), Self::Error>;
+
+	/// Destroys the specified collection and releases its deposit.
+	///
+	/// The collection must have no explicitly owned assets, no token URIs and unfrozen metadata.
+	///
+	/// # Arguments
+	///
+	/// * `origin` - The account ID of the caller, which must be the current owner.
+	/// * `collection_id` - The unique identifier of the collection.
+	fn destroy_collection(
+		origin: Self::AccountId,
+		collection_id: CollectionId,
+	) -> Result<(), Self::Error>;
+
+	/// Freezes the metadata of the specified collection, so that neither its base URI nor the
+	/// token URIs of its assets can be changed anymore. This can not be undone.
+	///
+	/// # Arguments
+	///
+	/// * `origin` - The account ID of the caller, which must be the owner or a metadata manager.
+	/// * `collection_id` - The unique identifier of the collection.
+	fn freeze_metadata(
+		origin: Self::AccountId,
+		collection_id: CollectionId,
+	) -> Result<(), Self::Error>;
+
+	/// Makes the assets of the specified collection non-transferable. They can still be burned.
+	/// This can not be undone.
+	///
+	/// # Arguments
+	///
+	/// * `origin` - The account ID of the caller, which must be the owner or an admin.
+	/// * `collection_id` - The unique identifier of the collection.
+	fn make_soulbound(
+		origin: Self::AccountId,
+		collection_id: CollectionId,
+	) -> Result<(), Self::Error>;
+
+	/// Halts every transfer and burn of the assets of the specified collection until it is
+	/// unpaused.
+	///
+	/// # Arguments
+	///
+	/// * `origin` - The account ID of the caller, which must be the owner or a pauser.
+	/// * `collection_id` - The unique identifier of the collection.
+	fn pause(origin: Self::AccountId, collection_id: CollectionId) -> Result<(), Self::Error>;
+
+	/// Resumes the transfers and burns of the assets of the specified paused collection.
+	///
+	/// # Arguments
+	///
+	/// * `origin` - The account ID of the caller, which must be the owner or a pauser.
+	/// * `collection_id` - The unique identifier of the collection.
+	fn unpause(origin: Self::AccountId, collection_id: CollectionId) -> Result<(), Self::Error>;
+
+	/// Sets the royalty paid for the assets of the specified collection that have no royalty of
+	/// their own. A zero `receiver` removes it.
+	///
+	/// # Arguments
+	///
+	/// * `origin` - The account ID of the caller, which must be the owner or a royalty manager.
+	/// * `collection_id` - The unique identifier of the collection.
+	/// * `receiver` - The address the royalty is paid to.
+	/// * `basis_points` - The share of the sale price, in hundredths of a percent.
+	fn set_default_royalty(
+		origin: Self::AccountId,
+		collection_id: CollectionId,
+		receiver: H160,
+		basis_points: u16,
+	) -> Result<(), Self::Error>;
+
+	/// Sets the royalty paid for a specific asset, which takes precedence over the default one of
+	/// the collection. A zero `receiver` removes it.
+	///
+	/// # Arguments
+	///
+	/// * `origin` - The account ID of the caller, which must be the owner or a royalty manager.
+	/// * `collection_id` - The unique identifier of the collection.
+	/// * `asset_id` - The unique identifier for the asset within the collection.
+	/// * `receiver` - The address the royalty is paid to.
+	/// * `basis_points` - The share of the sale price, in hundredths of a percent.
+	fn set_token_royalty(
+		origin: Self::AccountId,
+		collection_id: CollectionId,
+		asset_id: U256,
+		receiver: H160,
+		basis_points: u16,
+	) -> Result<(), Self::Error>;
+
+	/// Checks whether `account` holds `role` on the specified collection, either because it was
+	/// granted to it or because it owns the collection.
+	///
+	/// # Returns
+	///
+	/// `true` if `account` holds the role, or an error if the collection doesn't exist.
+	fn has_role(
+		collection_id: CollectionId,
+		role: CollectionRole,
+		account: Self::AccountId,
+	) -> Result<bool, Self::Error>;
+
+	/// Grants `role` on the specified collection to `account`.
+	///
+	/// # Arguments
+	///
+	/// * `origin` - The account ID of the caller, which must be the owner or an admin.
+	/// * `collection_id` - The unique identifier of the collection.
+	/// * `role` - The role to grant.
+	/// * `account` - The account ID receiving the role.
+	///
+	/// # Returns
+	///
+	/// `true` if the role was granted, `false` if `account` already held it.
+	fn grant_role(
+		origin: Self::AccountId,
+		collection_id: CollectionId,
+		role: CollectionRole,
+		account: Self::AccountId,
+	) -> Result<bool, Self::Error>;
+
+	/// Revokes `role` on the specified collection from `account`. The roles the owner holds
+	/// implicitly can not be revoked.
+	///
+	/// # Arguments
+	///
+	/// * `origin` - The account ID of the caller, which must be the owner or an admin.
+	/// * `collection_id` - The unique identifier of the collection.
+	/// * `role` - The role to revoke.
+	/// * `account` - The account ID losing the role.
+	///
+	/// # Returns
+	///
+	/// `true` if the role was revoked, `false` if it had not been granted to `account`.
+	fn revoke_role(
+		origin: Self::AccountId,
+		collection_id: CollectionId,
+		role: CollectionRole,
+		account: Self::AccountId,
+	) -> Result<bool, Self::Error>;
+
+	/// Gives up `role` on the specified collection.
+	///
+	/// # Arguments
+	///
+	/// * `origin` - The account ID of the caller, which loses the role.
+	/// * `collection_id` - The unique identifier of the collection.
+	/// * `role` - The role to give up.
+	///
+	/// # Returns
+	///
+	/// `true` if the role was renounced, `false` if it had not been granted to the caller.
+	fn renounce_role(
+		origin: Self::AccountId,
+		collection_id: CollectionId,
+		role: CollectionRole,
+	) -> Result<bool, Self::Error>;
 }
 
 /// The `Erc721` trait provides an interface for handling ERC721 tokens in a blockchain environment.
@@ -50,6 +381,17 @@ pub trait CollectionManager {
 ///
 /// - `owner_of`: Retrieve the owner of a specific asset within a collection.
 /// - `token_uri`: Retrieve the URI associated with a specific asset within a collection.
+/// - `transfer_from`: Transfer an asset, either by its owner or by an approved address.
+/// - `batch_transfer_from`: Transfer several assets of the same owner at once.
+/// - `approve`, `get_approved`: Manage the address approved to transfer a specific asset.
+/// - `set_approval_for_all`, `is_approved_for_all`: Manage operators of all the assets of an owner.
+/// - `burn`: Destroy an asset, either by its owner or by an approved address.
+/// - `locked`: Check whether an asset can not be transferred.
+/// - `is_locked`: Check whether an asset holds a lock placed by another pallet.
+/// - `paused`: Check whether the transfers of a collection are halted.
+/// - `set_user`, `user_of`, `user_expires`: Manage the user renting an asset, as in ERC-4907.
+/// - `royalty_info`: Retrieve the royalty to pay for the sale of an asset.
+/// - `balance_of`, `token_of_owner_by_index`: Enumerate the explicitly owned assets of an address.
 pub trait Erc721 {
 	type Error: AsRef<[u8]> + PartialEq;
 
@@ -69,6 +411,8 @@ pub trait Erc721 {
 	///
 	/// The URI is often used to provide metadata, images, or other information related to the asset.
 	/// This URI should conform to the ERC721 metadata standards and can be a URL, IPFS hash, or other URI format.
+	/// Unless it has been overridden for the asset, it is the base URI of the collection followed by
+	/// `'/'` and the asset id.
 	///
 	/// # Arguments
 	///
@@ -81,6 +425,10 @@ pub trait Erc721 {
 
 	/// Transfers the ownership of a asset from one address to another address
 	///
+	/// The caller must be the current owner of the asset, the address approved for it or an
+	/// operator of the owner. Any existing approval and user of the asset are cleared. Assets of
+	/// soulbound or paused collections, and locked assets, can not be transferred.
+	///
 	/// # Arguments
 	///
 	/// * `origin` - The caller's address.
@@ -95,4 +443,236 @@ pub trait Erc721 {
 		to: H160,
 		asset_id: U256,
 	) -> Result<(), Self::Error>;
+
+	/// Transfers several assets of `from`, possibly of different collections, in a single call
+	///
+	/// Each transfer is checked as in [`Self::transfer_from`]. The batch is atomic: if any of the
+	/// transfers fails, none of them takes place.
+	///
+	/// # Arguments
+	///
+	/// * `origin` - The caller's address.
+	/// * `from` - The current owner of the assets.
+	/// * `transfers` - The collection, new owner and asset id of each transfer.
+	fn batch_transfer_from(
+		origin: H160,
+		from: H160,
+		transfers: Vec<(CollectionId, H160, U256)>,
+	) -> Result<(), Self::Error>;
+
+	/// Gives permission to `to` to transfer the asset to another address.
+	///
+	/// Only a single address can be approved at a time, so approving `H160::zero()` clears the
+	/// previous approval.
+	///
+	/// # Arguments
+	///
+	/// * `origin` - The caller's address, which must be the owner of the asset or an operator of the owner.
+	/// * `collection_id` - The unique identifier for the collection.
+	/// * `to` - The address to be approved.
+	/// * `asset_id` - The unique identifier for the asset within the collection.
+	fn approve(
+		origin: H160,
+		collection_id: CollectionId,
+		to: H160,
+		asset_id: U256,
+	) -> Result<(), Self::Error>;
+
+	/// Retrieves the address approved for a specific asset within the specified collection.
+	///
+	/// # Returns
+	///
+	/// The approved address, `H160::zero()` if there is none, or an error.
+	fn get_approved(collection_id: CollectionId, asset_id: U256) -> Result<H160, Self::Error>;
+
+	/// Approves or removes `operator` as an operator for the caller.
+	///
+	/// Operators can transfer and approve any asset of the caller within the collection.
+	///
+	/// # Arguments
+	///
+	/// * `origin` - The caller's address.
+	/// * `collection_id` - The unique identifier for the collection.
+	/// * `operator` - The address to add to or remove from the set of authorized operators.
+	/// * `approved` - `true` if the operator is approved, `false` to revoke approval.
+	fn set_approval_for_all(
+		origin: H160,
+		collection_id: CollectionId,
+		operator: H160,
+		approved: bool,
+	) -> Result<(), Self::Error>;
+
+	/// Checks whether `operator` is an approved operator of `owner` within the specified collection.
+	fn is_approved_for_all(
+		collection_id: CollectionId,
+		owner: H160,
+		operator: H160,
+	) -> Result<bool, Self::Error>;
+
+	/// Burns an asset, after which it no longer has an owner and can not be transferred.
+	///
+	/// The caller must be the current owner of the asset, the address approved for it or an
+	/// operator of the owner. Burned assets are never handed back to their initial owner, and their
+	/// token URI and royalty are removed. Assets of paused collections, and locked assets, can not
+	/// be burned.
+	///
+	/// # Arguments
+	///
+	/// * `origin` - The caller's address.
+	/// * `collection_id` - The unique identifier for the collection.
+	/// * `asset_id` - The unique identifier for the asset within the collection.
+	fn burn(origin: H160, collection_id: CollectionId, asset_id: U256) -> Result<(), Self::Error>;
+
+	/// Checks whether a specific asset is locked, that is, whether it belongs to a soulbound
+	/// collection and therefore can not be transferred.
+	///
+	/// # Returns
+	///
+	/// `true` if the asset is locked, or an error if the collection or the asset do not exist.
+	fn locked(collection_id: CollectionId, asset_id: U256) -> Result<bool, Self::Error>;
+
+	/// Checks whether a specific asset holds any lock placed through [`AssetLockManager`], in
+	/// which case it can not be transferred nor burned.
+	///
+	/// # Returns
+	///
+	/// `true` if the asset is locked, or an error if the collection or the asset do not exist.
+	fn is_locked(collection_id: CollectionId, asset_id: U256) -> Result<bool, Self::Error>;
+
+	/// Checks whether the specified collection is paused, in which case none of its assets can
+	/// be transferred nor burned.
+	///
+	/// # Returns
+	///
+	/// `true` if the collection is paused, or an error if it doesn't exist.
+	fn paused(collection_id: CollectionId) -> Result<bool, Self::Error>;
+
+	/// Sets the user of an asset until `expires`, as described by ERC-4907. The user is not the
+	/// owner and is reset whenever the asset is transferred or burned.
+	///
+	/// Setting `H160::zero()` as user removes the current one.
+	///
+	/// # Arguments
+	///
+	/// * `origin` - The caller's address, which must be the owner of the asset, the address
+	///   approved for it or an operator of the owner.
+	/// * `collection_id` - The unique identifier for the collection.
+	/// * `asset_id` - The unique identifier for the asset within the collection.
+	/// * `user` - The address allowed to use the asset.
+	/// * `expires` - The unix timestamp, in seconds, until which `user` can use the asset.
+	fn set_user(
+		origin: H160,
+		collection_id: CollectionId,
+		asset_id: U256,
+		user: H160,
+		expires: u64,
+	) -> Result<(), Self::Error>;
+
+	/// Retrieves the user of a specific asset within the specified collection.
+	///
+	/// # Returns
+	///
+	/// The user, `H160::zero()` if there is none or it has expired, or an error.
+	fn user_of(collection_id: CollectionId, asset_id: U256) -> Result<H160, Self::Error>;
+
+	/// Retrieves the unix timestamp, in seconds, until which the user of a specific asset can use
+	/// it.
+	///
+	/// # Returns
+	///
+	/// The timestamp, `0` if there is no user, or an error.
+	fn user_expires(collection_id: CollectionId, asset_id: U256) -> Result<u64, Self::Error>;
+
+	/// Retrieves the royalty to pay for the sale of a specific asset, as described by ERC-2981.
+	///
+	/// # Arguments
+	///
+	/// * `collection_id` - The unique identifier for the collection.
+	/// * `asset_id` - The unique identifier for the asset within the collection.
+	/// * `sale_price` - The price the asset is sold for.
+	///
+	/// # Returns
+	///
+	/// The address the royalty is paid to and its amount, `(H160::zero(), 0)` if the asset has
+	/// no royalty, or an error if the collection doesn't exist.
+	fn royalty_info(
+		collection_id: CollectionId,
+		asset_id: U256,
+		sale_price: U256,
+	) -> Result<(H160, U256), Self::Error>;
+
+	/// Retrieves the number of assets explicitly owned by `owner` within the specified collection.
+	///
+	/// Only assets that have been transferred to `owner`, or assigned to it at genesis, are
+	/// counted. Assets still held by their initial owner, as given by the asset id, are not
+	/// tracked, so the actual balance of `owner` can be higher.
+	///
+	/// # Returns
+	///
+	/// The number of explicitly owned assets, or an error if the collection doesn't exist.
+	fn balance_of(collection_id: CollectionId, owner: H160) -> Result<u32, Self::Error>;
+
+	/// Retrieves the explicitly owned asset of `owner` at `index` within the specified collection.
+	///
+	/// `index` must be lower than [`Self::balance_of`]. The order of the assets is not stable, as
+	/// transferring an asset away moves the last one into its index.
+	///
+	/// # Returns
+	///
+	/// The asset id, or an error if the collection doesn't exist or `index` is out of bounds.
+	fn token_of_owner_by_index(
+		collection_id: CollectionId,
+		owner: H160,
+		index: u32,
+	) -> Result<U256, Self::Error>;
+}
+
+/// The `AssetLockManager` trait lets other pallets place named locks on assets, for instance to
+/// stake, list or collateralize them without moving them into an escrow account.
+///
+/// A locked asset stays with its owner but can not be transferred nor burned until every lock is
+/// lifted. Each pallet is expected to use its own lock identifiers, as any caller can lift any lock.
+///
+/// # Methods
+///
+/// - `set_lock`: Place a named lock on an asset.
+/// - `remove_lock`: Lift a named lock from an asset.
+/// - `is_locked`: Check whether an asset holds any lock.
+pub trait AssetLockManager {
+	type Error: AsRef<[u8]> + PartialEq;
+
+	/// Places the lock `id` on a specific asset. Placing a lock the asset already holds does
+	/// nothing.
+	///
+	/// # Arguments
+	///
+	/// * `id` - The identifier of the lock.
+	/// * `collection_id` - The unique identifier for the collection.
+	/// * `asset_id` - The unique identifier for the asset within the collection.
+	///
+	/// # Returns
+	///
+	/// An error if the collection or the asset do not exist, or the asset holds too many locks.
+	fn set_lock(
+		id: LockIdentifier,
+		collection_id: CollectionId,
+		asset_id: U256,
+	) -> Result<(), Self::Error>;
+
+	/// Lifts the lock `id` from a specific asset. Lifting a lock the asset does not hold does
+	/// nothing.
+	///
+	/// # Arguments
+	///
+	/// * `id` - The identifier of the lock.
+	/// * `collection_id` - The unique identifier for the collection.
+	/// * `asset_id` - The unique identifier for the asset within the collection.
+	fn remove_lock(
+		id: LockIdentifier,
+		collection_id: CollectionId,
+		asset_id: U256,
+	) -> Result<(), Self::Error>;
+
+	/// Checks whether a specific asset holds any lock.
+	fn is_locked(collection_id: CollectionId, asset_id: U256) -> bool;
 }
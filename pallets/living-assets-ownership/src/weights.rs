@@ -0,0 +1,989 @@
+//! Weights for pallet_living_assets_ownership
+//!
+//! PROVISIONAL, NOT MEASURED: ref times are rough estimates rounded up to two significant figures,
+//! reads, writes and proof sizes follow from the storage each benchmark touches. Release builds
+//! must replace this file with the one `scripts/benchmark.sh` generates on reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_living_assets_ownership.
+pub trait WeightInfo {
+	fn create_collection(b: u32, ) -> Weight;
+	fn create_collection_with_name(b: u32, ) -> Weight;
+	fn create_collection_with_mode(b: u32, ) -> Weight;
+	fn transfer_collection_ownership() -> Weight;
+	fn renounce_collection_ownership() -> Weight;
+	fn set_base_uri(b: u32, ) -> Weight;
+	fn set_token_uri(b: u32, ) -> Weight;
+	fn transfer() -> Weight;
+	fn transfer_from() -> Weight;
+	fn batch_transfer(n: u32, ) -> Weight;
+	fn destroy_collection() -> Weight;
+	fn freeze_metadata() -> Weight;
+	fn make_soulbound() -> Weight;
+	fn pause() -> Weight;
+	fn unpause() -> Weight;
+	fn grant_role() -> Weight;
+	fn revoke_role() -> Weight;
+	fn renounce_role() -> Weight;
+	fn has_role() -> Weight;
+	fn set_default_royalty() -> Weight;
+	fn set_token_royalty() -> Weight;
+	fn owner_of() -> Weight;
+	fn token_uri(b: u32, ) -> Weight;
+	fn base_uri(b: u32, ) -> Weight;
+	fn approve() -> Weight;
+	fn get_approved() -> Weight;
+	fn set_approval_for_all() -> Weight;
+	fn is_approved_for_all() -> Weight;
+	fn locked() -> Weight;
+	fn is_locked() -> Weight;
+	fn paused() -> Weight;
+	fn set_user() -> Weight;
+	fn user_of() -> Weight;
+	fn user_expires() -> Weight;
+	fn royalty_info() -> Weight;
+	fn balance_of() -> Weight;
+	fn token_of_owner_by_index() -> Weight;
+	fn total_supply() -> Weight;
+	fn burn() -> Weight;
+	fn mint() -> Weight;
+	fn owner_of_collection() -> Weight;
+	fn name() -> Weight;
+	fn symbol() -> Weight;
+}
+
+/// Weights for pallet_living_assets_ownership using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: LivingAssetsOwnership CollectionCounter (r:1 w:1)
+	/// Storage: LivingAssetsOwnership CollectionDepositOf (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: LivingAssetsOwnership CollectionOwner (r:0 w:1)
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:0 w:1)
+	/// The range of component `b` is `[0, 2015]`.
+	fn create_collection(b: u32, ) -> Weight {
+		Weight::from_parts(32_000_000, 3_593)
+			.saturating_add(Weight::from_parts(1_400, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionCounter (r:1 w:1)
+	/// Storage: LivingAssetsOwnership CollectionDepositOf (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: LivingAssetsOwnership CollectionOwner (r:0 w:1)
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:0 w:1)
+	/// Storage: LivingAssetsOwnership CollectionName (r:0 w:1)
+	/// Storage: LivingAssetsOwnership CollectionSymbol (r:0 w:1)
+	/// The range of component `b` is `[0, 2015]`.
+	fn create_collection_with_name(b: u32, ) -> Weight {
+		Weight::from_parts(35_000_000, 3_593)
+			.saturating_add(Weight::from_parts(1_400, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionCounter (r:1 w:1)
+	/// Storage: LivingAssetsOwnership CollectionDepositOf (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: LivingAssetsOwnership CollectionOwner (r:0 w:1)
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:0 w:1)
+	/// Storage: LivingAssetsOwnership CollectionName (r:0 w:1)
+	/// Storage: LivingAssetsOwnership CollectionSymbol (r:0 w:1)
+	/// Storage: LivingAssetsOwnership CollectionMintingMode (r:0 w:1)
+	/// The range of component `b` is `[0, 2015]`.
+	fn create_collection_with_mode(b: u32, ) -> Weight {
+		Weight::from_parts(36_000_000, 3_593)
+			.saturating_add(Weight::from_parts(1_400, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionOwner (r:1 w:1)
+	/// Storage: LivingAssetsOwnership CollectionDepositOf (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
+	fn transfer_collection_ownership() -> Weight {
+		Weight::from_parts(34_000_000, 11_231)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionOwner (r:1 w:1)
+	/// Storage: LivingAssetsOwnership CollectionDepositOf (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn renounce_collection_ownership() -> Weight {
+		Weight::from_parts(28_000_000, 8_617)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:1)
+	/// Storage: LivingAssetsOwnership CollectionOwner (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionRoles (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionDepositOf (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
+	/// Storage: LivingAssetsOwnership CollectionMetadataFrozen (r:1 w:0)
+	/// The range of component `b` is `[0, 2015]`.
+	fn set_base_uri(b: u32, ) -> Weight {
+		Weight::from_parts(40_000_000, 13_854)
+			.saturating_add(Weight::from_parts(1_400, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionOwner (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionRoles (r:1 w:0)
+	/// Storage: LivingAssetsOwnership BurnedAsset (r:1 w:0)
+	/// Storage: LivingAssetsOwnership Progress (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionMintingMode (r:1 w:0)
+	/// Storage: LivingAssetsOwnership AssetOwner (r:1 w:0)
+	/// Storage: LivingAssetsOwnership AssetTokenURI (r:0 w:1)
+	/// Storage: LivingAssetsOwnership CollectionMetadataFrozen (r:1 w:0)
+	/// The range of component `b` is `[0, 2015]`.
+	fn set_token_uri(b: u32, ) -> Weight {
+		Weight::from_parts(19_000_000, 17_717)
+			.saturating_add(Weight::from_parts(1_400, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership BurnedAsset (r:1 w:0)
+	/// Storage: LivingAssetsOwnership Progress (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionMintingMode (r:1 w:0)
+	/// Storage: LivingAssetsOwnership AssetLocks (r:1 w:0)
+	/// Storage: LivingAssetsOwnership AssetOwner (r:1 w:1)
+	/// Storage: LivingAssetsOwnership AssetApproval (r:1 w:1)
+	/// Storage: LivingAssetsOwnership AssetUser (r:1 w:1)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Storage: LivingAssetsOwnership SoulboundCollection (r:1 w:0)
+	/// Storage: LivingAssetsOwnership PausedCollection (r:1 w:0)
+	/// Storage: LivingAssetsOwnership OwnerAssetCount (r:2 w:2)
+	/// Storage: LivingAssetsOwnership AssetIndexOfOwner (r:1 w:2)
+	/// Storage: LivingAssetsOwnership OwnerAssetByIndex (r:1 w:3)
+	fn transfer() -> Weight {
+		Weight::from_parts(36_000_000, 37_957)
+			.saturating_add(T::DbWeight::get().reads(15_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership BurnedAsset (r:1 w:0)
+	/// Storage: LivingAssetsOwnership Progress (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionMintingMode (r:1 w:0)
+	/// Storage: LivingAssetsOwnership AssetLocks (r:1 w:0)
+	/// Storage: LivingAssetsOwnership AssetOwner (r:1 w:1)
+	/// Storage: LivingAssetsOwnership AssetApproval (r:1 w:1)
+	/// Storage: LivingAssetsOwnership AssetUser (r:1 w:1)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Storage: LivingAssetsOwnership OperatorApproval (r:1 w:0)
+	/// Storage: LivingAssetsOwnership SoulboundCollection (r:1 w:0)
+	/// Storage: LivingAssetsOwnership PausedCollection (r:1 w:0)
+	/// Storage: LivingAssetsOwnership OwnerAssetCount (r:2 w:2)
+	/// Storage: LivingAssetsOwnership AssetIndexOfOwner (r:1 w:2)
+	/// Storage: LivingAssetsOwnership OwnerAssetByIndex (r:1 w:3)
+	fn transfer_from() -> Weight {
+		Weight::from_parts(39_000_000, 41_489)
+			.saturating_add(T::DbWeight::get().reads(16_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:500 w:0)
+	/// Storage: LivingAssetsOwnership BurnedAsset (r:500 w:0)
+	/// Storage: LivingAssetsOwnership Progress (r:500 w:0)
+	/// Storage: LivingAssetsOwnership CollectionMintingMode (r:500 w:0)
+	/// Storage: LivingAssetsOwnership AssetLocks (r:500 w:0)
+	/// Storage: LivingAssetsOwnership AssetOwner (r:500 w:500)
+	/// Storage: LivingAssetsOwnership AssetApproval (r:500 w:500)
+	/// Storage: LivingAssetsOwnership AssetUser (r:500 w:500)
+	/// Storage: Timestamp Now (r:500 w:0)
+	/// Storage: LivingAssetsOwnership OperatorApproval (r:500 w:0)
+	/// Storage: LivingAssetsOwnership SoulboundCollection (r:500 w:0)
+	/// Storage: LivingAssetsOwnership PausedCollection (r:500 w:0)
+	/// Storage: LivingAssetsOwnership OwnerAssetCount (r:1000 w:1000)
+	/// Storage: LivingAssetsOwnership AssetIndexOfOwner (r:500 w:1000)
+	/// Storage: LivingAssetsOwnership OwnerAssetByIndex (r:500 w:1500)
+	/// The range of component `n` is `[1, 500]`.
+	fn batch_transfer(n: u32, ) -> Weight {
+		Weight::from_parts(4_900_000, 0)
+			.saturating_add(Weight::from_parts(36_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads((16_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((10_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 41_489).saturating_mul(n.into()))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:1)
+	/// Storage: LivingAssetsOwnership CollectionOwner (r:1 w:1)
+	/// Storage: LivingAssetsOwnership CollectionMetadataFrozen (r:1 w:0)
+	/// Storage: LivingAssetsOwnership AssetOwner (r:1 w:0)
+	/// Storage: LivingAssetsOwnership AssetTokenURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership AssetRoyalty (r:1 w:0)
+	/// Storage: LivingAssetsOwnership AssetLocks (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionDepositOf (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: LivingAssetsOwnership CollectionName (r:0 w:1)
+	/// Storage: LivingAssetsOwnership CollectionSymbol (r:0 w:1)
+	/// Storage: LivingAssetsOwnership CollectionRoyalty (r:0 w:1)
+	/// Storage: LivingAssetsOwnership CollectionMintingMode (r:0 w:1)
+	/// Storage: LivingAssetsOwnership CollectionTotalSupply (r:0 w:1)
+	fn destroy_collection() -> Weight {
+		Weight::from_parts(48_000_000, 18_620)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionOwner (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionRoles (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionMetadataFrozen (r:1 w:1)
+	fn freeze_metadata() -> Weight {
+		Weight::from_parts(17_000_000, 9_092)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionOwner (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionRoles (r:1 w:0)
+	/// Storage: LivingAssetsOwnership SoulboundCollection (r:1 w:1)
+	fn make_soulbound() -> Weight {
+		Weight::from_parts(17_000_000, 9_092)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionOwner (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionRoles (r:1 w:0)
+	/// Storage: LivingAssetsOwnership PausedCollection (r:1 w:1)
+	fn pause() -> Weight {
+		Weight::from_parts(15_000_000, 10_621)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionOwner (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionRoles (r:1 w:0)
+	/// Storage: LivingAssetsOwnership PausedCollection (r:1 w:1)
+	fn unpause() -> Weight {
+		Weight::from_parts(15_000_000, 10_621)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionOwner (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionRoles (r:2 w:1)
+	fn grant_role() -> Weight {
+		Weight::from_parts(18_000_000, 10_661)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionOwner (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionRoles (r:2 w:1)
+	fn revoke_role() -> Weight {
+		Weight::from_parts(18_000_000, 10_661)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionRoles (r:1 w:1)
+	fn renounce_role() -> Weight {
+		Weight::from_parts(13_000_000, 5_068)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionOwner (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionRoles (r:1 w:0)
+	fn has_role() -> Weight {
+		Weight::from_parts(11_000_000, 8_086)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionOwner (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionRoles (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionRoyalty (r:0 w:1)
+	fn set_default_royalty() -> Weight {
+		Weight::from_parts(16_000_000, 6_168)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionOwner (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionRoles (r:1 w:0)
+	/// Storage: LivingAssetsOwnership AssetRoyalty (r:0 w:1)
+	fn set_token_royalty() -> Weight {
+		Weight::from_parts(17_000_000, 6_168)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership BurnedAsset (r:1 w:0)
+	/// Storage: LivingAssetsOwnership Progress (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionMintingMode (r:1 w:0)
+	/// Storage: LivingAssetsOwnership AssetOwner (r:1 w:0)
+	fn owner_of() -> Weight {
+		Weight::from_parts(12_000_000, 12_555)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership BurnedAsset (r:1 w:0)
+	/// Storage: LivingAssetsOwnership Progress (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionMintingMode (r:1 w:0)
+	/// Storage: LivingAssetsOwnership AssetOwner (r:1 w:0)
+	/// Storage: LivingAssetsOwnership AssetTokenURI (r:1 w:0)
+	/// The range of component `b` is `[0, 2015]`.
+	fn token_uri(b: u32, ) -> Weight {
+		Weight::from_parts(13_000_000, 15_142)
+			.saturating_add(Weight::from_parts(1_200, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// The range of component `b` is `[0, 2015]`.
+	fn base_uri(b: u32, ) -> Weight {
+		Weight::from_parts(6_500_000, 1_493)
+			.saturating_add(Weight::from_parts(620, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership BurnedAsset (r:1 w:0)
+	/// Storage: LivingAssetsOwnership Progress (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionMintingMode (r:1 w:0)
+	/// Storage: LivingAssetsOwnership AssetOwner (r:1 w:0)
+	/// Storage: LivingAssetsOwnership OperatorApproval (r:1 w:0)
+	/// Storage: LivingAssetsOwnership AssetApproval (r:0 w:1)
+	fn approve() -> Weight {
+		Weight::from_parts(20_000_000, 16_087)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership BurnedAsset (r:1 w:0)
+	/// Storage: LivingAssetsOwnership Progress (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionMintingMode (r:1 w:0)
+	/// Storage: LivingAssetsOwnership AssetOwner (r:1 w:0)
+	/// Storage: LivingAssetsOwnership AssetApproval (r:1 w:0)
+	fn get_approved() -> Weight {
+		Weight::from_parts(12_000_000, 15_142)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership OperatorApproval (r:0 w:1)
+	fn set_approval_for_all() -> Weight {
+		Weight::from_parts(13_000_000, 1_493)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership OperatorApproval (r:1 w:0)
+	fn is_approved_for_all() -> Weight {
+		Weight::from_parts(9_400_000, 5_024)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership BurnedAsset (r:1 w:0)
+	/// Storage: LivingAssetsOwnership Progress (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionMintingMode (r:1 w:0)
+	/// Storage: LivingAssetsOwnership AssetOwner (r:1 w:0)
+	/// Storage: LivingAssetsOwnership SoulboundCollection (r:1 w:0)
+	fn locked() -> Weight {
+		Weight::from_parts(9_100_000, 11_610)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership BurnedAsset (r:1 w:0)
+	/// Storage: LivingAssetsOwnership Progress (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionMintingMode (r:1 w:0)
+	/// Storage: LivingAssetsOwnership AssetOwner (r:1 w:0)
+	/// Storage: LivingAssetsOwnership AssetLocks (r:1 w:0)
+	fn is_locked() -> Weight {
+		Weight::from_parts(9_200_000, 14_283)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership PausedCollection (r:1 w:0)
+	fn paused() -> Weight {
+		Weight::from_parts(8_300_000, 5_024)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership BurnedAsset (r:1 w:0)
+	/// Storage: LivingAssetsOwnership Progress (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionMintingMode (r:1 w:0)
+	/// Storage: LivingAssetsOwnership AssetOwner (r:1 w:0)
+	/// Storage: LivingAssetsOwnership AssetApproval (r:1 w:0)
+	/// Storage: LivingAssetsOwnership OperatorApproval (r:1 w:0)
+	/// Storage: LivingAssetsOwnership AssetUser (r:0 w:1)
+	fn set_user() -> Weight {
+		Weight::from_parts(21_000_000, 18_650)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership BurnedAsset (r:1 w:0)
+	/// Storage: LivingAssetsOwnership Progress (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionMintingMode (r:1 w:0)
+	/// Storage: LivingAssetsOwnership AssetOwner (r:1 w:0)
+	/// Storage: LivingAssetsOwnership AssetUser (r:1 w:0)
+	/// Storage: Timestamp Now (r:1 w:0)
+	fn user_of() -> Weight {
+		Weight::from_parts(11_000_000, 15_776)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership BurnedAsset (r:1 w:0)
+	/// Storage: LivingAssetsOwnership Progress (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionMintingMode (r:1 w:0)
+	/// Storage: LivingAssetsOwnership AssetOwner (r:1 w:0)
+	/// Storage: LivingAssetsOwnership AssetUser (r:1 w:0)
+	fn user_expires() -> Weight {
+		Weight::from_parts(9_200_000, 14_283)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership AssetRoyalty (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionRoyalty (r:1 w:0)
+	fn royalty_info() -> Weight {
+		Weight::from_parts(12_000_000, 8_556)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership Progress (r:1 w:0)
+	/// Storage: LivingAssetsOwnership OwnerAssetCount (r:1 w:0)
+	fn balance_of() -> Weight {
+		Weight::from_parts(7_400_000, 7_494)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership Progress (r:1 w:0)
+	/// Storage: LivingAssetsOwnership OwnerAssetByIndex (r:1 w:0)
+	fn token_of_owner_by_index() -> Weight {
+		Weight::from_parts(8_100_000, 7_621)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionMintingMode (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionTotalSupply (r:1 w:0)
+	fn total_supply() -> Weight {
+		Weight::from_parts(9_100_000, 6_512)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership PausedCollection (r:1 w:0)
+	/// Storage: LivingAssetsOwnership BurnedAsset (r:1 w:1)
+	/// Storage: LivingAssetsOwnership Progress (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionMintingMode (r:1 w:0)
+	/// Storage: LivingAssetsOwnership AssetLocks (r:1 w:0)
+	/// Storage: LivingAssetsOwnership AssetOwner (r:1 w:1)
+	/// Storage: LivingAssetsOwnership AssetApproval (r:1 w:1)
+	/// Storage: LivingAssetsOwnership AssetUser (r:1 w:1)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Storage: LivingAssetsOwnership OperatorApproval (r:1 w:0)
+	/// Storage: LivingAssetsOwnership AssetTokenURI (r:0 w:1)
+	/// Storage: LivingAssetsOwnership AssetRoyalty (r:0 w:1)
+	/// Storage: LivingAssetsOwnership CollectionTotalSupply (r:1 w:1)
+	/// Storage: LivingAssetsOwnership OwnerAssetCount (r:1 w:1)
+	/// Storage: LivingAssetsOwnership AssetIndexOfOwner (r:1 w:2)
+	/// Storage: LivingAssetsOwnership OwnerAssetByIndex (r:1 w:2)
+	fn burn() -> Weight {
+		Weight::from_parts(36_000_000, 38_877)
+			.saturating_add(T::DbWeight::get().reads(15_u64))
+			.saturating_add(T::DbWeight::get().writes(12_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionMintingMode (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionOwner (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionRoles (r:1 w:0)
+	/// Storage: LivingAssetsOwnership PausedCollection (r:1 w:0)
+	/// Storage: LivingAssetsOwnership BurnedAsset (r:1 w:0)
+	/// Storage: LivingAssetsOwnership Progress (r:1 w:0)
+	/// Storage: LivingAssetsOwnership AssetOwner (r:1 w:1)
+	/// Storage: LivingAssetsOwnership CollectionTotalSupply (r:1 w:1)
+	/// Storage: LivingAssetsOwnership OwnerAssetCount (r:1 w:1)
+	/// Storage: LivingAssetsOwnership AssetIndexOfOwner (r:0 w:1)
+	/// Storage: LivingAssetsOwnership OwnerAssetByIndex (r:0 w:1)
+	fn mint() -> Weight {
+		Weight::from_parts(31_000_000, 25_672)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionOwner (r:1 w:0)
+	fn owner_of_collection() -> Weight {
+		Weight::from_parts(5_900_000, 3_531)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionName (r:1 w:0)
+	fn name() -> Weight {
+		Weight::from_parts(8_300_000, 3_593)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionSymbol (r:1 w:0)
+	fn symbol() -> Weight {
+		Weight::from_parts(8_200_000, 3_545)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: LivingAssetsOwnership CollectionCounter (r:1 w:1)
+	/// Storage: LivingAssetsOwnership CollectionDepositOf (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: LivingAssetsOwnership CollectionOwner (r:0 w:1)
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:0 w:1)
+	/// The range of component `b` is `[0, 2015]`.
+	fn create_collection(b: u32, ) -> Weight {
+		Weight::from_parts(32_000_000, 3_593)
+			.saturating_add(Weight::from_parts(1_400, 0).saturating_mul(b.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionCounter (r:1 w:1)
+	/// Storage: LivingAssetsOwnership CollectionDepositOf (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: LivingAssetsOwnership CollectionOwner (r:0 w:1)
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:0 w:1)
+	/// Storage: LivingAssetsOwnership CollectionName (r:0 w:1)
+	/// Storage: LivingAssetsOwnership CollectionSymbol (r:0 w:1)
+	/// The range of component `b` is `[0, 2015]`.
+	fn create_collection_with_name(b: u32, ) -> Weight {
+		Weight::from_parts(35_000_000, 3_593)
+			.saturating_add(Weight::from_parts(1_400, 0).saturating_mul(b.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionCounter (r:1 w:1)
+	/// Storage: LivingAssetsOwnership CollectionDepositOf (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: LivingAssetsOwnership CollectionOwner (r:0 w:1)
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:0 w:1)
+	/// Storage: LivingAssetsOwnership CollectionName (r:0 w:1)
+	/// Storage: LivingAssetsOwnership CollectionSymbol (r:0 w:1)
+	/// Storage: LivingAssetsOwnership CollectionMintingMode (r:0 w:1)
+	/// The range of component `b` is `[0, 2015]`.
+	fn create_collection_with_mode(b: u32, ) -> Weight {
+		Weight::from_parts(36_000_000, 3_593)
+			.saturating_add(Weight::from_parts(1_400, 0).saturating_mul(b.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionOwner (r:1 w:1)
+	/// Storage: LivingAssetsOwnership CollectionDepositOf (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
+	fn transfer_collection_ownership() -> Weight {
+		Weight::from_parts(34_000_000, 11_231)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionOwner (r:1 w:1)
+	/// Storage: LivingAssetsOwnership CollectionDepositOf (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	fn renounce_collection_ownership() -> Weight {
+		Weight::from_parts(28_000_000, 8_617)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:1)
+	/// Storage: LivingAssetsOwnership CollectionOwner (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionRoles (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionDepositOf (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
+	/// Storage: LivingAssetsOwnership CollectionMetadataFrozen (r:1 w:0)
+	/// The range of component `b` is `[0, 2015]`.
+	fn set_base_uri(b: u32, ) -> Weight {
+		Weight::from_parts(40_000_000, 13_854)
+			.saturating_add(Weight::from_parts(1_400, 0).saturating_mul(b.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionOwner (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionRoles (r:1 w:0)
+	/// Storage: LivingAssetsOwnership BurnedAsset (r:1 w:0)
+	/// Storage: LivingAssetsOwnership Progress (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionMintingMode (r:1 w:0)
+	/// Storage: LivingAssetsOwnership AssetOwner (r:1 w:0)
+	/// Storage: LivingAssetsOwnership AssetTokenURI (r:0 w:1)
+	/// Storage: LivingAssetsOwnership CollectionMetadataFrozen (r:1 w:0)
+	/// The range of component `b` is `[0, 2015]`.
+	fn set_token_uri(b: u32, ) -> Weight {
+		Weight::from_parts(19_000_000, 17_717)
+			.saturating_add(Weight::from_parts(1_400, 0).saturating_mul(b.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership BurnedAsset (r:1 w:0)
+	/// Storage: LivingAssetsOwnership Progress (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionMintingMode (r:1 w:0)
+	/// Storage: LivingAssetsOwnership AssetLocks (r:1 w:0)
+	/// Storage: LivingAssetsOwnership AssetOwner (r:1 w:1)
+	/// Storage: LivingAssetsOwnership AssetApproval (r:1 w:1)
+	/// Storage: LivingAssetsOwnership AssetUser (r:1 w:1)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Storage: LivingAssetsOwnership SoulboundCollection (r:1 w:0)
+	/// Storage: LivingAssetsOwnership PausedCollection (r:1 w:0)
+	/// Storage: LivingAssetsOwnership OwnerAssetCount (r:2 w:2)
+	/// Storage: LivingAssetsOwnership AssetIndexOfOwner (r:1 w:2)
+	/// Storage: LivingAssetsOwnership OwnerAssetByIndex (r:1 w:3)
+	fn transfer() -> Weight {
+		Weight::from_parts(36_000_000, 37_957)
+			.saturating_add(RocksDbWeight::get().reads(15_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership BurnedAsset (r:1 w:0)
+	/// Storage: LivingAssetsOwnership Progress (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionMintingMode (r:1 w:0)
+	/// Storage: LivingAssetsOwnership AssetLocks (r:1 w:0)
+	/// Storage: LivingAssetsOwnership AssetOwner (r:1 w:1)
+	/// Storage: LivingAssetsOwnership AssetApproval (r:1 w:1)
+	/// Storage: LivingAssetsOwnership AssetUser (r:1 w:1)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Storage: LivingAssetsOwnership OperatorApproval (r:1 w:0)
+	/// Storage: LivingAssetsOwnership SoulboundCollection (r:1 w:0)
+	/// Storage: LivingAssetsOwnership PausedCollection (r:1 w:0)
+	/// Storage: LivingAssetsOwnership OwnerAssetCount (r:2 w:2)
+	/// Storage: LivingAssetsOwnership AssetIndexOfOwner (r:1 w:2)
+	/// Storage: LivingAssetsOwnership OwnerAssetByIndex (r:1 w:3)
+	fn transfer_from() -> Weight {
+		Weight::from_parts(39_000_000, 41_489)
+			.saturating_add(RocksDbWeight::get().reads(16_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:500 w:0)
+	/// Storage: LivingAssetsOwnership BurnedAsset (r:500 w:0)
+	/// Storage: LivingAssetsOwnership Progress (r:500 w:0)
+	/// Storage: LivingAssetsOwnership CollectionMintingMode (r:500 w:0)
+	/// Storage: LivingAssetsOwnership AssetLocks (r:500 w:0)
+	/// Storage: LivingAssetsOwnership AssetOwner (r:500 w:500)
+	/// Storage: LivingAssetsOwnership AssetApproval (r:500 w:500)
+	/// Storage: LivingAssetsOwnership AssetUser (r:500 w:500)
+	/// Storage: Timestamp Now (r:500 w:0)
+	/// Storage: LivingAssetsOwnership OperatorApproval (r:500 w:0)
+	/// Storage: LivingAssetsOwnership SoulboundCollection (r:500 w:0)
+	/// Storage: LivingAssetsOwnership PausedCollection (r:500 w:0)
+	/// Storage: LivingAssetsOwnership OwnerAssetCount (r:1000 w:1000)
+	/// Storage: LivingAssetsOwnership AssetIndexOfOwner (r:500 w:1000)
+	/// Storage: LivingAssetsOwnership OwnerAssetByIndex (r:500 w:1500)
+	/// The range of component `n` is `[1, 500]`.
+	fn batch_transfer(n: u32, ) -> Weight {
+		Weight::from_parts(4_900_000, 0)
+			.saturating_add(Weight::from_parts(36_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads((16_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((10_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 41_489).saturating_mul(n.into()))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:1)
+	/// Storage: LivingAssetsOwnership CollectionOwner (r:1 w:1)
+	/// Storage: LivingAssetsOwnership CollectionMetadataFrozen (r:1 w:0)
+	/// Storage: LivingAssetsOwnership AssetOwner (r:1 w:0)
+	/// Storage: LivingAssetsOwnership AssetTokenURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership AssetRoyalty (r:1 w:0)
+	/// Storage: LivingAssetsOwnership AssetLocks (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionDepositOf (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: LivingAssetsOwnership CollectionName (r:0 w:1)
+	/// Storage: LivingAssetsOwnership CollectionSymbol (r:0 w:1)
+	/// Storage: LivingAssetsOwnership CollectionRoyalty (r:0 w:1)
+	/// Storage: LivingAssetsOwnership CollectionMintingMode (r:0 w:1)
+	/// Storage: LivingAssetsOwnership CollectionTotalSupply (r:0 w:1)
+	fn destroy_collection() -> Weight {
+		Weight::from_parts(48_000_000, 18_620)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionOwner (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionRoles (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionMetadataFrozen (r:1 w:1)
+	fn freeze_metadata() -> Weight {
+		Weight::from_parts(17_000_000, 9_092)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionOwner (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionRoles (r:1 w:0)
+	/// Storage: LivingAssetsOwnership SoulboundCollection (r:1 w:1)
+	fn make_soulbound() -> Weight {
+		Weight::from_parts(17_000_000, 9_092)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionOwner (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionRoles (r:1 w:0)
+	/// Storage: LivingAssetsOwnership PausedCollection (r:1 w:1)
+	fn pause() -> Weight {
+		Weight::from_parts(15_000_000, 10_621)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionOwner (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionRoles (r:1 w:0)
+	/// Storage: LivingAssetsOwnership PausedCollection (r:1 w:1)
+	fn unpause() -> Weight {
+		Weight::from_parts(15_000_000, 10_621)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionOwner (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionRoles (r:2 w:1)
+	fn grant_role() -> Weight {
+		Weight::from_parts(18_000_000, 10_661)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionOwner (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionRoles (r:2 w:1)
+	fn revoke_role() -> Weight {
+		Weight::from_parts(18_000_000, 10_661)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionRoles (r:1 w:1)
+	fn renounce_role() -> Weight {
+		Weight::from_parts(13_000_000, 5_068)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionOwner (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionRoles (r:1 w:0)
+	fn has_role() -> Weight {
+		Weight::from_parts(11_000_000, 8_086)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionOwner (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionRoles (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionRoyalty (r:0 w:1)
+	fn set_default_royalty() -> Weight {
+		Weight::from_parts(16_000_000, 6_168)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionOwner (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionRoles (r:1 w:0)
+	/// Storage: LivingAssetsOwnership AssetRoyalty (r:0 w:1)
+	fn set_token_royalty() -> Weight {
+		Weight::from_parts(17_000_000, 6_168)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership BurnedAsset (r:1 w:0)
+	/// Storage: LivingAssetsOwnership Progress (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionMintingMode (r:1 w:0)
+	/// Storage: LivingAssetsOwnership AssetOwner (r:1 w:0)
+	fn owner_of() -> Weight {
+		Weight::from_parts(12_000_000, 12_555)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership BurnedAsset (r:1 w:0)
+	/// Storage: LivingAssetsOwnership Progress (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionMintingMode (r:1 w:0)
+	/// Storage: LivingAssetsOwnership AssetOwner (r:1 w:0)
+	/// Storage: LivingAssetsOwnership AssetTokenURI (r:1 w:0)
+	/// The range of component `b` is `[0, 2015]`.
+	fn token_uri(b: u32, ) -> Weight {
+		Weight::from_parts(13_000_000, 15_142)
+			.saturating_add(Weight::from_parts(1_200, 0).saturating_mul(b.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// The range of component `b` is `[0, 2015]`.
+	fn base_uri(b: u32, ) -> Weight {
+		Weight::from_parts(6_500_000, 1_493)
+			.saturating_add(Weight::from_parts(620, 0).saturating_mul(b.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership BurnedAsset (r:1 w:0)
+	/// Storage: LivingAssetsOwnership Progress (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionMintingMode (r:1 w:0)
+	/// Storage: LivingAssetsOwnership AssetOwner (r:1 w:0)
+	/// Storage: LivingAssetsOwnership OperatorApproval (r:1 w:0)
+	/// Storage: LivingAssetsOwnership AssetApproval (r:0 w:1)
+	fn approve() -> Weight {
+		Weight::from_parts(20_000_000, 16_087)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership BurnedAsset (r:1 w:0)
+	/// Storage: LivingAssetsOwnership Progress (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionMintingMode (r:1 w:0)
+	/// Storage: LivingAssetsOwnership AssetOwner (r:1 w:0)
+	/// Storage: LivingAssetsOwnership AssetApproval (r:1 w:0)
+	fn get_approved() -> Weight {
+		Weight::from_parts(12_000_000, 15_142)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership OperatorApproval (r:0 w:1)
+	fn set_approval_for_all() -> Weight {
+		Weight::from_parts(13_000_000, 1_493)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership OperatorApproval (r:1 w:0)
+	fn is_approved_for_all() -> Weight {
+		Weight::from_parts(9_400_000, 5_024)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership BurnedAsset (r:1 w:0)
+	/// Storage: LivingAssetsOwnership Progress (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionMintingMode (r:1 w:0)
+	/// Storage: LivingAssetsOwnership AssetOwner (r:1 w:0)
+	/// Storage: LivingAssetsOwnership SoulboundCollection (r:1 w:0)
+	fn locked() -> Weight {
+		Weight::from_parts(9_100_000, 11_610)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership BurnedAsset (r:1 w:0)
+	/// Storage: LivingAssetsOwnership Progress (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionMintingMode (r:1 w:0)
+	/// Storage: LivingAssetsOwnership AssetOwner (r:1 w:0)
+	/// Storage: LivingAssetsOwnership AssetLocks (r:1 w:0)
+	fn is_locked() -> Weight {
+		Weight::from_parts(9_200_000, 14_283)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership PausedCollection (r:1 w:0)
+	fn paused() -> Weight {
+		Weight::from_parts(8_300_000, 5_024)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership BurnedAsset (r:1 w:0)
+	/// Storage: LivingAssetsOwnership Progress (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionMintingMode (r:1 w:0)
+	/// Storage: LivingAssetsOwnership AssetOwner (r:1 w:0)
+	/// Storage: LivingAssetsOwnership AssetApproval (r:1 w:0)
+	/// Storage: LivingAssetsOwnership OperatorApproval (r:1 w:0)
+	/// Storage: LivingAssetsOwnership AssetUser (r:0 w:1)
+	fn set_user() -> Weight {
+		Weight::from_parts(21_000_000, 18_650)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership BurnedAsset (r:1 w:0)
+	/// Storage: LivingAssetsOwnership Progress (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionMintingMode (r:1 w:0)
+	/// Storage: LivingAssetsOwnership AssetOwner (r:1 w:0)
+	/// Storage: LivingAssetsOwnership AssetUser (r:1 w:0)
+	/// Storage: Timestamp Now (r:1 w:0)
+	fn user_of() -> Weight {
+		Weight::from_parts(11_000_000, 15_776)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership BurnedAsset (r:1 w:0)
+	/// Storage: LivingAssetsOwnership Progress (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionMintingMode (r:1 w:0)
+	/// Storage: LivingAssetsOwnership AssetOwner (r:1 w:0)
+	/// Storage: LivingAssetsOwnership AssetUser (r:1 w:0)
+	fn user_expires() -> Weight {
+		Weight::from_parts(9_200_000, 14_283)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership AssetRoyalty (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionRoyalty (r:1 w:0)
+	fn royalty_info() -> Weight {
+		Weight::from_parts(12_000_000, 8_556)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership Progress (r:1 w:0)
+	/// Storage: LivingAssetsOwnership OwnerAssetCount (r:1 w:0)
+	fn balance_of() -> Weight {
+		Weight::from_parts(7_400_000, 7_494)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership Progress (r:1 w:0)
+	/// Storage: LivingAssetsOwnership OwnerAssetByIndex (r:1 w:0)
+	fn token_of_owner_by_index() -> Weight {
+		Weight::from_parts(8_100_000, 7_621)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionMintingMode (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionTotalSupply (r:1 w:0)
+	fn total_supply() -> Weight {
+		Weight::from_parts(9_100_000, 6_512)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership PausedCollection (r:1 w:0)
+	/// Storage: LivingAssetsOwnership BurnedAsset (r:1 w:1)
+	/// Storage: LivingAssetsOwnership Progress (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionMintingMode (r:1 w:0)
+	/// Storage: LivingAssetsOwnership AssetLocks (r:1 w:0)
+	/// Storage: LivingAssetsOwnership AssetOwner (r:1 w:1)
+	/// Storage: LivingAssetsOwnership AssetApproval (r:1 w:1)
+	/// Storage: LivingAssetsOwnership AssetUser (r:1 w:1)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Storage: LivingAssetsOwnership OperatorApproval (r:1 w:0)
+	/// Storage: LivingAssetsOwnership AssetTokenURI (r:0 w:1)
+	/// Storage: LivingAssetsOwnership AssetRoyalty (r:0 w:1)
+	/// Storage: LivingAssetsOwnership CollectionTotalSupply (r:1 w:1)
+	/// Storage: LivingAssetsOwnership OwnerAssetCount (r:1 w:1)
+	/// Storage: LivingAssetsOwnership AssetIndexOfOwner (r:1 w:2)
+	/// Storage: LivingAssetsOwnership OwnerAssetByIndex (r:1 w:2)
+	fn burn() -> Weight {
+		Weight::from_parts(36_000_000, 38_877)
+			.saturating_add(RocksDbWeight::get().reads(15_u64))
+			.saturating_add(RocksDbWeight::get().writes(12_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionMintingMode (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionOwner (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionRoles (r:1 w:0)
+	/// Storage: LivingAssetsOwnership PausedCollection (r:1 w:0)
+	/// Storage: LivingAssetsOwnership BurnedAsset (r:1 w:0)
+	/// Storage: LivingAssetsOwnership Progress (r:1 w:0)
+	/// Storage: LivingAssetsOwnership AssetOwner (r:1 w:1)
+	/// Storage: LivingAssetsOwnership CollectionTotalSupply (r:1 w:1)
+	/// Storage: LivingAssetsOwnership OwnerAssetCount (r:1 w:1)
+	/// Storage: LivingAssetsOwnership AssetIndexOfOwner (r:0 w:1)
+	/// Storage: LivingAssetsOwnership OwnerAssetByIndex (r:0 w:1)
+	fn mint() -> Weight {
+		Weight::from_parts(31_000_000, 25_672)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionOwner (r:1 w:0)
+	fn owner_of_collection() -> Weight {
+		Weight::from_parts(5_900_000, 3_531)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionName (r:1 w:0)
+	fn name() -> Weight {
+		Weight::from_parts(8_300_000, 3_593)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+	}
+	/// Storage: LivingAssetsOwnership CollectionBaseURI (r:1 w:0)
+	/// Storage: LivingAssetsOwnership CollectionSymbol (r:1 w:0)
+	fn symbol() -> Weight {
+		Weight::from_parts(8_200_000, 3_545)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+	}
+}
@@ -8,9 +8,278 @@ interface IERC721 {
      */
     function tokenURI(uint256 _tokenId) external view returns (string memory);
 
+    /**
+     * @dev See {IERC721Metadata-name}. Empty if the collection was created without a name.
+     */
+    function name() external view returns (string memory);
+
+    /**
+     * @dev See {IERC721Metadata-symbol}. Empty if the collection was created without a symbol.
+     */
+    function symbol() external view returns (string memory);
+
     function ownerOf(uint256 _tokenId) external view returns (address);
 
+    /**
+     * @dev Number of assets explicitly owned by `_owner`, that is, minted or transferred to it
+     * at some point. Assets still held by the initial owner encoded in their id are not counted,
+     * so `_owner` may own more assets than reported.
+     */
+    function balanceOf(address _owner) external view returns (uint256);
+
+    /**
+     * @dev Number of minted assets that have not been burned. Reverts unless the collection was
+     * created with explicit minting, as any asset of other collections exists.
+     */
+    function totalSupply() external view returns (uint256);
+
+    /**
+     * @dev See {IERC721Enumerable-tokenOfOwnerByIndex}, restricted to the assets counted by
+     * `balanceOf`. The order is not stable, as transferring an asset away moves the last one
+     * into its index.
+     */
+    function tokenOfOwnerByIndex(address _owner, uint256 _index) external view returns (uint256);
+
     event Transfer(address indexed _from, address indexed _to, uint256 indexed _tokenId);
 
     function transferFrom(address _from, address _to, uint256 _tokenId) external;
+
+    /**
+     * @dev Transfers `_tokenId` as `transferFrom` does and, if `_to` is a contract, requires it to
+     * return `IERC721Receiver.onERC721Received.selector` when called with `_data`.
+     */
+    function safeTransferFrom(address _from, address _to, uint256 _tokenId, bytes calldata _data) external;
+
+    /**
+     * @dev Same as `safeTransferFrom` with empty `_data`.
+     */
+    function safeTransferFrom(address _from, address _to, uint256 _tokenId) external;
+
+    /**
+     * @dev Transfers `_tokenIds[i]` from `_from` to `_to[i]` for every `i`, all of them or none.
+     * Emits a `Transfer` event per asset.
+     */
+    function batchTransferFrom(address _from, address[] calldata _to, uint256[] calldata _tokenIds) external;
+
+    /**
+     * @dev Creates `_tokenId` owned by `_to`, emitting a `Transfer` from the zero address. Only
+     * callable by the owner or a minter, on collections created with explicit minting. Ids can
+     * only be minted once, and not past the max supply given at creation.
+     */
+    function mint(address _to, uint256 _tokenId) external;
+
+    /**
+     * @dev Destroys `_tokenId`, emitting a `Transfer` to the zero address. The caller must be
+     * the owner, the approved address or an operator of the owner.
+     */
+    function burn(uint256 _tokenId) external;
+
+    event Approval(address indexed _owner, address indexed _approved, uint256 indexed _tokenId);
+
+    event ApprovalForAll(address indexed _owner, address indexed _operator, bool _approved);
+
+    function approve(address _approved, uint256 _tokenId) external;
+
+    function getApproved(uint256 _tokenId) external view returns (address);
+
+    function setApprovalForAll(address _operator, bool _approved) external;
+
+    function isApprovedForAll(address _owner, address _operator) external view returns (bool);
+
+    /**
+     * @dev See ERC-173. The owner of the collection, or the zero address if it has no owner.
+     */
+    event OwnershipTransferred(address indexed previousOwner, address indexed newOwner);
+
+    function owner() external view returns (address);
+
+    /**
+     * @dev Transferring to the zero address renounces the ownership, as stated by ERC-173.
+     */
+    function transferOwnership(address _newOwner) external;
+
+    function renounceOwnership() external;
+
+    /**
+     * @dev See ERC-4906. Emitted with `_fromTokenId = 0` and `_toTokenId = type(uint256).max`
+     * when the base URI of the collection changes.
+     */
+    event BatchMetadataUpdate(uint256 _fromTokenId, uint256 _toTokenId);
+
+    /**
+     * @dev See ERC-4906. Emitted when the token URI of `_tokenId` changes.
+     */
+    event MetadataUpdate(uint256 _tokenId);
+
+    /**
+     * @dev Replaces the base URI of the collection. Only callable by the owner or a metadata
+     * manager.
+     */
+    function setBaseURI(string calldata _baseURI) external;
+
+    /**
+     * @dev Overrides the URI of `_tokenId`, an empty `_tokenURI` restores the one derived
+     * from the base URI. Only callable by the owner or a metadata manager.
+     */
+    function setTokenURI(uint256 _tokenId, string calldata _tokenURI) external;
+
+    /**
+     * @dev Emitted once with `_id = type(uint256).max` when the metadata of the collection is
+     * frozen. That id is not an asset but stands for every asset of the collection, which holds
+     * too many of them to emit the event for each.
+     */
+    event PermanentURI(string _value, uint256 indexed _id);
+
+    /**
+     * @dev Irreversibly prevents any later change of the base URI and token URIs of the
+     * collection. Only callable by the owner or a metadata manager.
+     */
+    function freezeMetadata() external;
+
+    /**
+     * @dev Destroys the collection, which must have no transferred assets nor token URIs and
+     * unfrozen metadata. Emits `OwnershipTransferred` to the zero address. Only callable by the owner.
+     */
+    function destroyCollection() external;
+
+    /**
+     * @dev See ERC-5192. Emitted once with `tokenId = type(uint256).max` when the collection is
+     * made soulbound. That id is not an asset but stands for every asset of the collection, which
+     * holds too many of them to emit the event for each.
+     */
+    event Locked(uint256 tokenId);
+
+    /**
+     * @dev See ERC-5192. Whether `tokenId` can not be transferred, which holds for every asset
+     * of a soulbound collection.
+     */
+    function locked(uint256 tokenId) external view returns (bool);
+
+    /**
+     * @dev Whether `_tokenId` holds a lock placed by a pallet of the chain, for instance while it
+     * is staked or listed. Locked assets can be neither transferred nor burned.
+     */
+    function isLocked(uint256 _tokenId) external view returns (bool);
+
+    /**
+     * @dev See OpenZeppelin {Pausable}. Emitted by `pause` with the caller as `account`.
+     */
+    event Paused(address account);
+
+    /**
+     * @dev See OpenZeppelin {Pausable}. Emitted by `unpause` with the caller as `account`.
+     */
+    event Unpaused(address account);
+
+    /**
+     * @dev Whether the collection is paused, in which case none of its assets can be transferred
+     * nor burned.
+     */
+    function paused() external view returns (bool);
+
+    /**
+     * @dev Halts every transfer and burn of the assets of the collection. Only callable by the
+     * owner or a pauser, the chain governance can also pause any collection.
+     */
+    function pause() external;
+
+    /**
+     * @dev Resumes the transfers and burns of the assets of a paused collection. Only callable by
+     * the owner or a pauser.
+     */
+    function unpause() external;
+
+    /**
+     * @dev See ERC-4907. Also emitted with the zero address when a transfer or a burn resets the
+     * user of `tokenId`, unless it had already expired.
+     */
+    event UpdateUser(uint256 indexed tokenId, address indexed user, uint64 expires);
+
+    /**
+     * @dev See ERC-4907. `expires` is a unix timestamp in seconds. The zero address removes the
+     * user. Callable by the owner, the approved address or an operator of the owner.
+     */
+    function setUser(uint256 tokenId, address user, uint64 expires) external;
+
+    /**
+     * @dev See ERC-4907. The zero address if there is no user or it has expired.
+     */
+    function userOf(uint256 tokenId) external view returns (address);
+
+    /**
+     * @dev See ERC-4907. Zero if there is no user.
+     */
+    function userExpires(uint256 tokenId) external view returns (uint256);
+
+    /**
+     * @dev Irreversibly prevents the assets of the collection from being transferred. They can
+     * still be burned. Only callable by the owner or an admin.
+     */
+    function makeSoulbound() external;
+
+    /**
+     * @dev See ERC-165. Reports ERC-165, ERC-721, ERC-721 Metadata, ERC-173, ERC-2981, ERC-4906,
+     * ERC-4907, ERC-5192 and OpenZeppelin IAccessControl.
+     */
+    function supportsInterface(bytes4 interfaceID) external view returns (bool);
+
+    /**
+     * @dev See ERC-2981. The zero address and no royalty if none was set for `_tokenId` nor for
+     * the collection.
+     */
+    function royaltyInfo(uint256 _tokenId, uint256 _salePrice) external view returns (address receiver, uint256 royaltyAmount);
+
+    /**
+     * @dev Sets the royalty of the assets that have none of their own, `_feeNumerator` being in
+     * basis points. A zero `_receiver` removes it. Only callable by the owner or a royalty
+     * manager.
+     */
+    function setDefaultRoyalty(address _receiver, uint96 _feeNumerator) external;
+
+    /**
+     * @dev Sets the royalty of `_tokenId`, `_feeNumerator` being in basis points. A zero
+     * `_receiver` removes it. Only callable by the owner or a royalty manager.
+     */
+    function setTokenRoyalty(uint256 _tokenId, address _receiver, uint96 _feeNumerator) external;
+
+    /**
+     * @dev See OpenZeppelin {IAccessControl}. The roles of the collection are `bytes32(0)` for
+     * admins, `keccak256("METADATA_MANAGER_ROLE")`, `keccak256("PAUSER_ROLE")`,
+     * `keccak256("ROYALTY_MANAGER_ROLE")` and `keccak256("MINTER_ROLE")`.
+     */
+    event RoleGranted(bytes32 indexed role, address indexed account, address indexed sender);
+
+    /**
+     * @dev See OpenZeppelin {IAccessControl}. Also emitted by `renounceRole`, with the caller as
+     * both `account` and `sender`.
+     */
+    event RoleRevoked(bytes32 indexed role, address indexed account, address indexed sender);
+
+    /**
+     * @dev Whether `account` holds `role`. The owner holds every role of the collection, and
+     * nobody holds an unknown role.
+     */
+    function hasRole(bytes32 role, address account) external view returns (bool);
+
+    /**
+     * @dev Always `bytes32(0)`, as admins manage every role of the collection.
+     */
+    function getRoleAdmin(bytes32 role) external view returns (bytes32);
+
+    /**
+     * @dev Grants `role` to `account`, emitting `RoleGranted` unless it already held it. Only
+     * callable by the owner or an admin.
+     */
+    function grantRole(bytes32 role, address account) external;
+
+    /**
+     * @dev Revokes `role` from `account`, emitting `RoleRevoked` unless it did not hold it. The
+     * roles the owner holds implicitly can not be revoked. Only callable by the owner or an admin.
+     */
+    function revokeRole(bytes32 role, address account) external;
+
+    /**
+     * @dev Gives up `role`. `callerConfirmation` must be the caller.
+     */
+    function renounceRole(bytes32 role, address callerConfirmation) external;
 }
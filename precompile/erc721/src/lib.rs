@@ -1,35 +1,307 @@
 #![cfg_attr(not(feature = "std"), no_std)]
-use fp_evm::{Precompile, PrecompileHandle, PrecompileOutput};
-use frame_support::pallet_prelude::*;
-use pallet_living_assets_ownership::{address_to_collection_id, CollectionId};
+use fp_evm::{
+	Context, ExitReason, ExitRevert, Precompile, PrecompileFailure, PrecompileHandle,
+	PrecompileOutput,
+};
+use frame_support::{pallet_prelude::*, traits::Contains, weights::Weight};
+use pallet_living_assets_ownership::{
+	address_to_collection_id,
+	traits::{CollectionManager, Erc721},
+	CollectionId, CollectionRole, ROYALTY_DENOMINATOR,
+};
 use precompile_utils::{
-	keccak256, revert, succeed, Address, Bytes, EvmDataWriter, EvmResult, FunctionModifier, LogExt,
-	LogsBuilder, PrecompileHandleExt,
+	keccak256, revert, succeed, Address, Bytes, EvmDataReader, EvmDataWriter, EvmResult,
+	FunctionModifier, LogExt, LogsBuilder, PrecompileHandleExt,
 };
 
+use pallet_evm::Log;
 use sp_core::{H160, H256, U256};
+use sp_runtime::{traits::Convert, SaturatedConversion};
 use sp_std::{fmt::Debug, marker::PhantomData, vec, vec::Vec};
 
 /// Solidity selector of the TransferFrom log, which is the Keccak of the Log signature.
 pub const SELECTOR_LOG_TRANSFER_FROM: [u8; 32] = keccak256!("Transfer(address,address,uint256)");
 
+/// Solidity selector of the Approval log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_APPROVAL: [u8; 32] = keccak256!("Approval(address,address,uint256)");
+
+/// Solidity selector of the ApprovalForAll log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_APPROVAL_FOR_ALL: [u8; 32] =
+	keccak256!("ApprovalForAll(address,address,bool)");
+
+/// Solidity selector of the OwnershipTransferred log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_OWNERSHIP_TRANSFERRED: [u8; 32] =
+	keccak256!("OwnershipTransferred(address,address)");
+
+/// Solidity selector of the ERC-4906 BatchMetadataUpdate log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_BATCH_METADATA_UPDATE: [u8; 32] =
+	keccak256!("BatchMetadataUpdate(uint256,uint256)");
+
+/// Solidity selector of the ERC-4906 MetadataUpdate log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_METADATA_UPDATE: [u8; 32] = keccak256!("MetadataUpdate(uint256)");
+
+/// Solidity selector of the PermanentURI log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_PERMANENT_URI: [u8; 32] = keccak256!("PermanentURI(string,uint256)");
+
+/// Solidity selector of the ERC-5192 Locked log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_LOCKED: [u8; 32] = keccak256!("Locked(uint256)");
+
+/// Token id of the `Locked` and `PermanentURI` logs emitted once for every asset of the
+/// collection, as a collection holds an asset per address and slot, far too many to log each.
+pub const ALL_ASSETS_TOKEN_ID: U256 = U256::MAX;
+
+/// Solidity selector of the Paused log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_PAUSED: [u8; 32] = keccak256!("Paused(address)");
+
+/// Solidity selector of the Unpaused log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_UNPAUSED: [u8; 32] = keccak256!("Unpaused(address)");
+
+/// Solidity selector of the ERC-4907 UpdateUser log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_UPDATE_USER: [u8; 32] = keccak256!("UpdateUser(uint256,address,uint64)");
+
+/// Solidity selector of the RoleGranted log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_ROLE_GRANTED: [u8; 32] = keccak256!("RoleGranted(bytes32,address,address)");
+
+/// Solidity selector of the RoleRevoked log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_ROLE_REVOKED: [u8; 32] = keccak256!("RoleRevoked(bytes32,address,address)");
+
+/// Role id of [`CollectionRole::Admin`], which is `DEFAULT_ADMIN_ROLE` in OpenZeppelin
+/// AccessControl. It is the admin role of every role.
+pub const ROLE_ADMIN: [u8; 32] = [0u8; 32];
+
+/// Role id of [`CollectionRole::MetadataManager`].
+pub const ROLE_METADATA_MANAGER: [u8; 32] = keccak256!("METADATA_MANAGER_ROLE");
+
+/// Role id of [`CollectionRole::Pauser`].
+pub const ROLE_PAUSER: [u8; 32] = keccak256!("PAUSER_ROLE");
+
+/// Role id of [`CollectionRole::RoyaltyManager`].
+pub const ROLE_ROYALTY_MANAGER: [u8; 32] = keccak256!("ROYALTY_MANAGER_ROLE");
+
+/// Role id of [`CollectionRole::Minter`].
+pub const ROLE_MINTER: [u8; 32] = keccak256!("MINTER_ROLE");
+
+/// The collection role of an OpenZeppelin AccessControl role id, if it is one of the above.
+fn collection_role(role: H256) -> Option<CollectionRole> {
+	match role.0 {
+		ROLE_ADMIN => Some(CollectionRole::Admin),
+		ROLE_METADATA_MANAGER => Some(CollectionRole::MetadataManager),
+		ROLE_PAUSER => Some(CollectionRole::Pauser),
+		ROLE_ROYALTY_MANAGER => Some(CollectionRole::RoyaltyManager),
+		ROLE_MINTER => Some(CollectionRole::Minter),
+		_ => None,
+	}
+}
+
+/// Selector of `onERC721Received(address,address,uint256,bytes)`, which a contract receiving an
+/// asset through `safeTransferFrom` must return to accept it.
+pub const SELECTOR_ON_ERC721_RECEIVED: u32 = 0x150b7a02;
+
+/// Gas charged for checking whether the receiver of a safe transfer is a contract, which is the
+/// cost of `EXTCODESIZE` on a cold account as given by EIP-2929.
+pub const IS_CONTRACT_GAS_COST: u64 = 2_600;
+
 #[precompile_utils_macro::generate_function_selector]
 #[derive(Debug, PartialEq)]
 pub enum Action {
 	/// Get token URI
 	TokenURI = "tokenURI(uint256)",
+	/// Name of the collection
+	Name = "name()",
+	/// Symbol of the collection
+	Symbol = "symbol()",
 	/// Owner of
 	OwnerOf = "ownerOf(uint256)",
+	/// Number of assets explicitly owned by an address
+	BalanceOf = "balanceOf(address)",
+	/// Explicitly owned asset of an address by index
+	TokenOfOwnerByIndex = "tokenOfOwnerByIndex(address,uint256)",
+	/// Number of minted assets of a collection with explicit minting
+	TotalSupply = "totalSupply()",
 	/// Transfer from
 	TransferFrom = "transferFrom(address,address,uint256)",
+	/// Safe transfer from
+	SafeTransferFrom = "safeTransferFrom(address,address,uint256)",
+	/// Safe transfer from with data for the receiver
+	SafeTransferFromWithData = "safeTransferFrom(address,address,uint256,bytes)",
+	/// Transfer several assets of the same owner
+	BatchTransferFrom = "batchTransferFrom(address,address[],uint256[])",
+	/// Approve
+	Approve = "approve(address,uint256)",
+	/// Get approved
+	GetApproved = "getApproved(uint256)",
+	/// Set approval for all
+	SetApprovalForAll = "setApprovalForAll(address,bool)",
+	/// Is approved for all
+	IsApprovedForAll = "isApprovedForAll(address,address)",
+	/// Owner of the collection
+	Owner = "owner()",
+	/// Transfer ownership of the collection
+	TransferOwnership = "transferOwnership(address)",
+	/// Renounce ownership of the collection
+	RenounceOwnership = "renounceOwnership()",
+	/// Set base URI of the collection
+	SetBaseURI = "setBaseURI(string)",
+	/// Set token URI of an asset
+	SetTokenURI = "setTokenURI(uint256,string)",
+	/// Mint an asset of a collection with explicit minting
+	Mint = "mint(address,uint256)",
+	/// Burn
+	Burn = "burn(uint256)",
+	/// Freeze the base URI and token URIs of the collection
+	FreezeMetadata = "freezeMetadata()",
+	/// Destroy the collection
+	DestroyCollection = "destroyCollection()",
+	/// Make the assets of the collection non-transferable
+	MakeSoulbound = "makeSoulbound()",
+	/// Whether an asset is non-transferable
+	Locked = "locked(uint256)",
+	/// Whether an asset holds a lock placed by another pallet
+	IsLocked = "isLocked(uint256)",
+	/// Halt the transfers of the collection
+	Pause = "pause()",
+	/// Resume the transfers of the collection
+	Unpause = "unpause()",
+	/// Whether the transfers of the collection are halted
+	Paused = "paused()",
+	/// Whether an account holds a role of the collection
+	HasRole = "hasRole(bytes32,address)",
+	/// The role managing a role of the collection
+	GetRoleAdmin = "getRoleAdmin(bytes32)",
+	/// Grant a role of the collection
+	GrantRole = "grantRole(bytes32,address)",
+	/// Revoke a role of the collection
+	RevokeRole = "revokeRole(bytes32,address)",
+	/// Give up a role of the collection
+	RenounceRole = "renounceRole(bytes32,address)",
+	/// Set the user of an asset until it expires
+	SetUser = "setUser(uint256,address,uint64)",
+	/// ERC-4907 user of an asset
+	UserOf = "userOf(uint256)",
+	/// When the user of an asset expires
+	UserExpires = "userExpires(uint256)",
+	/// ERC-165 interface detection
+	SupportsInterface = "supportsInterface(bytes4)",
+	/// ERC-2981 royalty of a sale
+	RoyaltyInfo = "royaltyInfo(uint256,uint256)",
+	/// Set the default royalty of the collection
+	SetDefaultRoyalty = "setDefaultRoyalty(address,uint96)",
+	/// Set the royalty of an asset
+	SetTokenRoyalty = "setTokenRoyalty(uint256,address,uint96)",
+}
+
+/// Computes an ERC-165 interface id, which is the XOR of the selectors of its functions.
+pub const fn interface_id(selectors: &[u32]) -> u32 {
+	let mut id = 0;
+	let mut i = 0;
+	while i < selectors.len() {
+		id ^= selectors[i];
+		i += 1;
+	}
+	id
 }
 
+/// ERC-165 interface id of ERC-165 itself.
+pub const INTERFACE_ID_ERC165: u32 = interface_id(&[Action::SupportsInterface as u32]);
+
+/// ERC-165 interface id of ERC-721.
+pub const INTERFACE_ID_ERC721: u32 = interface_id(&[
+	Action::BalanceOf as u32,
+	Action::OwnerOf as u32,
+	Action::SafeTransferFromWithData as u32,
+	Action::SafeTransferFrom as u32,
+	Action::TransferFrom as u32,
+	Action::Approve as u32,
+	Action::SetApprovalForAll as u32,
+	Action::GetApproved as u32,
+	Action::IsApprovedForAll as u32,
+]);
+
+/// ERC-165 interface id of the ERC-721 metadata extension.
+pub const INTERFACE_ID_ERC721_METADATA: u32 =
+	interface_id(&[Action::Name as u32, Action::Symbol as u32, Action::TokenURI as u32]);
+
+/// ERC-165 interface id of ERC-173, the ownership of the collection.
+pub const INTERFACE_ID_ERC173: u32 =
+	interface_id(&[Action::Owner as u32, Action::TransferOwnership as u32]);
+
+/// ERC-165 interface id of ERC-5192, soulbound assets.
+pub const INTERFACE_ID_ERC5192: u32 = interface_id(&[Action::Locked as u32]);
+
+/// ERC-165 interface id of ERC-2981, royalties.
+pub const INTERFACE_ID_ERC2981: u32 = interface_id(&[Action::RoyaltyInfo as u32]);
+
+/// ERC-165 interface id of ERC-4906. It only consists of events, so the standard fixes its id.
+pub const INTERFACE_ID_ERC4906: u32 = 0x49064906;
+
+/// ERC-165 interface id of ERC-4907, the users renting the assets.
+pub const INTERFACE_ID_ERC4907: u32 =
+	interface_id(&[Action::SetUser as u32, Action::UserOf as u32, Action::UserExpires as u32]);
+
+/// ERC-165 interface id of OpenZeppelin IAccessControl, the roles of the collection.
+pub const INTERFACE_ID_ACCESS_CONTROL: u32 = interface_id(&[
+	Action::HasRole as u32,
+	Action::GetRoleAdmin as u32,
+	Action::GrantRole as u32,
+	Action::RevokeRole as u32,
+	Action::RenounceRole as u32,
+]);
+
+/// Interfaces reported by `supportsInterface`. An interface is only listed once every function
+/// it consists of is implemented by the precompile.
+pub const SUPPORTED_INTERFACES: &[u32] = &[
+	INTERFACE_ID_ERC165,
+	INTERFACE_ID_ERC721,
+	INTERFACE_ID_ERC721_METADATA,
+	INTERFACE_ID_ERC173,
+	INTERFACE_ID_ERC2981,
+	INTERFACE_ID_ERC4906,
+	INTERFACE_ID_ERC4907,
+	INTERFACE_ID_ERC5192,
+	INTERFACE_ID_ACCESS_CONTROL,
+];
+
 /// Wrapper for the precompile function.
-pub struct Erc721Precompile<AssetManager>(PhantomData<AssetManager>);
+///
+/// `H160ToAccountId` and `AccountIdToH160` map EVM addresses to and from the accounts used by
+/// `AssetManager` to identify collection owners. Every call is charged the gas equivalent, as
+/// given by `GasWeightMapping`, of the `WeightInfo` of the operations it performs. `IsContract`
+/// tells the addresses with code, which safe transfers have to notify.
+pub struct Erc721Precompile<
+	H160ToAccountId,
+	AccountIdToH160,
+	AssetManager,
+	GasWeightMapping,
+	WeightInfo,
+	IsContract,
+>(
+	PhantomData<(
+		H160ToAccountId,
+		AccountIdToH160,
+		AssetManager,
+		GasWeightMapping,
+		WeightInfo,
+		IsContract,
+	)>,
+);
 
-impl<AssetManager> Precompile for Erc721Precompile<AssetManager>
+impl<H160ToAccountId, AccountIdToH160, AssetManager, GasWeightMapping, WeightInfo, IsContract>
+	Precompile
+	for Erc721Precompile<
+		H160ToAccountId,
+		AccountIdToH160,
+		AssetManager,
+		GasWeightMapping,
+		WeightInfo,
+		IsContract,
+	>
 where
-	AssetManager: pallet_living_assets_ownership::traits::Erc721,
+	H160ToAccountId: Convert<H160, <AssetManager as CollectionManager>::AccountId>,
+	AccountIdToH160: Convert<<AssetManager as CollectionManager>::AccountId, H160>,
+	AssetManager: Erc721 + CollectionManager,
+	GasWeightMapping: pallet_evm::GasWeightMapping,
+	WeightInfo: pallet_living_assets_ownership::WeightInfo,
+	IsContract: Contains<H160>,
 {
 	fn execute(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
 		// collection id is encoded into the contract address
@@ -40,22 +312,128 @@ where
 
 		handle.check_function_modifier(match selector {
 			Action::TokenURI => FunctionModifier::View,
+			Action::Name => FunctionModifier::View,
+			Action::Symbol => FunctionModifier::View,
 			Action::OwnerOf => FunctionModifier::View,
+			Action::BalanceOf => FunctionModifier::View,
+			Action::TokenOfOwnerByIndex => FunctionModifier::View,
+			Action::TotalSupply => FunctionModifier::View,
 			Action::TransferFrom => FunctionModifier::NonPayable,
+			Action::SafeTransferFrom => FunctionModifier::NonPayable,
+			Action::SafeTransferFromWithData => FunctionModifier::NonPayable,
+			Action::BatchTransferFrom => FunctionModifier::NonPayable,
+			Action::Approve => FunctionModifier::NonPayable,
+			Action::GetApproved => FunctionModifier::View,
+			Action::SetApprovalForAll => FunctionModifier::NonPayable,
+			Action::IsApprovedForAll => FunctionModifier::View,
+			Action::Owner => FunctionModifier::View,
+			Action::TransferOwnership => FunctionModifier::NonPayable,
+			Action::RenounceOwnership => FunctionModifier::NonPayable,
+			Action::SetBaseURI => FunctionModifier::NonPayable,
+			Action::SetTokenURI => FunctionModifier::NonPayable,
+			Action::Mint => FunctionModifier::NonPayable,
+			Action::Burn => FunctionModifier::NonPayable,
+			Action::FreezeMetadata => FunctionModifier::NonPayable,
+			Action::DestroyCollection => FunctionModifier::NonPayable,
+			Action::MakeSoulbound => FunctionModifier::NonPayable,
+			Action::Locked => FunctionModifier::View,
+			Action::IsLocked => FunctionModifier::View,
+			Action::Pause => FunctionModifier::NonPayable,
+			Action::Unpause => FunctionModifier::NonPayable,
+			Action::Paused => FunctionModifier::View,
+			Action::SetUser => FunctionModifier::NonPayable,
+			Action::UserOf => FunctionModifier::View,
+			Action::UserExpires => FunctionModifier::View,
+			Action::HasRole => FunctionModifier::View,
+			Action::GetRoleAdmin => FunctionModifier::View,
+			Action::GrantRole => FunctionModifier::NonPayable,
+			Action::RevokeRole => FunctionModifier::NonPayable,
+			Action::RenounceRole => FunctionModifier::NonPayable,
+			Action::SupportsInterface => FunctionModifier::View,
+			Action::RoyaltyInfo => FunctionModifier::View,
+			Action::SetDefaultRoyalty => FunctionModifier::NonPayable,
+			Action::SetTokenRoyalty => FunctionModifier::NonPayable,
 		})?;
 
 		match selector {
 			Action::TokenURI => Self::token_uri(collection_id, handle),
+			Action::Name => Self::name(collection_id, handle),
+			Action::Symbol => Self::symbol(collection_id, handle),
 			Action::OwnerOf => Self::owner_of(collection_id, handle),
+			Action::BalanceOf => Self::balance_of(collection_id, handle),
+			Action::TokenOfOwnerByIndex => Self::token_of_owner_by_index(collection_id, handle),
+			Action::TotalSupply => Self::total_supply(collection_id, handle),
 			Action::TransferFrom => Self::transfer_from(collection_id, handle),
+			Action::SafeTransferFrom => Self::safe_transfer_from(collection_id, handle, false),
+			Action::SafeTransferFromWithData => {
+				Self::safe_transfer_from(collection_id, handle, true)
+			},
+			Action::BatchTransferFrom => Self::batch_transfer_from(collection_id, handle),
+			Action::Approve => Self::approve(collection_id, handle),
+			Action::GetApproved => Self::get_approved(collection_id, handle),
+			Action::SetApprovalForAll => Self::set_approval_for_all(collection_id, handle),
+			Action::IsApprovedForAll => Self::is_approved_for_all(collection_id, handle),
+			Action::Owner => Self::owner(collection_id, handle),
+			Action::TransferOwnership => Self::transfer_ownership(collection_id, handle),
+			Action::RenounceOwnership => Self::renounce_ownership(collection_id, handle),
+			Action::SetBaseURI => Self::set_base_uri(collection_id, handle),
+			Action::SetTokenURI => Self::set_token_uri(collection_id, handle),
+			Action::Mint => Self::mint(collection_id, handle),
+			Action::Burn => Self::burn(collection_id, handle),
+			Action::FreezeMetadata => Self::freeze_metadata(collection_id, handle),
+			Action::DestroyCollection => Self::destroy_collection(collection_id, handle),
+			Action::MakeSoulbound => Self::make_soulbound(collection_id, handle),
+			Action::Locked => Self::locked(collection_id, handle),
+			Action::IsLocked => Self::is_locked(collection_id, handle),
+			Action::Pause => Self::set_paused(collection_id, handle, true),
+			Action::Unpause => Self::set_paused(collection_id, handle, false),
+			Action::Paused => Self::paused(collection_id, handle),
+			Action::SetUser => Self::set_user(collection_id, handle),
+			Action::UserOf => Self::user_of(collection_id, handle),
+			Action::UserExpires => Self::user_expires(collection_id, handle),
+			Action::HasRole => Self::has_role(collection_id, handle),
+			Action::GetRoleAdmin => Self::get_role_admin(handle),
+			Action::GrantRole => Self::update_role(collection_id, handle, true),
+			Action::RevokeRole => Self::update_role(collection_id, handle, false),
+			Action::RenounceRole => Self::renounce_role(collection_id, handle),
+			Action::SupportsInterface => Self::supports_interface(handle),
+			Action::RoyaltyInfo => Self::royalty_info(collection_id, handle),
+			Action::SetDefaultRoyalty => Self::set_default_royalty(collection_id, handle),
+			Action::SetTokenRoyalty => Self::set_token_royalty(collection_id, handle),
 		}
 	}
 }
 
-impl<AssetManager> Erc721Precompile<AssetManager>
+impl<H160ToAccountId, AccountIdToH160, AssetManager, GasWeightMapping, WeightInfo, IsContract>
+	Erc721Precompile<
+		H160ToAccountId,
+		AccountIdToH160,
+		AssetManager,
+		GasWeightMapping,
+		WeightInfo,
+		IsContract,
+	>
 where
-	AssetManager: pallet_living_assets_ownership::traits::Erc721,
+	H160ToAccountId: Convert<H160, <AssetManager as CollectionManager>::AccountId>,
+	AccountIdToH160: Convert<<AssetManager as CollectionManager>::AccountId, H160>,
+	AssetManager: Erc721 + CollectionManager,
+	GasWeightMapping: pallet_evm::GasWeightMapping,
+	WeightInfo: pallet_living_assets_ownership::WeightInfo,
+	IsContract: Contains<H160>,
 {
+	/// Charges the gas equivalent of `weight`, including its proof size.
+	fn record_weight(handle: &mut impl PrecompileHandle, weight: Weight) -> EvmResult {
+		handle.record_cost(GasWeightMapping::weight_to_gas(weight))?;
+		handle.record_external_cost(None, Some(weight.proof_size()))?;
+		Ok(())
+	}
+
+	/// Charges the cost of `log` and emits it.
+	fn record_log(handle: &mut impl PrecompileHandle, log: Log) -> EvmResult {
+		handle.record_cost(log.compute_cost()?)?;
+		log.record(handle)
+	}
+
 	fn owner_of(
 		collection_id: CollectionId,
 		handle: &mut impl PrecompileHandle,
@@ -65,10 +443,54 @@ where
 
 		let asset_id: U256 = input.read()?;
 
+		Self::record_weight(handle, WeightInfo::owner_of())?;
 		let owner = AssetManager::owner_of(collection_id, asset_id).map_err(|err| revert(err))?;
 		Ok(succeed(EvmDataWriter::new().write(Address(owner)).build()))
 	}
 
+	fn balance_of(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(1)?;
+
+		let owner: H160 = input.read::<Address>()?.into();
+
+		Self::record_weight(handle, WeightInfo::balance_of())?;
+		let balance = AssetManager::balance_of(collection_id, owner).map_err(|err| revert(err))?;
+		Ok(succeed(EvmDataWriter::new().write(U256::from(balance)).build()))
+	}
+
+	fn token_of_owner_by_index(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(2)?;
+
+		let owner: H160 = input.read::<Address>()?.into();
+		let index: U256 = input.read()?;
+		// no owner can have more than `u32::MAX` assets indexed
+		if index > U256::from(u32::MAX) {
+			return Err(revert("OwnerIndexOutOfBounds"));
+		}
+
+		Self::record_weight(handle, WeightInfo::token_of_owner_by_index())?;
+		let asset_id = AssetManager::token_of_owner_by_index(collection_id, owner, index.low_u32())
+			.map_err(|err| revert(err))?;
+		Ok(succeed(EvmDataWriter::new().write(asset_id).build()))
+	}
+
+	fn total_supply(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		Self::record_weight(handle, WeightInfo::total_supply())?;
+		let supply = AssetManager::total_supply(collection_id).map_err(|err| revert(err))?;
+		Ok(succeed(EvmDataWriter::new().write(U256::from(supply)).build()))
+	}
+
 	fn token_uri(
 		collection_id: CollectionId,
 		handle: &mut impl PrecompileHandle,
@@ -78,10 +500,39 @@ where
 
 		let asset_id: U256 = input.read()?;
 
+		Self::record_weight(handle, WeightInfo::token_uri(0))?;
 		let uri = AssetManager::token_uri(collection_id, asset_id).map_err(|err| revert(err))?;
+		// the length of the URI is only known once it has been read
+		Self::record_weight(
+			handle,
+			WeightInfo::token_uri(uri.len().saturated_into())
+				.saturating_sub(WeightInfo::token_uri(0)),
+		)?;
 		Ok(succeed(EvmDataWriter::new().write(Bytes(uri)).build()))
 	}
 
+	fn name(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		Self::record_weight(handle, WeightInfo::name())?;
+		let name: Vec<u8> = AssetManager::name(collection_id)
+			.ok_or_else(|| revert("CollectionDoesNotExist"))?
+			.into();
+		Ok(succeed(EvmDataWriter::new().write(Bytes(name)).build()))
+	}
+
+	fn symbol(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		Self::record_weight(handle, WeightInfo::symbol())?;
+		let symbol: Vec<u8> = AssetManager::symbol(collection_id)
+			.ok_or_else(|| revert("CollectionDoesNotExist"))?
+			.into();
+		Ok(succeed(EvmDataWriter::new().write(Bytes(symbol)).build()))
+	}
+
 	fn transfer_from(
 		collection_id: CollectionId,
 		handle: &mut impl PrecompileHandle,
@@ -92,24 +543,827 @@ where
 		let from: H160 = input.read::<Address>()?.into();
 		let to: H160 = input.read::<Address>()?.into();
 		let asset_id: U256 = input.read()?;
+
+		Self::transfer(collection_id, handle, from, to, asset_id)?;
+		Ok(succeed(vec![]))
+	}
+
+	/// Transfers the asset as `transferFrom` does and, if `to` is a contract, requires it to
+	/// accept the asset through `onERC721Received`.
+	fn safe_transfer_from(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+		with_data: bool,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(if with_data { 4 } else { 3 })?;
+		let from: H160 = input.read::<Address>()?.into();
+		let to: H160 = input.read::<Address>()?.into();
+		let asset_id: U256 = input.read()?;
+		let data: Vec<u8> = if with_data { input.read::<Bytes>()?.into() } else { Vec::new() };
+
+		Self::transfer(collection_id, handle, from, to, asset_id)?;
+		Self::check_on_erc721_received(handle, from, to, asset_id, data)?;
+		Ok(succeed(vec![]))
+	}
+
+	/// Transfers the asset and emits the `Transfer` log.
+	fn transfer(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+		from: H160,
+		to: H160,
+		asset_id: U256,
+	) -> EvmResult {
 		let mut asset_id_big_endian = [0u8; 32];
 		asset_id.to_big_endian(&mut asset_id_big_endian);
 
+		Self::record_weight(
+			handle,
+			WeightInfo::user_of().saturating_add(WeightInfo::transfer_from()),
+		)?;
+		let user = AssetManager::user_of(collection_id, asset_id).map_err(|err| revert(err))?;
 		AssetManager::transfer_from(handle.context().caller, collection_id, from, to, asset_id)
 			.map_err(|err| revert(err))?;
 
-		LogsBuilder::new(handle.context().address)
-			.log4(
+		Self::record_user_reset(handle, user, asset_id)?;
+		let log = LogsBuilder::new(handle.context().address).log4(
+			SELECTOR_LOG_TRANSFER_FROM,
+			from,
+			to,
+			H256::from_slice(asset_id_big_endian.as_slice()),
+			Vec::new(),
+		);
+		Self::record_log(handle, log)
+	}
+
+	/// Calls `onERC721Received` on `to` if it is a contract, reverting unless it returns its
+	/// selector. A revert reason given by `to` is passed on.
+	fn check_on_erc721_received(
+		handle: &mut impl PrecompileHandle,
+		from: H160,
+		to: H160,
+		asset_id: U256,
+		data: Vec<u8>,
+	) -> EvmResult {
+		handle.record_cost(IS_CONTRACT_GAS_COST)?;
+		if !IsContract::contains(&to) {
+			return Ok(());
+		}
+
+		let input = EvmDataWriter::new_with_selector(SELECTOR_ON_ERC721_RECEIVED)
+			.write(Address(handle.context().caller))
+			.write(Address(from))
+			.write(asset_id)
+			.write(Bytes(data))
+			.build();
+		let context =
+			Context { address: to, caller: handle.context().address, apparent_value: U256::zero() };
+		// the gas used by the subcall is charged to this call
+		let (reason, output) = handle.call(to, None, input, None, false, &context);
+
+		let accepted = match reason {
+			ExitReason::Succeed(_) => EvmDataReader::new(&output)
+				.read::<H256>()
+				.map(|word| word[..4] == SELECTOR_ON_ERC721_RECEIVED.to_be_bytes())
+				.unwrap_or(false),
+			ExitReason::Revert(_) if !output.is_empty() => {
+				return Err(PrecompileFailure::Revert { exit_status: ExitRevert::Reverted, output })
+			},
+			ExitReason::Fatal(exit_status) => return Err(PrecompileFailure::Fatal { exit_status }),
+			_ => false,
+		};
+		if !accepted {
+			return Err(revert("transfer to non ERC721Receiver implementer"));
+		}
+		Ok(())
+	}
+
+	fn batch_transfer_from(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(3)?;
+		let from: H160 = input.read::<Address>()?.into();
+		let to: Vec<Address> = input.read()?;
+		let asset_ids: Vec<U256> = input.read()?;
+		if to.len() != asset_ids.len() {
+			return Err(revert("arrays of different length"));
+		}
+
+		Self::record_weight(
+			handle,
+			WeightInfo::user_of()
+				.saturating_mul(to.len().saturated_into())
+				.saturating_add(WeightInfo::batch_transfer(to.len().saturated_into())),
+		)?;
+		let users = asset_ids
+			.iter()
+			.map(|asset_id| AssetManager::user_of(collection_id, *asset_id))
+			.collect::<Result<Vec<_>, _>>()
+			.map_err(|err| revert(err))?;
+		let transfers = to
+			.iter()
+			.zip(asset_ids.iter())
+			.map(|(to, asset_id)| (collection_id, to.0, *asset_id))
+			.collect();
+		AssetManager::batch_transfer_from(handle.context().caller, from, transfers)
+			.map_err(|err| revert(err))?;
+
+		// logs per asset, as if each of them had been transferred on its own
+		for ((Address(to), asset_id), user) in to.into_iter().zip(asset_ids).zip(users) {
+			Self::record_user_reset(handle, user, asset_id)?;
+			let mut asset_id_big_endian = [0u8; 32];
+			asset_id.to_big_endian(&mut asset_id_big_endian);
+			let log = LogsBuilder::new(handle.context().address).log4(
 				SELECTOR_LOG_TRANSFER_FROM,
 				from,
 				to,
 				H256::from_slice(asset_id_big_endian.as_slice()),
 				Vec::new(),
+			);
+			Self::record_log(handle, log)?;
+		}
+
+		Ok(succeed(vec![]))
+	}
+
+	fn mint(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(2)?;
+		let to: H160 = input.read::<Address>()?.into();
+		let asset_id: U256 = input.read()?;
+		let mut asset_id_big_endian = [0u8; 32];
+		asset_id.to_big_endian(&mut asset_id_big_endian);
+
+		Self::record_weight(handle, WeightInfo::mint())?;
+		let origin = H160ToAccountId::convert(handle.context().caller);
+		AssetManager::mint(origin, collection_id, to, asset_id).map_err(|err| revert(err))?;
+
+		let log = LogsBuilder::new(handle.context().address).log4(
+			SELECTOR_LOG_TRANSFER_FROM,
+			H160::zero(),
+			to,
+			H256::from_slice(asset_id_big_endian.as_slice()),
+			Vec::new(),
+		);
+		Self::record_log(handle, log)?;
+
+		Ok(succeed(vec![]))
+	}
+
+	fn burn(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(1)?;
+		let asset_id: U256 = input.read()?;
+		let mut asset_id_big_endian = [0u8; 32];
+		asset_id.to_big_endian(&mut asset_id_big_endian);
+
+		Self::record_weight(
+			handle,
+			WeightInfo::owner_of()
+				.saturating_add(WeightInfo::user_of())
+				.saturating_add(WeightInfo::burn()),
+		)?;
+		let owner = AssetManager::owner_of(collection_id, asset_id).map_err(|err| revert(err))?;
+		let user = AssetManager::user_of(collection_id, asset_id).map_err(|err| revert(err))?;
+		AssetManager::burn(handle.context().caller, collection_id, asset_id)
+			.map_err(|err| revert(err))?;
+
+		Self::record_user_reset(handle, user, asset_id)?;
+		let log = LogsBuilder::new(handle.context().address).log4(
+			SELECTOR_LOG_TRANSFER_FROM,
+			owner,
+			H160::zero(),
+			H256::from_slice(asset_id_big_endian.as_slice()),
+			Vec::new(),
+		);
+		Self::record_log(handle, log)?;
+
+		Ok(succeed(vec![]))
+	}
+
+	fn approve(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(2)?;
+		let to: H160 = input.read::<Address>()?.into();
+		let asset_id: U256 = input.read()?;
+		let mut asset_id_big_endian = [0u8; 32];
+		asset_id.to_big_endian(&mut asset_id_big_endian);
+
+		Self::record_weight(handle, WeightInfo::owner_of().saturating_add(WeightInfo::approve()))?;
+		let owner = AssetManager::owner_of(collection_id, asset_id).map_err(|err| revert(err))?;
+		AssetManager::approve(handle.context().caller, collection_id, to, asset_id)
+			.map_err(|err| revert(err))?;
+
+		let log = LogsBuilder::new(handle.context().address).log4(
+			SELECTOR_LOG_APPROVAL,
+			owner,
+			to,
+			H256::from_slice(asset_id_big_endian.as_slice()),
+			Vec::new(),
+		);
+		Self::record_log(handle, log)?;
+
+		Ok(succeed(vec![]))
+	}
+
+	fn get_approved(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(1)?;
+
+		let asset_id: U256 = input.read()?;
+
+		Self::record_weight(handle, WeightInfo::get_approved())?;
+		let approved =
+			AssetManager::get_approved(collection_id, asset_id).map_err(|err| revert(err))?;
+		Ok(succeed(EvmDataWriter::new().write(Address(approved)).build()))
+	}
+
+	fn set_approval_for_all(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(2)?;
+		let operator: H160 = input.read::<Address>()?.into();
+		let approved: bool = input.read()?;
+		let caller = handle.context().caller;
+
+		Self::record_weight(handle, WeightInfo::set_approval_for_all())?;
+		AssetManager::set_approval_for_all(caller, collection_id, operator, approved)
+			.map_err(|err| revert(err))?;
+
+		let log = LogsBuilder::new(handle.context().address).log3(
+			SELECTOR_LOG_APPROVAL_FOR_ALL,
+			caller,
+			operator,
+			EvmDataWriter::new().write(approved).build(),
+		);
+		Self::record_log(handle, log)?;
+
+		Ok(succeed(vec![]))
+	}
+
+	fn is_approved_for_all(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(2)?;
+		let owner: H160 = input.read::<Address>()?.into();
+		let operator: H160 = input.read::<Address>()?.into();
+
+		Self::record_weight(handle, WeightInfo::is_approved_for_all())?;
+		let is_approved = AssetManager::is_approved_for_all(collection_id, owner, operator)
+			.map_err(|err| revert(err))?;
+		Ok(succeed(EvmDataWriter::new().write(is_approved).build()))
+	}
+
+	fn owner(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		Self::record_weight(handle, WeightInfo::owner_of_collection())?;
+		let owner = AssetManager::owner_of_collection(collection_id)
+			.map(AccountIdToH160::convert)
+			.unwrap_or_default();
+		Ok(succeed(EvmDataWriter::new().write(Address(owner)).build()))
+	}
+
+	fn transfer_ownership(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(1)?;
+		let new_owner: H160 = input.read::<Address>()?.into();
+
+		// as stated by ERC-173, transferring to the zero address renounces the ownership
+		if new_owner == H160::zero() {
+			return Self::renounce_ownership(collection_id, handle);
+		}
+
+		Self::record_weight(handle, WeightInfo::transfer_collection_ownership())?;
+		let caller = handle.context().caller;
+		AssetManager::transfer_collection_ownership(
+			H160ToAccountId::convert(caller),
+			collection_id,
+			H160ToAccountId::convert(new_owner),
+		)
+		.map_err(|err| revert(err))?;
+
+		let log = LogsBuilder::new(handle.context().address).log3(
+			SELECTOR_LOG_OWNERSHIP_TRANSFERRED,
+			caller,
+			new_owner,
+			Vec::new(),
+		);
+		Self::record_log(handle, log)?;
+
+		Ok(succeed(vec![]))
+	}
+
+	fn renounce_ownership(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		Self::record_weight(handle, WeightInfo::renounce_collection_ownership())?;
+		let caller = handle.context().caller;
+		AssetManager::renounce_collection_ownership(
+			H160ToAccountId::convert(caller),
+			collection_id,
+		)
+		.map_err(|err| revert(err))?;
+
+		let log = LogsBuilder::new(handle.context().address).log3(
+			SELECTOR_LOG_OWNERSHIP_TRANSFERRED,
+			caller,
+			H160::zero(),
+			Vec::new(),
+		);
+		Self::record_log(handle, log)?;
+
+		Ok(succeed(vec![]))
+	}
+
+	fn set_base_uri(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(1)?;
+
+		let base_uri_bytes: Vec<u8> = input.read::<Bytes>()?.into();
+		Self::record_weight(
+			handle,
+			WeightInfo::set_base_uri(base_uri_bytes.len().saturated_into()),
+		)?;
+		let base_uri = base_uri_bytes.try_into().map_err(|_| revert("base_uri too long"))?;
+
+		AssetManager::set_base_uri(
+			H160ToAccountId::convert(handle.context().caller),
+			collection_id,
+			base_uri,
+		)
+		.map_err(|err| revert(err))?;
+
+		// ERC-4906: every token of the collection has new metadata
+		let log = LogsBuilder::new(handle.context().address).log1(
+			SELECTOR_LOG_BATCH_METADATA_UPDATE,
+			EvmDataWriter::new().write(U256::zero()).write(U256::MAX).build(),
+		);
+		Self::record_log(handle, log)?;
+
+		Ok(succeed(vec![]))
+	}
+
+	fn set_token_uri(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(2)?;
+
+		let asset_id = input.read::<U256>()?;
+		let token_uri_bytes: Vec<u8> = input.read::<Bytes>()?.into();
+		Self::record_weight(
+			handle,
+			WeightInfo::set_token_uri(token_uri_bytes.len().saturated_into()),
+		)?;
+		let token_uri = token_uri_bytes.try_into().map_err(|_| revert("token_uri too long"))?;
+
+		AssetManager::set_token_uri(
+			H160ToAccountId::convert(handle.context().caller),
+			collection_id,
+			asset_id,
+			token_uri,
+		)
+		.map_err(|err| revert(err))?;
+
+		let log = LogsBuilder::new(handle.context().address)
+			.log1(SELECTOR_LOG_METADATA_UPDATE, EvmDataWriter::new().write(asset_id).build());
+		Self::record_log(handle, log)?;
+
+		Ok(succeed(vec![]))
+	}
+
+	fn freeze_metadata(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		Self::record_weight(
+			handle,
+			WeightInfo::freeze_metadata().saturating_add(WeightInfo::base_uri(0)),
+		)?;
+		AssetManager::freeze_metadata(
+			H160ToAccountId::convert(handle.context().caller),
+			collection_id,
+		)
+		.map_err(|err| revert(err))?;
+		let base_uri: Vec<u8> =
+			AssetManager::base_uri(collection_id).map(Into::into).unwrap_or_default();
+		// the length of the URI is only known once it has been read
+		Self::record_weight(
+			handle,
+			WeightInfo::base_uri(base_uri.len().saturated_into())
+				.saturating_sub(WeightInfo::base_uri(0)),
+		)?;
+
+		let mut all_assets_big_endian = [0u8; 32];
+		ALL_ASSETS_TOKEN_ID.to_big_endian(&mut all_assets_big_endian);
+		let log = LogsBuilder::new(handle.context().address).log2(
+			SELECTOR_LOG_PERMANENT_URI,
+			H256::from_slice(all_assets_big_endian.as_slice()),
+			EvmDataWriter::new().write(Bytes(base_uri)).build(),
+		);
+		Self::record_log(handle, log)?;
+
+		Ok(succeed(vec![]))
+	}
+
+	fn destroy_collection(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		Self::record_weight(handle, WeightInfo::destroy_collection())?;
+		let caller = handle.context().caller;
+		AssetManager::destroy_collection(H160ToAccountId::convert(caller), collection_id)
+			.map_err(|err| revert(err))?;
+
+		// the collection is left without owner
+		let log = LogsBuilder::new(handle.context().address).log3(
+			SELECTOR_LOG_OWNERSHIP_TRANSFERRED,
+			caller,
+			H160::zero(),
+			Vec::new(),
+		);
+		Self::record_log(handle, log)?;
+
+		Ok(succeed(vec![]))
+	}
+
+	fn make_soulbound(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		Self::record_weight(handle, WeightInfo::make_soulbound())?;
+		AssetManager::make_soulbound(
+			H160ToAccountId::convert(handle.context().caller),
+			collection_id,
+		)
+		.map_err(|err| revert(err))?;
+
+		// ERC-5192, once for every asset of the collection
+		let log = LogsBuilder::new(handle.context().address)
+			.log1(SELECTOR_LOG_LOCKED, EvmDataWriter::new().write(ALL_ASSETS_TOKEN_ID).build());
+		Self::record_log(handle, log)?;
+
+		Ok(succeed(vec![]))
+	}
+
+	fn locked(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(1)?;
+
+		let asset_id: U256 = input.read()?;
+
+		Self::record_weight(handle, WeightInfo::locked())?;
+		let locked = AssetManager::locked(collection_id, asset_id).map_err(|err| revert(err))?;
+		Ok(succeed(EvmDataWriter::new().write(locked).build()))
+	}
+
+	fn is_locked(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(1)?;
+
+		let asset_id: U256 = input.read()?;
+
+		Self::record_weight(handle, WeightInfo::is_locked())?;
+		let is_locked =
+			AssetManager::is_locked(collection_id, asset_id).map_err(|err| revert(err))?;
+		Ok(succeed(EvmDataWriter::new().write(is_locked).build()))
+	}
+
+	/// Pauses the collection if `paused`, otherwise unpauses it.
+	fn set_paused(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+		paused: bool,
+	) -> EvmResult<PrecompileOutput> {
+		let caller = handle.context().caller;
+		let owner = H160ToAccountId::convert(caller);
+		let (selector, result) = if paused {
+			Self::record_weight(handle, WeightInfo::pause())?;
+			(SELECTOR_LOG_PAUSED, AssetManager::pause(owner, collection_id))
+		} else {
+			Self::record_weight(handle, WeightInfo::unpause())?;
+			(SELECTOR_LOG_UNPAUSED, AssetManager::unpause(owner, collection_id))
+		};
+		result.map_err(|err| revert(err))?;
+
+		let log = LogsBuilder::new(handle.context().address)
+			.log1(selector, EvmDataWriter::new().write(Address(caller)).build());
+		Self::record_log(handle, log)?;
+
+		Ok(succeed(vec![]))
+	}
+
+	fn paused(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		Self::record_weight(handle, WeightInfo::paused())?;
+		let paused = AssetManager::paused(collection_id).map_err(|err| revert(err))?;
+		Ok(succeed(EvmDataWriter::new().write(paused).build()))
+	}
+
+	fn set_user(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(3)?;
+		let asset_id: U256 = input.read()?;
+		let user: H160 = input.read::<Address>()?.into();
+		let expires: u64 = input.read()?;
+
+		Self::record_weight(handle, WeightInfo::set_user())?;
+		AssetManager::set_user(handle.context().caller, collection_id, asset_id, user, expires)
+			.map_err(|err| revert(err))?;
+
+		Self::record_update_user_log(handle, asset_id, user, expires)?;
+		Ok(succeed(vec![]))
+	}
+
+	fn user_of(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(1)?;
+		let asset_id: U256 = input.read()?;
+
+		Self::record_weight(handle, WeightInfo::user_of())?;
+		let user = AssetManager::user_of(collection_id, asset_id).map_err(|err| revert(err))?;
+		Ok(succeed(EvmDataWriter::new().write(Address(user)).build()))
+	}
+
+	fn user_expires(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(1)?;
+		let asset_id: U256 = input.read()?;
+
+		Self::record_weight(handle, WeightInfo::user_expires())?;
+		let expires =
+			AssetManager::user_expires(collection_id, asset_id).map_err(|err| revert(err))?;
+		Ok(succeed(EvmDataWriter::new().write(U256::from(expires)).build()))
+	}
+
+	/// Emits the ERC-4907 `UpdateUser` log.
+	fn record_update_user_log(
+		handle: &mut impl PrecompileHandle,
+		asset_id: U256,
+		user: H160,
+		expires: u64,
+	) -> EvmResult {
+		let mut asset_id_big_endian = [0u8; 32];
+		asset_id.to_big_endian(&mut asset_id_big_endian);
+		let log = LogsBuilder::new(handle.context().address).log3(
+			SELECTOR_LOG_UPDATE_USER,
+			H256::from_slice(asset_id_big_endian.as_slice()),
+			user,
+			EvmDataWriter::new().write(U256::from(expires)).build(),
+		);
+		Self::record_log(handle, log)
+	}
+
+	/// Reports the reset of the user of an asset that has just been transferred or burned, as
+	/// the pallet does, provided it had a user that had not expired.
+	fn record_user_reset(
+		handle: &mut impl PrecompileHandle,
+		user: H160,
+		asset_id: U256,
+	) -> EvmResult {
+		if user == H160::zero() {
+			return Ok(());
+		}
+		Self::record_update_user_log(handle, asset_id, H160::zero(), 0)
+	}
+
+	fn has_role(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(2)?;
+		let role: H256 = input.read()?;
+		let account: H160 = input.read::<Address>()?.into();
+
+		Self::record_weight(handle, WeightInfo::has_role())?;
+		// nobody holds a role unknown to the collection
+		let has_role = match collection_role(role) {
+			Some(role) => {
+				AssetManager::has_role(collection_id, role, H160ToAccountId::convert(account))
+					.map_err(|err| revert(err))?
+			},
+			None => false,
+		};
+		Ok(succeed(EvmDataWriter::new().write(has_role).build()))
+	}
+
+	fn get_role_admin(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(1)?;
+		let _role: H256 = input.read()?;
+
+		Ok(succeed(EvmDataWriter::new().write(H256(ROLE_ADMIN)).build()))
+	}
+
+	/// Grants the role if `grant`, otherwise revokes it. As in OpenZeppelin AccessControl, the
+	/// log is only emitted if the role of the account changes.
+	fn update_role(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+		grant: bool,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(2)?;
+		let role: H256 = input.read()?;
+		let account: H160 = input.read::<Address>()?.into();
+		let collection_role = collection_role(role).ok_or_else(|| revert("unknown role"))?;
+
+		let caller = handle.context().caller;
+		let origin = H160ToAccountId::convert(caller);
+		let target = H160ToAccountId::convert(account);
+		let (selector, updated) = if grant {
+			Self::record_weight(handle, WeightInfo::grant_role())?;
+			(
+				SELECTOR_LOG_ROLE_GRANTED,
+				AssetManager::grant_role(origin, collection_id, collection_role, target),
+			)
+		} else {
+			Self::record_weight(handle, WeightInfo::revoke_role())?;
+			(
+				SELECTOR_LOG_ROLE_REVOKED,
+				AssetManager::revoke_role(origin, collection_id, collection_role, target),
 			)
-			.record(handle)?;
+		};
+
+		if updated.map_err(|err| revert(err))? {
+			let log = LogsBuilder::new(handle.context().address).log4(
+				selector,
+				role,
+				account,
+				caller,
+				Vec::new(),
+			);
+			Self::record_log(handle, log)?;
+		}
+
+		Ok(succeed(vec![]))
+	}
+
+	fn renounce_role(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(2)?;
+		let role: H256 = input.read()?;
+		let caller_confirmation: H160 = input.read::<Address>()?.into();
+		let collection_role = collection_role(role).ok_or_else(|| revert("unknown role"))?;
+
+		let caller = handle.context().caller;
+		if caller_confirmation != caller {
+			return Err(revert("can only renounce roles for self"));
+		}
+
+		Self::record_weight(handle, WeightInfo::renounce_role())?;
+		let renounced = AssetManager::renounce_role(
+			H160ToAccountId::convert(caller),
+			collection_id,
+			collection_role,
+		)
+		.map_err(|err| revert(err))?;
+
+		if renounced {
+			let log = LogsBuilder::new(handle.context().address).log4(
+				SELECTOR_LOG_ROLE_REVOKED,
+				role,
+				caller,
+				caller,
+				Vec::new(),
+			);
+			Self::record_log(handle, log)?;
+		}
+
+		Ok(succeed(vec![]))
+	}
+
+	fn royalty_info(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(2)?;
+
+		let asset_id: U256 = input.read()?;
+		let sale_price: U256 = input.read()?;
+
+		Self::record_weight(handle, WeightInfo::royalty_info())?;
+		let (receiver, royalty) = AssetManager::royalty_info(collection_id, asset_id, sale_price)
+			.map_err(|err| revert(err))?;
+		Ok(succeed(EvmDataWriter::new().write(Address(receiver)).write(royalty).build()))
+	}
+
+	fn set_default_royalty(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(2)?;
+
+		let receiver: H160 = input.read::<Address>()?.into();
+		let basis_points = Self::read_basis_points(input.read()?)?;
+
+		Self::record_weight(handle, WeightInfo::set_default_royalty())?;
+		AssetManager::set_default_royalty(
+			H160ToAccountId::convert(handle.context().caller),
+			collection_id,
+			receiver,
+			basis_points,
+		)
+		.map_err(|err| revert(err))?;
+
+		Ok(succeed(vec![]))
+	}
+
+	fn set_token_royalty(
+		collection_id: CollectionId,
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(3)?;
+
+		let asset_id: U256 = input.read()?;
+		let receiver: H160 = input.read::<Address>()?.into();
+		let basis_points = Self::read_basis_points(input.read()?)?;
+
+		Self::record_weight(handle, WeightInfo::set_token_royalty())?;
+		AssetManager::set_token_royalty(
+			H160ToAccountId::convert(handle.context().caller),
+			collection_id,
+			asset_id,
+			receiver,
+			basis_points,
+		)
+		.map_err(|err| revert(err))?;
 
 		Ok(succeed(vec![]))
 	}
+
+	/// Converts the `uint96` fee numerator of the royalty setters into basis points.
+	fn read_basis_points(fee_numerator: U256) -> EvmResult<u16> {
+		if fee_numerator > U256::from(ROYALTY_DENOMINATOR) {
+			return Err(revert("RoyaltyTooHigh"));
+		}
+		Ok(fee_numerator.low_u32() as u16)
+	}
+
+	fn supports_interface(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(1)?;
+
+		// `bytes4` is left aligned in its 32 bytes word
+		let word: H256 = input.read()?;
+		let interface_id = u32::from_be_bytes([word[0], word[1], word[2], word[3]]);
+
+		let supported = SUPPORTED_INTERFACES.contains(&interface_id);
+		Ok(succeed(EvmDataWriter::new().write(supported).build()))
+	}
 }
 
 #[cfg(test)]
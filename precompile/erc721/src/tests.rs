@@ -2,17 +2,89 @@ use core::str::FromStr;
 
 use super::*;
 use frame_support::assert_ok;
-use pallet_living_assets_ownership::CollectionId;
+use pallet_living_assets_ownership::{CollectionId, MintingMode};
 use precompile_utils::testing::create_mock_handle_from_input;
 use sp_core::{H160, U256};
+use sp_runtime::traits::Identity;
 
 type AccountId = H160;
 
+/// Charges a unit of gas per unit of `ref_time`.
+pub struct MockGasWeightMapping;
+impl pallet_evm::GasWeightMapping for MockGasWeightMapping {
+	fn gas_to_weight(gas: u64, _without_base_weight: bool) -> Weight {
+		Weight::from_parts(gas, 0)
+	}
+
+	fn weight_to_gas(weight: Weight) -> u64 {
+		weight.ref_time()
+	}
+}
+
+/// Address of the only contract known to the mocks.
+pub const CONTRACT: H160 = H160::repeat_byte(0xDD);
+
+/// Only asset with a user in the mocks, which is [`USER`].
+pub const RENTED_ASSET: u64 = 4907;
+
+/// User of [`RENTED_ASSET`].
+pub const USER: H160 = H160::repeat_byte(0xEE);
+
+/// Total supply of every collection in the mocks.
+pub const TOTAL_SUPPLY: u64 = 21;
+
+/// Tells [`CONTRACT`] as the only address with code.
+pub struct MockIsContract;
+impl frame_support::traits::Contains<H160> for MockIsContract {
+	fn contains(address: &H160) -> bool {
+		*address == CONTRACT
+	}
+}
+
 #[test]
 fn check_selectors() {
 	assert_eq!(Action::OwnerOf as u32, 0x6352211E);
+	assert_eq!(Action::BalanceOf as u32, 0x70a08231);
+	assert_eq!(Action::TokenOfOwnerByIndex as u32, 0x2f745c59);
+	assert_eq!(Action::TotalSupply as u32, 0x18160ddd);
 	assert_eq!(Action::TokenURI as u32, 0xC87B56DD);
+	assert_eq!(Action::Name as u32, 0x06fdde03);
+	assert_eq!(Action::Symbol as u32, 0x95d89b41);
 	assert_eq!(Action::TransferFrom as u32, 0x23b872dd);
+	assert_eq!(Action::SafeTransferFrom as u32, 0x42842e0e);
+	assert_eq!(Action::SafeTransferFromWithData as u32, 0xb88d4fde);
+	assert_eq!(Action::BatchTransferFrom as u32, 0x4885b254);
+	assert_eq!(Action::Approve as u32, 0x095ea7b3);
+	assert_eq!(Action::GetApproved as u32, 0x081812fc);
+	assert_eq!(Action::SetApprovalForAll as u32, 0xa22cb465);
+	assert_eq!(Action::IsApprovedForAll as u32, 0xe985e9c5);
+	assert_eq!(Action::Owner as u32, 0x8da5cb5b);
+	assert_eq!(Action::TransferOwnership as u32, 0xf2fde38b);
+	assert_eq!(Action::RenounceOwnership as u32, 0x715018a6);
+	assert_eq!(Action::SetBaseURI as u32, 0x55f804b3);
+	assert_eq!(Action::SetTokenURI as u32, 0x162094c4);
+	assert_eq!(Action::Mint as u32, 0x40c10f19);
+	assert_eq!(Action::Burn as u32, 0x42966c68);
+	assert_eq!(Action::FreezeMetadata as u32, 0xd111515d);
+	assert_eq!(Action::DestroyCollection as u32, 0x55227b86);
+	assert_eq!(Action::MakeSoulbound as u32, 0xa1c6bf38);
+	assert_eq!(Action::Locked as u32, 0xb45a3c0e);
+	assert_eq!(Action::IsLocked as u32, 0xf6aacfb1);
+	assert_eq!(Action::Pause as u32, 0x8456cb59);
+	assert_eq!(Action::Unpause as u32, 0x3f4ba83a);
+	assert_eq!(Action::Paused as u32, 0x5c975abb);
+	assert_eq!(Action::SetUser as u32, 0xe030565e);
+	assert_eq!(Action::UserOf as u32, 0xc2f1f14a);
+	assert_eq!(Action::UserExpires as u32, 0x8fc88c48);
+	assert_eq!(Action::HasRole as u32, 0x91d14854);
+	assert_eq!(Action::GetRoleAdmin as u32, 0x248a9ca3);
+	assert_eq!(Action::GrantRole as u32, 0x2f2ff15d);
+	assert_eq!(Action::RevokeRole as u32, 0xd547741f);
+	assert_eq!(Action::RenounceRole as u32, 0x36568abe);
+	assert_eq!(Action::SupportsInterface as u32, 0x01ffc9a7);
+	assert_eq!(Action::RoyaltyInfo as u32, 0x2a55205a);
+	assert_eq!(Action::SetDefaultRoyalty as u32, 0x04634d8d);
+	assert_eq!(Action::SetTokenRoyalty as u32, 0x5944c753);
 }
 
 #[test]
@@ -250,7 +322,244 @@ mod transfer_from {
 		);
 		assert_eq!(logs[0].data, Vec::<u8>::new());
 	}
+
+	#[test]
+	fn batch_transfer_from_should_generate_a_log_per_asset() {
+		impl_precompile_mock_simple!(Mock, Ok(H160::zero()), Ok(vec![]), Ok(()));
+
+		let input = EvmDataWriter::new_with_selector(Action::BatchTransferFrom)
+			.write(Address(H160::repeat_byte(0xAA)))
+			.write(vec![Address(H160::repeat_byte(0xBB)), Address(H160::repeat_byte(0xCC))])
+			.write(vec![U256::from(4), U256::from(5)])
+			.build();
+		let mut handle = create_mock_handle(input, 0, 0, H160::repeat_byte(0xAA));
+		handle.code_address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+		assert_ok!(Mock::execute(&mut handle));
+
+		let logs = handle.logs;
+		assert_eq!(logs.len(), 2);
+		for (log, (to, asset_id)) in
+			logs.iter().zip([(H160::repeat_byte(0xBB), 4), (H160::repeat_byte(0xCC), 5)])
+		{
+			assert_eq!(log.topics[0], SELECTOR_LOG_TRANSFER_FROM.into());
+			assert_eq!(log.topics[1], H160::repeat_byte(0xAA).into());
+			assert_eq!(log.topics[2], to.into());
+			assert_eq!(log.topics[3], H256::from_low_u64_be(asset_id));
+		}
+	}
+
+	#[test]
+	fn failing_batch_transfer_from_should_not_generate_logs() {
+		impl_precompile_mock_simple!(Mock, Ok(H160::zero()), Ok(vec![]), Err("this is an error"));
+
+		let input = EvmDataWriter::new_with_selector(Action::BatchTransferFrom)
+			.write(Address(H160::repeat_byte(0xAA)))
+			.write(vec![Address(H160::repeat_byte(0xBB))])
+			.write(vec![U256::from(4)])
+			.build();
+		let mut handle = create_mock_handle(input, 0, 0, H160::repeat_byte(0xAA));
+		handle.code_address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+		assert_eq!(Mock::execute(&mut handle).unwrap_err(), revert("this is an error"));
+		assert!(handle.logs.is_empty());
+	}
+
+	#[test]
+	fn batch_transfer_from_with_arrays_of_different_length_should_fail() {
+		impl_precompile_mock_simple!(Mock, Ok(H160::zero()), Ok(vec![]), Ok(()));
+
+		let input = EvmDataWriter::new_with_selector(Action::BatchTransferFrom)
+			.write(Address(H160::repeat_byte(0xAA)))
+			.write(vec![Address(H160::repeat_byte(0xBB))])
+			.write(vec![U256::from(4), U256::from(5)])
+			.build();
+		let mut handle = create_mock_handle(input, 0, 0, H160::repeat_byte(0xAA));
+		handle.code_address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+		assert_eq!(Mock::execute(&mut handle).unwrap_err(), revert("arrays of different length"));
+	}
+
+	#[test]
+	fn burn_should_generate_transfer_log_to_zero_address() {
+		impl_precompile_mock_simple!(
+			Mock,
+			Ok(H160::from_str("aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa").unwrap()),
+			Ok(vec![]),
+			Ok(())
+		);
+
+		let input = EvmDataWriter::new_with_selector(Action::Burn).write(U256::from(4)).build();
+		let mut handle = create_mock_handle(input, 0, 0, H160::repeat_byte(0xAA));
+		handle.code_address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+		assert_ok!(Mock::execute(&mut handle));
+
+		let logs = handle.logs;
+		assert_eq!(logs.len(), 1);
+		assert_eq!(logs[0].topics.len(), 4);
+		assert_eq!(logs[0].topics[0], SELECTOR_LOG_TRANSFER_FROM.into());
+		assert_eq!(
+			hex::encode(logs[0].topics[1]),
+			"000000000000000000000000aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa"
+		);
+		assert_eq!(logs[0].topics[2], H256::zero());
+		assert_eq!(
+			hex::encode(logs[0].topics[3]),
+			"0000000000000000000000000000000000000000000000000000000000000004"
+		);
+		assert_eq!(logs[0].data, Vec::<u8>::new());
+	}
+
+	#[test]
+	fn burn_of_unexistent_asset_should_fail() {
+		impl_precompile_mock_simple!(Mock, Err("this is an error"), Ok(vec![]), Ok(()));
+
+		let input = EvmDataWriter::new_with_selector(Action::Burn).write(U256::from(4)).build();
+		let mut handle = create_mock_handle(input, 0, 0, H160::repeat_byte(0xAA));
+		handle.code_address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+		assert_eq!(Mock::execute(&mut handle).unwrap_err(), revert("this is an error"));
+		assert!(handle.logs.is_empty());
+	}
+}
+
+mod safe_transfer_from {
+	use super::*;
+	use precompile_utils::testing::{create_mock_handle, MockHandle, SubcallOutput};
+
+	fn safe_transfer_from_handle(to: H160, data: Option<Vec<u8>>) -> MockHandle {
+		let input = match data {
+			Some(data) => EvmDataWriter::new_with_selector(Action::SafeTransferFromWithData)
+				.write(Address(H160::repeat_byte(0xAA)))
+				.write(Address(to))
+				.write(U256::from(4))
+				.write(Bytes(data))
+				.build(),
+			None => EvmDataWriter::new_with_selector(Action::SafeTransferFrom)
+				.write(Address(H160::repeat_byte(0xAA)))
+				.write(Address(to))
+				.write(U256::from(4))
+				.build(),
+		};
+		let mut handle = create_mock_handle(input, 0, 0, H160::repeat_byte(0xAA));
+		handle.code_address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+		handle
+	}
+
+	fn on_erc721_received_output(selector: u32) -> Vec<u8> {
+		let mut word = H256::zero();
+		word[..4].copy_from_slice(&selector.to_be_bytes());
+		EvmDataWriter::new().write(word).build()
+	}
+
+	#[test]
+	fn check_on_erc721_received_selector() {
+		assert_eq!(
+			SELECTOR_ON_ERC721_RECEIVED.to_be_bytes(),
+			keccak256!("onERC721Received(address,address,uint256,bytes)")[..4]
+		);
+	}
+
+	#[test]
+	fn safe_transfer_to_an_account_without_code_should_not_call_it() {
+		impl_precompile_mock_simple!(Mock, Ok(H160::zero()), Ok(vec![]), Ok(()));
+
+		// no subcall handle is registered, so a call to the receiver would panic
+		let mut handle = safe_transfer_from_handle(H160::repeat_byte(0xBB), None);
+		assert_ok!(Mock::execute(&mut handle));
+		assert_eq!(handle.logs.len(), 1);
+		assert_eq!(handle.logs[0].topics[0], SELECTOR_LOG_TRANSFER_FROM.into());
+	}
+
+	#[test]
+	fn safe_transfer_to_an_accepting_contract_should_work() {
+		impl_precompile_mock_simple!(Mock, Ok(H160::zero()), Ok(vec![]), Ok(()));
+
+		let mut handle = safe_transfer_from_handle(CONTRACT, None).with_subcall_handle(|subcall| {
+			assert_eq!(subcall.address, CONTRACT);
+			assert_eq!(subcall.context.address, CONTRACT);
+			assert_eq!(subcall.context.caller, H160::zero());
+			assert_eq!(subcall.context.apparent_value, U256::zero());
+			assert!(subcall.transfer.is_none());
+			assert!(!subcall.is_static);
+			assert_eq!(
+				subcall.input,
+				EvmDataWriter::new_with_selector(SELECTOR_ON_ERC721_RECEIVED)
+					.write(Address(H160::repeat_byte(0xAA)))
+					.write(Address(H160::repeat_byte(0xAA)))
+					.write(U256::from(4))
+					.write(Bytes(vec![]))
+					.build()
+			);
+			SubcallOutput::succeed(on_erc721_received_output(SELECTOR_ON_ERC721_RECEIVED))
+		});
+		assert_ok!(Mock::execute(&mut handle));
+		assert_eq!(handle.logs.len(), 1);
+	}
+
+	#[test]
+	fn safe_transfer_with_data_should_pass_it_to_the_receiver() {
+		impl_precompile_mock_simple!(Mock, Ok(H160::zero()), Ok(vec![]), Ok(()));
+
+		let mut handle = safe_transfer_from_handle(CONTRACT, Some(vec![0xCA, 0xFE]))
+			.with_subcall_handle(|subcall| {
+				let mut input = EvmDataReader::new_skip_selector(&subcall.input).unwrap();
+				let _operator: Address = input.read().unwrap();
+				let _from: Address = input.read().unwrap();
+				let _asset_id: U256 = input.read().unwrap();
+				let data: Bytes = input.read().unwrap();
+				assert_eq!(Vec::<u8>::from(data), vec![0xCA, 0xFE]);
+				SubcallOutput::succeed(on_erc721_received_output(SELECTOR_ON_ERC721_RECEIVED))
+			});
+		assert_ok!(Mock::execute(&mut handle));
+	}
+
+	#[test]
+	fn safe_transfer_to_a_contract_returning_another_value_should_fail() {
+		impl_precompile_mock_simple!(Mock, Ok(H160::zero()), Ok(vec![]), Ok(()));
+
+		let mut handle = safe_transfer_from_handle(CONTRACT, None)
+			.with_subcall_handle(|_| SubcallOutput::succeed(on_erc721_received_output(0x12345678)));
+		assert_eq!(
+			Mock::execute(&mut handle).unwrap_err(),
+			revert("transfer to non ERC721Receiver implementer")
+		);
+
+		let mut handle = safe_transfer_from_handle(CONTRACT, None)
+			.with_subcall_handle(|_| SubcallOutput::succeed(vec![]));
+		assert_eq!(
+			Mock::execute(&mut handle).unwrap_err(),
+			revert("transfer to non ERC721Receiver implementer")
+		);
+	}
+
+	#[test]
+	fn safe_transfer_to_a_reverting_contract_should_pass_on_the_reason() {
+		impl_precompile_mock_simple!(Mock, Ok(H160::zero()), Ok(vec![]), Ok(()));
+
+		let mut handle = safe_transfer_from_handle(CONTRACT, None)
+			.with_subcall_handle(|_| SubcallOutput::revert(b"not today".to_vec()));
+		assert_eq!(
+			Mock::execute(&mut handle).unwrap_err(),
+			PrecompileFailure::Revert {
+				exit_status: ExitRevert::Reverted,
+				output: b"not today".to_vec()
+			}
+		);
+
+		let mut handle = safe_transfer_from_handle(CONTRACT, None)
+			.with_subcall_handle(|_| SubcallOutput::revert(vec![]));
+		assert_eq!(
+			Mock::execute(&mut handle).unwrap_err(),
+			revert("transfer to non ERC721Receiver implementer")
+		);
+	}
+
+	#[test]
+	fn failing_safe_transfer_should_not_call_the_receiver() {
+		impl_precompile_mock_simple!(Mock, Ok(H160::zero()), Ok(vec![]), Err("this is an error"));
+
+		let mut handle = safe_transfer_from_handle(CONTRACT, None);
+		assert_eq!(Mock::execute(&mut handle).unwrap_err(), revert("this is an error"));
+	}
 }
+
 #[test]
 fn token_uri_should_return_a_string() {
 	impl_precompile_mock_simple!(
@@ -275,60 +584,1677 @@ fn token_uri_should_return_a_string() {
 	);
 }
 
-mod helpers {
-	/// Macro to define a precompile mock with custom closures for testing.
-	///
-	/// This macro creates mock implementations of the `Erc721` trait,
-	/// allowing you to test how your code interacts with the precompiled contracts.
-	/// You can define a custom closure for the owner_of function.
-	///
-	/// # Arguments
-	///
-	/// * `$name`: An identifier to name the precompile mock type.
-	/// * `$owner_of_collection`: A closure that takes `collection_id` and `asset_id` and returns a `Result<AccountId, &'static str>`.
-	///
-	/// # Example
-	///
-	/// ```
-	/// impl_precompile_mock!(
-	///     MyMock,
-	///     |collection_id, asset_id| { Ok(AccountId::default()) }
-	/// );
-	/// ```
-	#[macro_export]
-	macro_rules! impl_precompile_mock {
-		($name:ident, $owner_of_collection:expr, $token_uri:expr, $transfer_from:expr) => {
-			struct Erc721Mock;
+mod approvals {
+	use super::*;
+	use precompile_utils::testing::create_mock_handle;
 
-			impl pallet_living_assets_ownership::traits::Erc721 for Erc721Mock {
-				type Error = &'static str;
+	#[test]
+	fn check_log_selectors() {
+		assert_eq!(
+			hex::encode(SELECTOR_LOG_APPROVAL),
+			"8c5be1e5ebec7d5bd14f71427d1e84f3dd0314c0f7b2291e5b200ac8c7c3b925"
+		);
+		assert_eq!(
+			hex::encode(SELECTOR_LOG_APPROVAL_FOR_ALL),
+			"17307eab39ab6107e8899845ad3d59bd9653f200f220920489ca2b5937696c31"
+		);
+	}
 
-				fn owner_of(
-					collection_id: CollectionId,
-					asset_id: U256,
-				) -> Result<AccountId, Self::Error> {
-					($owner_of_collection)(collection_id, asset_id)
-				}
+	#[test]
+	fn approve_should_generate_log() {
+		impl_precompile_mock_simple!(
+			Mock,
+			Ok(H160::from_str("aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa").unwrap()),
+			Ok(vec![]),
+			Ok(())
+		);
 
-				fn token_uri(
-					collectio_id: CollectionId,
-					asset_id: U256,
-				) -> Result<Vec<u8>, Self::Error> {
-					($token_uri)(collectio_id, asset_id)
-				}
+		let input_data = EvmDataWriter::new_with_selector(Action::Approve)
+			.write(Address(H160::repeat_byte(0xBB)))
+			.write(U256::from(4))
+			.build();
 
-				fn transfer_from(
-					origin: AccountId,
-					collection_id: CollectionId,
-					from: AccountId,
-					to: AccountId,
-					asset_id: U256,
-				) -> Result<(), Self::Error> {
-					($transfer_from)(origin, collection_id, from, to, asset_id)
+		let mut handle = create_mock_handle(input_data, 0, 0, H160::repeat_byte(0xAA));
+		handle.code_address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+		assert_ok!(Mock::execute(&mut handle));
+
+		let logs = handle.logs;
+		assert_eq!(logs.len(), 1);
+		assert_eq!(logs[0].topics.len(), 4);
+		assert_eq!(logs[0].topics[0], SELECTOR_LOG_APPROVAL.into());
+		assert_eq!(
+			hex::encode(logs[0].topics[1]),
+			"000000000000000000000000aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa"
+		);
+		assert_eq!(
+			hex::encode(logs[0].topics[2]),
+			"000000000000000000000000bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb"
+		);
+		assert_eq!(
+			hex::encode(logs[0].topics[3]),
+			"0000000000000000000000000000000000000000000000000000000000000004"
+		);
+		assert_eq!(logs[0].data, Vec::<u8>::new());
+	}
+
+	#[test]
+	fn approve_of_unexistent_asset_should_fail() {
+		impl_precompile_mock_simple!(Mock, Err("this is an error"), Ok(vec![]), Ok(()));
+
+		let input_data = EvmDataWriter::new_with_selector(Action::Approve)
+			.write(Address(H160::repeat_byte(0xBB)))
+			.write(U256::from(4))
+			.build();
+
+		let mut handle = create_mock_handle(input_data, 0, 0, H160::repeat_byte(0xAA));
+		handle.code_address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+		assert_eq!(Mock::execute(&mut handle).unwrap_err(), revert("this is an error"));
+		assert!(handle.logs.is_empty());
+	}
+
+	#[test]
+	fn get_approved_should_return_an_address() {
+		impl_precompile_mock_simple!(Mock, Ok(H160::zero()), Ok(vec![]), Ok(()));
+
+		let input = EvmDataWriter::new_with_selector(Action::GetApproved)
+			.write(U256::from(4))
+			.build();
+		let mut handle = create_mock_handle_from_input(input);
+		handle.code_address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+		assert_ok!(
+			Mock::execute(&mut handle),
+			succeed(EvmDataWriter::new().write(Address(H160::zero())).build())
+		);
+	}
+
+	#[test]
+	fn set_approval_for_all_should_generate_log() {
+		impl_precompile_mock_simple!(Mock, Ok(H160::zero()), Ok(vec![]), Ok(()));
+
+		let input_data = EvmDataWriter::new_with_selector(Action::SetApprovalForAll)
+			.write(Address(H160::repeat_byte(0xBB)))
+			.write(true)
+			.build();
+
+		let mut handle = create_mock_handle(input_data, 0, 0, H160::repeat_byte(0xAA));
+		handle.code_address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+		assert_ok!(Mock::execute(&mut handle));
+
+		let logs = handle.logs;
+		assert_eq!(logs.len(), 1);
+		assert_eq!(logs[0].topics.len(), 3);
+		assert_eq!(logs[0].topics[0], SELECTOR_LOG_APPROVAL_FOR_ALL.into());
+		assert_eq!(
+			hex::encode(logs[0].topics[1]),
+			"000000000000000000000000aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa"
+		);
+		assert_eq!(
+			hex::encode(logs[0].topics[2]),
+			"000000000000000000000000bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb"
+		);
+		assert_eq!(logs[0].data, EvmDataWriter::new().write(true).build());
+	}
+
+	#[test]
+	fn is_approved_for_all_should_return_a_bool() {
+		impl_precompile_mock_simple!(Mock, Ok(H160::zero()), Ok(vec![]), Ok(()));
+
+		let input = EvmDataWriter::new_with_selector(Action::IsApprovedForAll)
+			.write(Address(H160::repeat_byte(0xAA)))
+			.write(Address(H160::repeat_byte(0xBB)))
+			.build();
+		let mut handle = create_mock_handle_from_input(input);
+		handle.code_address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+		assert_ok!(Mock::execute(&mut handle), succeed(EvmDataWriter::new().write(false).build()));
+	}
+
+	#[test]
+	fn set_approval_for_all_in_static_context_should_fail() {
+		impl_precompile_mock_simple!(Mock, Ok(H160::zero()), Ok(vec![]), Ok(()));
+
+		let input = EvmDataWriter::new_with_selector(Action::SetApprovalForAll)
+			.write(Address(H160::repeat_byte(0xBB)))
+			.write(true)
+			.build();
+		let mut handle = create_mock_handle_from_input(input);
+		handle.code_address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+		handle.is_static = true;
+		assert_eq!(
+			Mock::execute(&mut handle).unwrap_err(),
+			revert("can't call non-static function in static context")
+		);
+	}
+}
+
+mod ownership {
+	use super::*;
+	use precompile_utils::testing::create_mock_handle;
+
+	#[test]
+	fn check_log_selectors() {
+		assert_eq!(
+			hex::encode(SELECTOR_LOG_OWNERSHIP_TRANSFERRED),
+			"8be0079c531659141344cd1fd0a4f28419497f9722a3daafe3b4186f6b6457e0"
+		);
+	}
+
+	#[test]
+	fn owner_should_return_the_collection_owner() {
+		impl_precompile_mock_simple!(Mock, Ok(H160::zero()), Ok(vec![]), Ok(()));
+
+		let input = EvmDataWriter::new_with_selector(Action::Owner).build();
+		let mut handle = create_mock_handle_from_input(input);
+		handle.code_address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+		assert_ok!(
+			Mock::execute(&mut handle),
+			succeed(EvmDataWriter::new().write(Address(H160::repeat_byte(0xCC))).build())
+		);
+	}
+
+	#[test]
+	fn transfer_ownership_should_generate_log() {
+		impl_precompile_mock_simple!(Mock, Ok(H160::zero()), Ok(vec![]), Ok(()));
+
+		let input = EvmDataWriter::new_with_selector(Action::TransferOwnership)
+			.write(Address(H160::repeat_byte(0xBB)))
+			.build();
+		let mut handle = create_mock_handle(input, 0, 0, H160::repeat_byte(0xCC));
+		handle.code_address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+		assert_ok!(Mock::execute(&mut handle));
+
+		let logs = handle.logs;
+		assert_eq!(logs.len(), 1);
+		assert_eq!(logs[0].topics.len(), 3);
+		assert_eq!(logs[0].topics[0], SELECTOR_LOG_OWNERSHIP_TRANSFERRED.into());
+		assert_eq!(
+			hex::encode(logs[0].topics[1]),
+			"000000000000000000000000cccccccccccccccccccccccccccccccccccccccc"
+		);
+		assert_eq!(
+			hex::encode(logs[0].topics[2]),
+			"000000000000000000000000bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb"
+		);
+		assert_eq!(logs[0].data, Vec::<u8>::new());
+	}
+
+	#[test]
+	fn transfer_ownership_to_zero_address_should_renounce() {
+		impl_precompile_mock_simple!(Mock, Ok(H160::zero()), Ok(vec![]), Ok(()));
+
+		let input = EvmDataWriter::new_with_selector(Action::TransferOwnership)
+			.write(Address(H160::zero()))
+			.build();
+		let mut handle = create_mock_handle(input, 0, 0, H160::repeat_byte(0xCC));
+		handle.code_address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+		assert_ok!(Mock::execute(&mut handle));
+
+		let logs = handle.logs;
+		assert_eq!(logs.len(), 1);
+		assert_eq!(logs[0].topics[2], H256::zero());
+	}
+
+	#[test]
+	fn renounce_ownership_should_generate_log() {
+		impl_precompile_mock_simple!(Mock, Ok(H160::zero()), Ok(vec![]), Ok(()));
+
+		let input = EvmDataWriter::new_with_selector(Action::RenounceOwnership).build();
+		let mut handle = create_mock_handle(input, 0, 0, H160::repeat_byte(0xCC));
+		handle.code_address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+		assert_ok!(Mock::execute(&mut handle));
+
+		let logs = handle.logs;
+		assert_eq!(logs.len(), 1);
+		assert_eq!(logs[0].topics.len(), 3);
+		assert_eq!(logs[0].topics[0], SELECTOR_LOG_OWNERSHIP_TRANSFERRED.into());
+		assert_eq!(
+			hex::encode(logs[0].topics[1]),
+			"000000000000000000000000cccccccccccccccccccccccccccccccccccccccc"
+		);
+		assert_eq!(logs[0].topics[2], H256::zero());
+	}
+
+	#[test]
+	fn renounce_ownership_with_value_should_fail() {
+		impl_precompile_mock_simple!(Mock, Ok(H160::zero()), Ok(vec![]), Ok(()));
+
+		let input = EvmDataWriter::new_with_selector(Action::RenounceOwnership).build();
+		let mut handle = create_mock_handle(input, 0, 1, H160::repeat_byte(0xCC));
+		handle.code_address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+		assert_eq!(Mock::execute(&mut handle).unwrap_err(), revert("function is not payable"));
+	}
+}
+
+mod metadata {
+	use super::*;
+	use precompile_utils::testing::create_mock_handle;
+
+	#[test]
+	fn check_log_selectors() {
+		assert_eq!(
+			hex::encode(SELECTOR_LOG_BATCH_METADATA_UPDATE),
+			"6bd5c950a8d8df17f772f5af37cb3655737899cbf903264b9795592da439661c"
+		);
+		assert_eq!(
+			hex::encode(SELECTOR_LOG_METADATA_UPDATE),
+			"f8e1a15aba9398e019f0b49df1a4fde98ee17ae345cb5f6b5e2c27f5033e8ce7"
+		);
+		assert_eq!(
+			hex::encode(SELECTOR_LOG_PERMANENT_URI),
+			"a109ba539900bf1b633f956d63c96fc89b814c7287f7aa50a9216d0b55657207"
+		);
+	}
+
+	#[test]
+	fn set_base_uri_should_generate_batch_metadata_update_log() {
+		impl_precompile_mock_simple!(Mock, Ok(H160::zero()), Ok(vec![]), Ok(()));
+
+		let input = EvmDataWriter::new_with_selector(Action::SetBaseURI)
+			.write(Bytes("ipfs://carbonara".into()))
+			.build();
+		let mut handle = create_mock_handle(input, 0, 0, H160::repeat_byte(0xCC));
+		handle.code_address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+		handle.context.address = handle.code_address;
+		assert_ok!(Mock::execute(&mut handle));
+
+		let logs = handle.logs;
+		assert_eq!(logs.len(), 1);
+		assert_eq!(logs[0].address, handle.code_address);
+		assert_eq!(logs[0].topics, vec![SELECTOR_LOG_BATCH_METADATA_UPDATE.into()]);
+		assert_eq!(logs[0].data, EvmDataWriter::new().write(U256::zero()).write(U256::MAX).build());
+	}
+
+	#[test]
+	fn set_token_uri_should_generate_metadata_update_log() {
+		impl_precompile_mock_simple!(Mock, Ok(H160::zero()), Ok(vec![]), Ok(()));
+
+		let input = EvmDataWriter::new_with_selector(Action::SetTokenURI)
+			.write(U256::from(7))
+			.write(Bytes("ipfs://carbonara".into()))
+			.build();
+		let mut handle = create_mock_handle(input, 0, 0, H160::repeat_byte(0xCC));
+		handle.code_address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+		handle.context.address = handle.code_address;
+		assert_ok!(Mock::execute(&mut handle));
+
+		let logs = handle.logs;
+		assert_eq!(logs.len(), 1);
+		assert_eq!(logs[0].address, handle.code_address);
+		assert_eq!(logs[0].topics, vec![SELECTOR_LOG_METADATA_UPDATE.into()]);
+		assert_eq!(logs[0].data, EvmDataWriter::new().write(U256::from(7)).build());
+	}
+
+	#[test]
+	fn name_and_symbol_should_return_strings() {
+		impl_precompile_mock_simple!(Mock, Ok(H160::zero()), Ok(vec![]), Ok(()));
+
+		for (action, expected) in [(Action::Name, "Carbonara"), (Action::Symbol, "CRB")] {
+			let input = EvmDataWriter::new_with_selector(action).build();
+			let mut handle = create_mock_handle_from_input(input);
+			handle.code_address =
+				H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+
+			let result = Mock::execute(&mut handle).unwrap();
+			assert_eq!(result.output, EvmDataWriter::new().write(Bytes(expected.into())).build());
+		}
+	}
+
+	#[test]
+	fn freeze_metadata_should_generate_permanent_uri_log() {
+		impl_precompile_mock_simple!(Mock, Ok(H160::zero()), Ok(vec![]), Ok(()));
+
+		let input = EvmDataWriter::new_with_selector(Action::FreezeMetadata).build();
+		let mut handle = create_mock_handle(input, 0, 0, H160::repeat_byte(0xCC));
+		handle.code_address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+		handle.context.address = handle.code_address;
+		assert_ok!(Mock::execute(&mut handle));
+
+		let logs = handle.logs;
+		assert_eq!(logs.len(), 1);
+		assert_eq!(logs[0].address, handle.code_address);
+		assert_eq!(
+			logs[0].topics,
+			vec![SELECTOR_LOG_PERMANENT_URI.into(), H256::repeat_byte(0xff)]
+		);
+		assert_eq!(
+			logs[0].data,
+			EvmDataWriter::new().write(Bytes("ipfs://carbonara".into())).build()
+		);
+	}
+
+	#[test]
+	fn destroy_collection_should_generate_ownership_transferred_log() {
+		impl_precompile_mock_simple!(Mock, Ok(H160::zero()), Ok(vec![]), Ok(()));
+
+		let input = EvmDataWriter::new_with_selector(Action::DestroyCollection).build();
+		let mut handle = create_mock_handle(input, 0, 0, H160::repeat_byte(0xCC));
+		handle.code_address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+		handle.context.address = handle.code_address;
+		assert_ok!(Mock::execute(&mut handle));
+
+		let logs = handle.logs;
+		assert_eq!(logs.len(), 1);
+		assert_eq!(
+			logs[0].topics,
+			vec![
+				SELECTOR_LOG_OWNERSHIP_TRANSFERRED.into(),
+				H160::repeat_byte(0xCC).into(),
+				H160::zero().into()
+			]
+		);
+	}
+
+	#[test]
+	fn set_token_uri_in_static_context_should_fail() {
+		impl_precompile_mock_simple!(Mock, Ok(H160::zero()), Ok(vec![]), Ok(()));
+
+		let input = EvmDataWriter::new_with_selector(Action::SetTokenURI)
+			.write(U256::from(7))
+			.write(Bytes("ipfs://carbonara".into()))
+			.build();
+		let mut handle = create_mock_handle(input, 0, 0, H160::repeat_byte(0xCC));
+		handle.code_address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+		handle.is_static = true;
+		assert_eq!(
+			Mock::execute(&mut handle).unwrap_err(),
+			revert("can't call non-static function in static context")
+		);
+	}
+}
+
+mod soulbound {
+	use super::*;
+	use precompile_utils::testing::create_mock_handle;
+
+	#[test]
+	fn check_log_selectors() {
+		assert_eq!(
+			hex::encode(SELECTOR_LOG_LOCKED),
+			"032bc66be43dbccb7487781d168eb7bda224628a3b2c3388bdf69b532a3a1611"
+		);
+	}
+
+	#[test]
+	fn all_assets_token_id_should_be_type_uint256_max() {
+		// the interface documents `type(uint256).max` as the id of every asset
+		assert_eq!(ALL_ASSETS_TOKEN_ID, U256::MAX);
+	}
+
+	#[test]
+	fn make_soulbound_should_generate_locked_log() {
+		impl_precompile_mock_simple!(Mock, Ok(H160::zero()), Ok(vec![]), Ok(()));
+
+		let input = EvmDataWriter::new_with_selector(Action::MakeSoulbound).build();
+		let mut handle = create_mock_handle(input, 0, 0, H160::repeat_byte(0xCC));
+		handle.code_address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+		handle.context.address = handle.code_address;
+		assert_ok!(Mock::execute(&mut handle));
+
+		let logs = handle.logs;
+		assert_eq!(logs.len(), 1);
+		assert_eq!(logs[0].address, handle.code_address);
+		assert_eq!(logs[0].topics, vec![SELECTOR_LOG_LOCKED.into()]);
+		assert_eq!(logs[0].data, EvmDataWriter::new().write(U256::MAX).build());
+	}
+
+	#[test]
+	fn locked_should_return_a_bool() {
+		impl_precompile_mock_simple!(Mock, Ok(H160::zero()), Ok(vec![]), Ok(()));
+
+		let input = EvmDataWriter::new_with_selector(Action::Locked).write(U256::from(4)).build();
+		let mut handle = create_mock_handle_from_input(input);
+		handle.code_address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+
+		let result = Mock::execute(&mut handle).unwrap();
+		assert_eq!(result.output, EvmDataWriter::new().write(true).build());
+	}
+
+	#[test]
+	fn is_locked_should_return_a_bool() {
+		impl_precompile_mock_simple!(Mock, Ok(H160::zero()), Ok(vec![]), Ok(()));
+
+		let input = EvmDataWriter::new_with_selector(Action::IsLocked).write(U256::from(4)).build();
+		let mut handle = create_mock_handle_from_input(input);
+		handle.code_address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+
+		let result = Mock::execute(&mut handle).unwrap();
+		assert_eq!(result.output, EvmDataWriter::new().write(false).build());
+	}
+}
+
+mod pausing {
+	use super::*;
+	use precompile_utils::testing::create_mock_handle;
+
+	#[test]
+	fn check_log_selectors() {
+		assert_eq!(
+			hex::encode(SELECTOR_LOG_PAUSED),
+			"62e78cea01bee320cd4e420270b5ea74000d11b0c9f74754ebdbfc544b05a258"
+		);
+		assert_eq!(
+			hex::encode(SELECTOR_LOG_UNPAUSED),
+			"5db9ee0a495bf2e6ff9c91a7834c1ba4fdd244a5e8aa4e537bd38aeae4b073aa"
+		);
+	}
+
+	#[test]
+	fn pause_and_unpause_should_generate_logs_of_the_caller() {
+		impl_precompile_mock_simple!(Mock, Ok(H160::zero()), Ok(vec![]), Ok(()));
+
+		for (action, selector) in
+			[(Action::Pause, SELECTOR_LOG_PAUSED), (Action::Unpause, SELECTOR_LOG_UNPAUSED)]
+		{
+			let input = EvmDataWriter::new_with_selector(action).build();
+			let mut handle = create_mock_handle(input, 0, 0, H160::repeat_byte(0xCC));
+			handle.code_address =
+				H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+			handle.context.address = handle.code_address;
+			assert_ok!(Mock::execute(&mut handle));
+
+			let logs = handle.logs;
+			assert_eq!(logs.len(), 1);
+			assert_eq!(logs[0].address, handle.code_address);
+			assert_eq!(logs[0].topics, vec![selector.into()]);
+			assert_eq!(
+				logs[0].data,
+				EvmDataWriter::new().write(Address(H160::repeat_byte(0xCC))).build()
+			);
+		}
+	}
+
+	#[test]
+	fn paused_should_return_a_bool() {
+		impl_precompile_mock_simple!(Mock, Ok(H160::zero()), Ok(vec![]), Ok(()));
+
+		let input = EvmDataWriter::new_with_selector(Action::Paused).build();
+		let mut handle = create_mock_handle_from_input(input);
+		handle.code_address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+
+		let result = Mock::execute(&mut handle).unwrap();
+		assert_eq!(result.output, EvmDataWriter::new().write(true).build());
+	}
+
+	#[test]
+	fn pause_with_value_should_fail() {
+		impl_precompile_mock_simple!(Mock, Ok(H160::zero()), Ok(vec![]), Ok(()));
+
+		let input = EvmDataWriter::new_with_selector(Action::Pause).build();
+		let mut handle = create_mock_handle(input, 0, 1, H160::repeat_byte(0xCC));
+		handle.code_address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+		assert_eq!(Mock::execute(&mut handle).unwrap_err(), revert("function is not payable"));
+	}
+}
+
+mod minting {
+	use super::*;
+	use precompile_utils::testing::create_mock_handle;
+
+	#[test]
+	fn mint_should_generate_transfer_log_from_zero_address() {
+		impl_precompile_mock_simple!(Mock, Ok(H160::zero()), Ok(vec![]), Ok(()));
+
+		let input = EvmDataWriter::new_with_selector(Action::Mint)
+			.write(Address(H160::repeat_byte(0xBB)))
+			.write(U256::from(4))
+			.build();
+		let mut handle = create_mock_handle(input, 0, 0, H160::repeat_byte(0xAA));
+		handle.code_address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+		assert_ok!(Mock::execute(&mut handle));
+
+		let logs = handle.logs;
+		assert_eq!(logs.len(), 1);
+		assert_eq!(logs[0].topics.len(), 4);
+		assert_eq!(logs[0].topics[0], SELECTOR_LOG_TRANSFER_FROM.into());
+		assert_eq!(logs[0].topics[1], H256::zero());
+		assert_eq!(
+			hex::encode(logs[0].topics[2]),
+			"000000000000000000000000bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb"
+		);
+		assert_eq!(
+			hex::encode(logs[0].topics[3]),
+			"0000000000000000000000000000000000000000000000000000000000000004"
+		);
+		assert_eq!(logs[0].data, Vec::<u8>::new());
+	}
+
+	#[test]
+	fn mint_with_value_should_fail() {
+		impl_precompile_mock_simple!(Mock, Ok(H160::zero()), Ok(vec![]), Ok(()));
+
+		let input = EvmDataWriter::new_with_selector(Action::Mint)
+			.write(Address(H160::repeat_byte(0xBB)))
+			.write(U256::from(4))
+			.build();
+		let mut handle = create_mock_handle(input, 0, 1, H160::repeat_byte(0xAA));
+		handle.code_address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+		assert_eq!(Mock::execute(&mut handle).unwrap_err(), revert("function is not payable"));
+	}
+
+	#[test]
+	fn total_supply_should_return_the_supply() {
+		impl_precompile_mock_simple!(Mock, Ok(H160::zero()), Ok(vec![]), Ok(()));
+
+		let input = EvmDataWriter::new_with_selector(Action::TotalSupply).build();
+		let mut handle = create_mock_handle_from_input(input);
+		handle.code_address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+
+		let result = Mock::execute(&mut handle).unwrap();
+		assert_eq!(result.output, EvmDataWriter::new().write(U256::from(TOTAL_SUPPLY)).build());
+	}
+}
+
+mod rental {
+	use super::*;
+	use precompile_utils::testing::create_mock_handle;
+
+	#[test]
+	fn check_log_selectors() {
+		assert_eq!(
+			hex::encode(SELECTOR_LOG_UPDATE_USER),
+			"4e06b4e7000e659094299b3533b47b6aa8ad048e95e872d23d1f4ee55af89cfe"
+		);
+	}
+
+	#[test]
+	fn set_user_should_generate_log() {
+		impl_precompile_mock_simple!(Mock, Ok(H160::zero()), Ok(vec![]), Ok(()));
+
+		let input = EvmDataWriter::new_with_selector(Action::SetUser)
+			.write(U256::from(4))
+			.write(Address(USER))
+			.write(1_700_000_000u64)
+			.build();
+		let mut handle = create_mock_handle(input, 0, 0, H160::repeat_byte(0xCC));
+		handle.code_address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+		handle.context.address = handle.code_address;
+		assert_ok!(Mock::execute(&mut handle));
+
+		let logs = handle.logs;
+		assert_eq!(logs.len(), 1);
+		assert_eq!(logs[0].address, handle.code_address);
+		assert_eq!(logs[0].topics.len(), 3);
+		assert_eq!(logs[0].topics[0], SELECTOR_LOG_UPDATE_USER.into());
+		assert_eq!(logs[0].topics[1], H256::from_low_u64_be(4));
+		assert_eq!(logs[0].topics[2], USER.into());
+		assert_eq!(logs[0].data, EvmDataWriter::new().write(1_700_000_000u64).build());
+	}
+
+	#[test]
+	fn user_of_and_user_expires_should_return_the_user_and_its_expiry() {
+		impl_precompile_mock_simple!(Mock, Ok(H160::zero()), Ok(vec![]), Ok(()));
+
+		let input = EvmDataWriter::new_with_selector(Action::UserOf)
+			.write(U256::from(RENTED_ASSET))
+			.build();
+		let mut handle = create_mock_handle_from_input(input);
+		handle.code_address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+		let result = Mock::execute(&mut handle).unwrap();
+		assert_eq!(result.output, EvmDataWriter::new().write(Address(USER)).build());
+
+		let input = EvmDataWriter::new_with_selector(Action::UserExpires)
+			.write(U256::from(RENTED_ASSET))
+			.build();
+		let mut handle = create_mock_handle_from_input(input);
+		handle.code_address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+		let result = Mock::execute(&mut handle).unwrap();
+		assert_eq!(result.output, EvmDataWriter::new().write(U256::from(1_700_000_000u64)).build());
+	}
+
+	#[test]
+	fn transfer_of_a_rented_asset_should_log_the_reset_of_its_user() {
+		impl_precompile_mock_simple!(Mock, Ok(H160::repeat_byte(0xAA)), Ok(vec![]), Ok(()));
+
+		let input = EvmDataWriter::new_with_selector(Action::TransferFrom)
+			.write(Address(H160::repeat_byte(0xAA)))
+			.write(Address(H160::repeat_byte(0xBB)))
+			.write(U256::from(RENTED_ASSET))
+			.build();
+		let mut handle = create_mock_handle(input, 0, 0, H160::repeat_byte(0xAA));
+		handle.code_address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+		assert_ok!(Mock::execute(&mut handle));
+
+		let logs = handle.logs;
+		assert_eq!(logs.len(), 2);
+		assert_eq!(logs[0].topics[0], SELECTOR_LOG_UPDATE_USER.into());
+		assert_eq!(logs[0].topics[1], H256::from_low_u64_be(RENTED_ASSET));
+		assert_eq!(logs[0].topics[2], H256::zero());
+		assert_eq!(logs[0].data, EvmDataWriter::new().write(0u64).build());
+		assert_eq!(logs[1].topics[0], SELECTOR_LOG_TRANSFER_FROM.into());
+	}
+
+	#[test]
+	fn burn_of_a_rented_asset_should_log_the_reset_of_its_user() {
+		impl_precompile_mock_simple!(Mock, Ok(H160::repeat_byte(0xAA)), Ok(vec![]), Ok(()));
+
+		let input = EvmDataWriter::new_with_selector(Action::Burn)
+			.write(U256::from(RENTED_ASSET))
+			.build();
+		let mut handle = create_mock_handle(input, 0, 0, H160::repeat_byte(0xAA));
+		handle.code_address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+		assert_ok!(Mock::execute(&mut handle));
+
+		let logs = handle.logs;
+		assert_eq!(logs.len(), 2);
+		assert_eq!(logs[0].topics[0], SELECTOR_LOG_UPDATE_USER.into());
+		assert_eq!(logs[1].topics[0], SELECTOR_LOG_TRANSFER_FROM.into());
+	}
+}
+
+mod roles {
+	use super::*;
+	use precompile_utils::testing::create_mock_handle;
+
+	#[test]
+	fn check_log_selectors() {
+		assert_eq!(
+			hex::encode(SELECTOR_LOG_ROLE_GRANTED),
+			"2f8788117e7eff1d82e926ec794901d17c78024a50270940304540a733656f0d"
+		);
+		assert_eq!(
+			hex::encode(SELECTOR_LOG_ROLE_REVOKED),
+			"f6391f5c32d9c69d2a47ea670b442974b53935d1edc7fd64eb21e047a839171b"
+		);
+	}
+
+	#[test]
+	fn check_role_ids() {
+		assert_eq!(
+			hex::encode(ROLE_METADATA_MANAGER),
+			"21e141d29efe528175baa3d6b347407f49288a1a3c0aebcc3160cd2b50b2a9c1"
+		);
+		assert_eq!(
+			hex::encode(ROLE_PAUSER),
+			"65d7a28e3265b37a6474929f336521b332c1681b933f6cb9f3376673440d862a"
+		);
+		assert_eq!(
+			hex::encode(ROLE_ROYALTY_MANAGER),
+			"4c845bd8367732455594e4267d2660f9c3f2cbb53288e8c2f3ec69276e9a440a"
+		);
+		assert_eq!(
+			hex::encode(ROLE_MINTER),
+			"9f2df0fed2c77648de5860a4cc508cd0818c85b8b8a1ab4ceeef8d981c8956a6"
+		);
+	}
+
+	#[test]
+	fn grant_role_should_generate_log() {
+		impl_precompile_mock_simple!(Mock, Ok(H160::zero()), Ok(vec![]), Ok(()));
+
+		let input = EvmDataWriter::new_with_selector(Action::GrantRole)
+			.write(H256(ROLE_PAUSER))
+			.write(Address(H160::repeat_byte(0xBB)))
+			.build();
+		let mut handle = create_mock_handle(input, 0, 0, H160::repeat_byte(0xCC));
+		handle.code_address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+		assert_ok!(Mock::execute(&mut handle));
+
+		let logs = handle.logs;
+		assert_eq!(logs.len(), 1);
+		assert_eq!(logs[0].topics.len(), 4);
+		assert_eq!(logs[0].topics[0], SELECTOR_LOG_ROLE_GRANTED.into());
+		assert_eq!(logs[0].topics[1], ROLE_PAUSER.into());
+		assert_eq!(
+			hex::encode(logs[0].topics[2]),
+			"000000000000000000000000bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb"
+		);
+		assert_eq!(
+			hex::encode(logs[0].topics[3]),
+			"000000000000000000000000cccccccccccccccccccccccccccccccccccccccc"
+		);
+		assert_eq!(logs[0].data, Vec::<u8>::new());
+	}
+
+	#[test]
+	fn grant_unknown_role_should_fail() {
+		impl_precompile_mock_simple!(Mock, Ok(H160::zero()), Ok(vec![]), Ok(()));
+
+		let input = EvmDataWriter::new_with_selector(Action::GrantRole)
+			.write(H256::repeat_byte(0x01))
+			.write(Address(H160::repeat_byte(0xBB)))
+			.build();
+		let mut handle = create_mock_handle(input, 0, 0, H160::repeat_byte(0xCC));
+		handle.code_address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+		assert_eq!(Mock::execute(&mut handle).unwrap_err(), revert("unknown role"));
+	}
+
+	#[test]
+	fn has_unknown_role_should_return_false() {
+		impl_precompile_mock_simple!(Mock, Ok(H160::zero()), Ok(vec![]), Ok(()));
+
+		for (role, expected) in [(H256(ROLE_ADMIN), true), (H256::repeat_byte(0x01), false)] {
+			let input = EvmDataWriter::new_with_selector(Action::HasRole)
+				.write(role)
+				.write(Address(H160::repeat_byte(0xBB)))
+				.build();
+			let mut handle = create_mock_handle_from_input(input);
+			handle.code_address =
+				H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+
+			let result = Mock::execute(&mut handle).unwrap();
+			assert_eq!(result.output, EvmDataWriter::new().write(expected).build());
+		}
+	}
+
+	#[test]
+	fn get_role_admin_should_return_the_admin_role() {
+		impl_precompile_mock_simple!(Mock, Ok(H160::zero()), Ok(vec![]), Ok(()));
+
+		let input = EvmDataWriter::new_with_selector(Action::GetRoleAdmin)
+			.write(H256(ROLE_ROYALTY_MANAGER))
+			.build();
+		let mut handle = create_mock_handle_from_input(input);
+		handle.code_address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+
+		let result = Mock::execute(&mut handle).unwrap();
+		assert_eq!(result.output, EvmDataWriter::new().write(H256(ROLE_ADMIN)).build());
+	}
+
+	#[test]
+	fn renounce_role_should_generate_revoked_log_of_the_caller() {
+		impl_precompile_mock_simple!(Mock, Ok(H160::zero()), Ok(vec![]), Ok(()));
+
+		let input = EvmDataWriter::new_with_selector(Action::RenounceRole)
+			.write(H256(ROLE_METADATA_MANAGER))
+			.write(Address(H160::repeat_byte(0xCC)))
+			.build();
+		let mut handle = create_mock_handle(input, 0, 0, H160::repeat_byte(0xCC));
+		handle.code_address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+		assert_ok!(Mock::execute(&mut handle));
+
+		let logs = handle.logs;
+		assert_eq!(logs.len(), 1);
+		assert_eq!(logs[0].topics[0], SELECTOR_LOG_ROLE_REVOKED.into());
+		assert_eq!(logs[0].topics[1], ROLE_METADATA_MANAGER.into());
+		assert_eq!(logs[0].topics[2], logs[0].topics[3]);
+		assert_eq!(
+			hex::encode(logs[0].topics[2]),
+			"000000000000000000000000cccccccccccccccccccccccccccccccccccccccc"
+		);
+	}
+
+	#[test]
+	fn renounce_role_for_another_account_should_fail() {
+		impl_precompile_mock_simple!(Mock, Ok(H160::zero()), Ok(vec![]), Ok(()));
+
+		let input = EvmDataWriter::new_with_selector(Action::RenounceRole)
+			.write(H256(ROLE_METADATA_MANAGER))
+			.write(Address(H160::repeat_byte(0xBB)))
+			.build();
+		let mut handle = create_mock_handle(input, 0, 0, H160::repeat_byte(0xCC));
+		handle.code_address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+		assert_eq!(
+			Mock::execute(&mut handle).unwrap_err(),
+			revert("can only renounce roles for self")
+		);
+		assert!(handle.logs.is_empty());
+	}
+}
+
+mod royalties {
+	use super::*;
+	use precompile_utils::testing::create_mock_handle;
+
+	#[test]
+	fn royalty_info_should_return_the_receiver_and_the_amount() {
+		impl_precompile_mock_simple!(Mock, Ok(H160::zero()), Ok(vec![]), Ok(()));
+
+		let input = EvmDataWriter::new_with_selector(Action::RoyaltyInfo)
+			.write(U256::from(7))
+			.write(U256::from(1_000))
+			.build();
+		let mut handle = create_mock_handle_from_input(input);
+		handle.code_address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+
+		let result = Mock::execute(&mut handle).unwrap();
+		assert_eq!(
+			result.output,
+			EvmDataWriter::new()
+				.write(Address(H160::repeat_byte(0xAA)))
+				.write(U256::from(100))
+				.build()
+		);
+	}
+
+	#[test]
+	fn set_royalty_above_the_denominator_should_fail() {
+		impl_precompile_mock_simple!(Mock, Ok(H160::zero()), Ok(vec![]), Ok(()));
+
+		let input = EvmDataWriter::new_with_selector(Action::SetDefaultRoyalty)
+			.write(Address(H160::repeat_byte(0xAA)))
+			.write(U256::from(10_001))
+			.build();
+		let mut handle = create_mock_handle(input, 0, 0, H160::repeat_byte(0xCC));
+		handle.code_address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+		assert_eq!(Mock::execute(&mut handle).unwrap_err(), revert("RoyaltyTooHigh"));
+
+		let input = EvmDataWriter::new_with_selector(Action::SetTokenRoyalty)
+			.write(U256::from(7))
+			.write(Address(H160::repeat_byte(0xAA)))
+			.write(U256::from(10_000))
+			.build();
+		let mut handle = create_mock_handle(input, 0, 0, H160::repeat_byte(0xCC));
+		handle.code_address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+		assert_ok!(Mock::execute(&mut handle));
+	}
+}
+
+mod enumeration {
+	use super::*;
+
+	#[test]
+	fn balance_of_should_return_the_number_of_explicitly_owned_assets() {
+		impl_precompile_mock_simple!(Mock, Ok(H160::zero()), Ok(vec![]), Ok(()));
+
+		let input = EvmDataWriter::new_with_selector(Action::BalanceOf)
+			.write(Address(H160::repeat_byte(0xAA)))
+			.build();
+		let mut handle = create_mock_handle_from_input(input);
+		handle.code_address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+
+		let result = Mock::execute(&mut handle).unwrap();
+		assert_eq!(result.output, EvmDataWriter::new().write(U256::from(2)).build());
+	}
+
+	#[test]
+	fn token_of_owner_by_index_should_return_the_asset_id() {
+		impl_precompile_mock_simple!(Mock, Ok(H160::zero()), Ok(vec![]), Ok(()));
+
+		let input = EvmDataWriter::new_with_selector(Action::TokenOfOwnerByIndex)
+			.write(Address(H160::repeat_byte(0xAA)))
+			.write(U256::from(1))
+			.build();
+		let mut handle = create_mock_handle_from_input(input);
+		handle.code_address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+
+		let result = Mock::execute(&mut handle).unwrap();
+		assert_eq!(result.output, EvmDataWriter::new().write(U256::from(5)).build());
+	}
+
+	#[test]
+	fn token_of_owner_by_index_out_of_bounds_should_fail() {
+		impl_precompile_mock_simple!(Mock, Ok(H160::zero()), Ok(vec![]), Ok(()));
+
+		for index in [U256::from(2), U256::from(u32::MAX) + 1, U256::MAX] {
+			let input = EvmDataWriter::new_with_selector(Action::TokenOfOwnerByIndex)
+				.write(Address(H160::repeat_byte(0xAA)))
+				.write(index)
+				.build();
+			let mut handle = create_mock_handle_from_input(input);
+			handle.code_address =
+				H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+			assert_eq!(Mock::execute(&mut handle).unwrap_err(), revert("OwnerIndexOutOfBounds"));
+		}
+	}
+}
+
+mod interfaces {
+	use super::*;
+	use precompile_utils::testing::create_mock_handle;
+
+	fn supports_interface(interface_id: u32) -> bool {
+		impl_precompile_mock_simple!(Mock, Ok(H160::zero()), Ok(vec![]), Ok(()));
+
+		let mut word = H256::zero();
+		word[..4].copy_from_slice(&interface_id.to_be_bytes());
+		let input = EvmDataWriter::new_with_selector(Action::SupportsInterface).write(word).build();
+		let mut handle = create_mock_handle_from_input(input);
+		handle.code_address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+		handle.is_static = true;
+
+		Mock::execute(&mut handle).unwrap().output == EvmDataWriter::new().write(true).build()
+	}
+
+	#[test]
+	fn check_interface_ids() {
+		assert_eq!(INTERFACE_ID_ERC165, 0x01ffc9a7);
+		assert_eq!(INTERFACE_ID_ERC721, 0x80ac58cd);
+		assert_eq!(INTERFACE_ID_ERC721_METADATA, 0x5b5e139f);
+		assert_eq!(INTERFACE_ID_ERC173, 0x7f5828d0);
+		assert_eq!(INTERFACE_ID_ERC2981, 0x2a55205a);
+		assert_eq!(INTERFACE_ID_ERC5192, 0xb45a3c0e);
+		assert_eq!(INTERFACE_ID_ERC4907, 0xad092b5c);
+		assert_eq!(INTERFACE_ID_ACCESS_CONTROL, 0x7965db0b);
+	}
+
+	#[test]
+	fn supports_interface_should_report_the_supported_interfaces() {
+		for interface_id in SUPPORTED_INTERFACES {
+			assert!(supports_interface(*interface_id));
+		}
+	}
+
+	#[test]
+	fn supports_interface_should_reject_unsupported_interfaces() {
+		// as required by ERC-165
+		assert!(!supports_interface(0xffffffff));
+		assert!(!supports_interface(0x00000000));
+		assert!(!supports_interface(Action::Burn as u32));
+	}
+
+	#[test]
+	fn supports_interface_with_value_should_fail() {
+		impl_precompile_mock_simple!(Mock, Ok(H160::zero()), Ok(vec![]), Ok(()));
+
+		let input = EvmDataWriter::new_with_selector(Action::SupportsInterface)
+			.write(H256::zero())
+			.build();
+		let mut handle = create_mock_handle(input, 0, 1, H160::zero());
+		handle.code_address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+		assert_eq!(Mock::execute(&mut handle).unwrap_err(), revert("function is not payable"));
+	}
+}
+
+mod gas {
+	use super::*;
+	use pallet_living_assets_ownership::WeightInfo;
+	use precompile_utils::{
+		log_costs,
+		testing::{create_mock_handle, SubcallOutput},
+	};
+
+	#[test]
+	fn owner_of_should_charge_its_weight() {
+		impl_precompile_mock_simple!(Mock, Ok(H160::zero()), Ok(vec![]), Ok(()));
+
+		let input = EvmDataWriter::new_with_selector(Action::OwnerOf).write(U256::from(4)).build();
+		let mut handle = create_mock_handle_from_input(input);
+		handle.code_address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+
+		assert_ok!(Mock::execute(&mut handle));
+		assert_eq!(handle.gas_used, <() as WeightInfo>::owner_of().ref_time());
+	}
+
+	#[test]
+	fn batch_transfer_from_should_charge_its_weight_and_the_logs() {
+		impl_precompile_mock_simple!(Mock, Ok(H160::zero()), Ok(vec![]), Ok(()));
+
+		let input = EvmDataWriter::new_with_selector(Action::BatchTransferFrom)
+			.write(Address(H160::repeat_byte(0xAA)))
+			.write(vec![Address(H160::repeat_byte(0xBB)); 3])
+			.write(vec![U256::from(4), U256::from(5), U256::from(6)])
+			.build();
+		let mut handle = create_mock_handle(input, 0, 0, H160::repeat_byte(0xAA));
+		handle.code_address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+
+		assert_ok!(Mock::execute(&mut handle));
+		assert_eq!(
+			handle.gas_used,
+			3 * <() as WeightInfo>::user_of().ref_time() +
+				<() as WeightInfo>::batch_transfer(3).ref_time() +
+				3 * log_costs(4, 0).unwrap()
+		);
+	}
+
+	#[test]
+	fn mint_should_charge_its_weight_and_the_log() {
+		impl_precompile_mock_simple!(Mock, Ok(H160::zero()), Ok(vec![]), Ok(()));
+
+		let input = EvmDataWriter::new_with_selector(Action::Mint)
+			.write(Address(H160::repeat_byte(0xBB)))
+			.write(U256::from(4))
+			.build();
+		let mut handle = create_mock_handle(input, 0, 0, H160::repeat_byte(0xAA));
+		handle.code_address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+
+		assert_ok!(Mock::execute(&mut handle));
+		assert_eq!(
+			handle.gas_used,
+			<() as WeightInfo>::mint().ref_time() + log_costs(4, 0).unwrap()
+		);
+	}
+
+	#[test]
+	fn total_supply_should_charge_its_weight() {
+		impl_precompile_mock_simple!(Mock, Ok(H160::zero()), Ok(vec![]), Ok(()));
+
+		let input = EvmDataWriter::new_with_selector(Action::TotalSupply).build();
+		let mut handle = create_mock_handle_from_input(input);
+		handle.code_address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+
+		assert_ok!(Mock::execute(&mut handle));
+		assert_eq!(handle.gas_used, <() as WeightInfo>::total_supply().ref_time());
+	}
+
+	#[test]
+	fn balance_of_should_charge_its_weight() {
+		impl_precompile_mock_simple!(Mock, Ok(H160::zero()), Ok(vec![]), Ok(()));
+
+		let input = EvmDataWriter::new_with_selector(Action::BalanceOf)
+			.write(Address(H160::repeat_byte(0xAA)))
+			.build();
+		let mut handle = create_mock_handle_from_input(input);
+		handle.code_address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+
+		assert_ok!(Mock::execute(&mut handle));
+		assert_eq!(handle.gas_used, <() as WeightInfo>::balance_of().ref_time());
+	}
+
+	#[test]
+	fn is_locked_should_charge_its_weight() {
+		impl_precompile_mock_simple!(Mock, Ok(H160::zero()), Ok(vec![]), Ok(()));
+
+		let input = EvmDataWriter::new_with_selector(Action::IsLocked).write(U256::from(4)).build();
+		let mut handle = create_mock_handle_from_input(input);
+		handle.code_address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+
+		assert_ok!(Mock::execute(&mut handle));
+		assert_eq!(handle.gas_used, <() as WeightInfo>::is_locked().ref_time());
+	}
+
+	#[test]
+	fn token_uri_should_charge_by_the_length_of_the_uri() {
+		impl_precompile_mock_simple!(
+			Mock,
+			Ok(H160::zero()),
+			Ok("ipfs://carbonara/4".as_bytes().to_vec()),
+			Ok(())
+		);
+
+		let input = EvmDataWriter::new_with_selector(Action::TokenURI).write(U256::from(4)).build();
+		let mut handle = create_mock_handle_from_input(input);
+		handle.code_address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+
+		assert_ok!(Mock::execute(&mut handle));
+		assert_eq!(handle.gas_used, <() as WeightInfo>::token_uri(18).ref_time());
+	}
+
+	#[test]
+	fn transfer_from_should_charge_its_weight_and_the_log() {
+		impl_precompile_mock_simple!(Mock, Ok(H160::repeat_byte(0xAA)), Ok(vec![]), Ok(()));
+
+		let input = EvmDataWriter::new_with_selector(Action::TransferFrom)
+			.write(Address(H160::repeat_byte(0xAA)))
+			.write(Address(H160::repeat_byte(0xBB)))
+			.write(U256::from(4))
+			.build();
+		let mut handle = create_mock_handle(input, 0, 0, H160::repeat_byte(0xAA));
+		handle.code_address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+
+		assert_ok!(Mock::execute(&mut handle));
+		assert_eq!(
+			handle.gas_used,
+			<() as WeightInfo>::user_of().ref_time() +
+				<() as WeightInfo>::transfer_from().ref_time() +
+				log_costs(4, 0).unwrap()
+		);
+	}
+
+	#[test]
+	fn safe_transfer_from_should_charge_the_receiver_check_and_the_subcall() {
+		impl_precompile_mock_simple!(Mock, Ok(H160::zero()), Ok(vec![]), Ok(()));
+
+		let input = EvmDataWriter::new_with_selector(Action::SafeTransferFrom)
+			.write(Address(H160::repeat_byte(0xAA)))
+			.write(Address(CONTRACT))
+			.write(U256::from(4))
+			.build();
+		let mut handle = create_mock_handle(input, 0, 0, H160::repeat_byte(0xAA))
+			.with_subcall_handle(|_| {
+				let mut word = H256::zero();
+				word[..4].copy_from_slice(&SELECTOR_ON_ERC721_RECEIVED.to_be_bytes());
+				SubcallOutput {
+					cost: 1_000,
+					..SubcallOutput::succeed(EvmDataWriter::new().write(word).build())
+				}
+			});
+		handle.code_address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+
+		assert_ok!(Mock::execute(&mut handle));
+		assert_eq!(
+			handle.gas_used,
+			<() as WeightInfo>::user_of().ref_time() +
+				<() as WeightInfo>::transfer_from().ref_time() +
+				log_costs(4, 0).unwrap() +
+				IS_CONTRACT_GAS_COST +
+				1_000
+		);
+	}
+
+	#[test]
+	fn failing_transfer_from_should_charge_its_weight() {
+		impl_precompile_mock_simple!(Mock, Ok(H160::zero()), Ok(vec![]), Err("this is an error"));
+
+		let input = EvmDataWriter::new_with_selector(Action::TransferFrom)
+			.write(Address(H160::repeat_byte(0xAA)))
+			.write(Address(H160::repeat_byte(0xBB)))
+			.write(U256::from(4))
+			.build();
+		let mut handle = create_mock_handle(input, 0, 0, H160::repeat_byte(0xAA));
+		handle.code_address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+
+		assert!(Mock::execute(&mut handle).is_err());
+		assert_eq!(
+			handle.gas_used,
+			<() as WeightInfo>::user_of().ref_time() +
+				<() as WeightInfo>::transfer_from().ref_time()
+		);
+	}
+
+	#[test]
+	fn set_base_uri_should_charge_by_the_length_of_the_uri_and_the_log() {
+		impl_precompile_mock_simple!(Mock, Ok(H160::zero()), Ok(vec![]), Ok(()));
+
+		let input = EvmDataWriter::new_with_selector(Action::SetBaseURI)
+			.write(Bytes("ipfs://carbonara".into()))
+			.build();
+		let mut handle = create_mock_handle(input, 0, 0, H160::repeat_byte(0xCC));
+		handle.code_address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+
+		assert_ok!(Mock::execute(&mut handle));
+		assert_eq!(
+			handle.gas_used,
+			<() as WeightInfo>::set_base_uri(16).ref_time() + log_costs(1, 64).unwrap()
+		);
+	}
+
+	#[test]
+	fn freeze_metadata_should_charge_by_the_length_of_the_uri_and_the_log() {
+		impl_precompile_mock_simple!(Mock, Ok(H160::zero()), Ok(vec![]), Ok(()));
+
+		let input = EvmDataWriter::new_with_selector(Action::FreezeMetadata).build();
+		let mut handle = create_mock_handle(input, 0, 0, H160::repeat_byte(0xCC));
+		handle.code_address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+
+		assert_ok!(Mock::execute(&mut handle));
+		// the base URI of the mock, "ipfs://carbonara", is 16 bytes long
+		assert_eq!(
+			handle.gas_used,
+			<() as WeightInfo>::freeze_metadata().ref_time() +
+				<() as WeightInfo>::base_uri(16).ref_time() +
+				log_costs(2, 96).unwrap()
+		);
+	}
+
+	#[test]
+	fn set_default_royalty_should_charge_its_weight() {
+		impl_precompile_mock_simple!(Mock, Ok(H160::zero()), Ok(vec![]), Ok(()));
+
+		let input = EvmDataWriter::new_with_selector(Action::SetDefaultRoyalty)
+			.write(Address(H160::repeat_byte(0xAA)))
+			.write(U256::from(250))
+			.build();
+		let mut handle = create_mock_handle(input, 0, 0, H160::repeat_byte(0xCC));
+		handle.code_address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+
+		assert_ok!(Mock::execute(&mut handle));
+		assert_eq!(handle.gas_used, <() as WeightInfo>::set_default_royalty().ref_time());
+	}
+
+	#[test]
+	fn make_soulbound_should_charge_its_weight_and_the_log() {
+		impl_precompile_mock_simple!(Mock, Ok(H160::zero()), Ok(vec![]), Ok(()));
+
+		let input = EvmDataWriter::new_with_selector(Action::MakeSoulbound).build();
+		let mut handle = create_mock_handle(input, 0, 0, H160::repeat_byte(0xCC));
+		handle.code_address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+
+		assert_ok!(Mock::execute(&mut handle));
+		assert_eq!(
+			handle.gas_used,
+			<() as WeightInfo>::make_soulbound().ref_time() + log_costs(1, 32).unwrap()
+		);
+	}
+
+	#[test]
+	fn pause_should_charge_its_weight_and_the_log() {
+		impl_precompile_mock_simple!(Mock, Ok(H160::zero()), Ok(vec![]), Ok(()));
+
+		let input = EvmDataWriter::new_with_selector(Action::Pause).build();
+		let mut handle = create_mock_handle(input, 0, 0, H160::repeat_byte(0xCC));
+		handle.code_address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+
+		assert_ok!(Mock::execute(&mut handle));
+		assert_eq!(
+			handle.gas_used,
+			<() as WeightInfo>::pause().ref_time() + log_costs(1, 32).unwrap()
+		);
+	}
+
+	#[test]
+	fn paused_should_charge_its_weight() {
+		impl_precompile_mock_simple!(Mock, Ok(H160::zero()), Ok(vec![]), Ok(()));
+
+		let input = EvmDataWriter::new_with_selector(Action::Paused).build();
+		let mut handle = create_mock_handle_from_input(input);
+		handle.code_address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+
+		assert_ok!(Mock::execute(&mut handle));
+		assert_eq!(handle.gas_used, <() as WeightInfo>::paused().ref_time());
+	}
+
+	#[test]
+	fn set_user_should_charge_its_weight_and_the_log() {
+		impl_precompile_mock_simple!(Mock, Ok(H160::zero()), Ok(vec![]), Ok(()));
+
+		let input = EvmDataWriter::new_with_selector(Action::SetUser)
+			.write(U256::from(4))
+			.write(Address(USER))
+			.write(1_700_000_000u64)
+			.build();
+		let mut handle = create_mock_handle(input, 0, 0, H160::repeat_byte(0xCC));
+		handle.code_address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+
+		assert_ok!(Mock::execute(&mut handle));
+		assert_eq!(
+			handle.gas_used,
+			<() as WeightInfo>::set_user().ref_time() + log_costs(3, 32).unwrap()
+		);
+	}
+
+	#[test]
+	fn user_of_should_charge_its_weight() {
+		impl_precompile_mock_simple!(Mock, Ok(H160::zero()), Ok(vec![]), Ok(()));
+
+		let input = EvmDataWriter::new_with_selector(Action::UserOf).write(U256::from(4)).build();
+		let mut handle = create_mock_handle_from_input(input);
+		handle.code_address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+
+		assert_ok!(Mock::execute(&mut handle));
+		assert_eq!(handle.gas_used, <() as WeightInfo>::user_of().ref_time());
+	}
+
+	#[test]
+	fn revoke_role_should_charge_its_weight_and_the_log() {
+		impl_precompile_mock_simple!(Mock, Ok(H160::zero()), Ok(vec![]), Ok(()));
+
+		let input = EvmDataWriter::new_with_selector(Action::RevokeRole)
+			.write(H256(ROLE_PAUSER))
+			.write(Address(H160::repeat_byte(0xBB)))
+			.build();
+		let mut handle = create_mock_handle(input, 0, 0, H160::repeat_byte(0xCC));
+		handle.code_address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+
+		assert_ok!(Mock::execute(&mut handle));
+		assert_eq!(
+			handle.gas_used,
+			<() as WeightInfo>::revoke_role().ref_time() + log_costs(4, 0).unwrap()
+		);
+	}
+
+	#[test]
+	fn has_role_should_charge_its_weight() {
+		impl_precompile_mock_simple!(Mock, Ok(H160::zero()), Ok(vec![]), Ok(()));
+
+		let input = EvmDataWriter::new_with_selector(Action::HasRole)
+			.write(H256(ROLE_PAUSER))
+			.write(Address(H160::repeat_byte(0xBB)))
+			.build();
+		let mut handle = create_mock_handle_from_input(input);
+		handle.code_address = H160::from_str("ffffffffffffffffffffffff0000000000000005").unwrap();
+
+		assert_ok!(Mock::execute(&mut handle));
+		assert_eq!(handle.gas_used, <() as WeightInfo>::has_role().ref_time());
+	}
+}
+
+mod helpers {
+	/// Macro to define a precompile mock with custom closures for testing.
+	///
+	/// This macro creates mock implementations of the `Erc721` and `CollectionManager` traits,
+	/// allowing you to test how your code interacts with the precompiled contracts.
+	/// You can define a custom closure for the owner_of function. The remaining methods of the
+	/// trait succeed without side effects.
+	///
+	/// # Arguments
+	///
+	/// * `$name`: An identifier to name the precompile mock type.
+	/// * `$owner_of_collection`: A closure that takes `collection_id` and `asset_id` and returns a `Result<AccountId, &'static str>`.
+	///
+	/// # Example
+	///
+	/// ```
+	/// impl_precompile_mock!(
+	///     MyMock,
+	///     |collection_id, asset_id| { Ok(AccountId::default()) }
+	/// );
+	/// ```
+	#[macro_export]
+	macro_rules! impl_precompile_mock {
+		($name:ident, $owner_of_collection:expr, $token_uri:expr, $transfer_from:expr) => {
+			struct Erc721Mock;
+
+			impl pallet_living_assets_ownership::traits::Erc721 for Erc721Mock {
+				type Error = &'static str;
+
+				fn owner_of(
+					collection_id: CollectionId,
+					asset_id: U256,
+				) -> Result<AccountId, Self::Error> {
+					($owner_of_collection)(collection_id, asset_id)
+				}
+
+				fn token_uri(
+					collectio_id: CollectionId,
+					asset_id: U256,
+				) -> Result<Vec<u8>, Self::Error> {
+					($token_uri)(collectio_id, asset_id)
+				}
+
+				fn transfer_from(
+					origin: AccountId,
+					collection_id: CollectionId,
+					from: AccountId,
+					to: AccountId,
+					asset_id: U256,
+				) -> Result<(), Self::Error> {
+					($transfer_from)(origin, collection_id, from, to, asset_id)
+				}
+
+				fn batch_transfer_from(
+					origin: AccountId,
+					from: AccountId,
+					transfers: Vec<(CollectionId, AccountId, U256)>,
+				) -> Result<(), Self::Error> {
+					transfers.into_iter().try_for_each(|(collection_id, to, asset_id)| {
+						($transfer_from)(origin, collection_id, from, to, asset_id)
+					})
+				}
+
+				fn approve(
+					_origin: AccountId,
+					_collection_id: CollectionId,
+					_to: AccountId,
+					_asset_id: U256,
+				) -> Result<(), Self::Error> {
+					Ok(())
+				}
+
+				fn get_approved(
+					_collection_id: CollectionId,
+					_asset_id: U256,
+				) -> Result<AccountId, Self::Error> {
+					Ok(AccountId::zero())
+				}
+
+				fn set_approval_for_all(
+					_origin: AccountId,
+					_collection_id: CollectionId,
+					_operator: AccountId,
+					_approved: bool,
+				) -> Result<(), Self::Error> {
+					Ok(())
+				}
+
+				fn is_approved_for_all(
+					_collection_id: CollectionId,
+					_owner: AccountId,
+					_operator: AccountId,
+				) -> Result<bool, Self::Error> {
+					Ok(false)
+				}
+
+				fn burn(
+					_origin: AccountId,
+					_collection_id: CollectionId,
+					_asset_id: U256,
+				) -> Result<(), Self::Error> {
+					Ok(())
+				}
+
+				fn locked(
+					_collection_id: CollectionId,
+					_asset_id: U256,
+				) -> Result<bool, Self::Error> {
+					Ok(true)
+				}
+
+				fn is_locked(
+					_collection_id: CollectionId,
+					_asset_id: U256,
+				) -> Result<bool, Self::Error> {
+					Ok(false)
+				}
+
+				fn paused(_collection_id: CollectionId) -> Result<bool, Self::Error> {
+					Ok(true)
+				}
+
+				fn set_user(
+					_origin: AccountId,
+					_collection_id: CollectionId,
+					_asset_id: U256,
+					_user: AccountId,
+					_expires: u64,
+				) -> Result<(), Self::Error> {
+					Ok(())
+				}
+
+				fn user_of(
+					_collection_id: CollectionId,
+					asset_id: U256,
+				) -> Result<AccountId, Self::Error> {
+					if asset_id == U256::from(RENTED_ASSET) {
+						Ok(USER)
+					} else {
+						Ok(H160::zero())
+					}
+				}
+
+				fn user_expires(
+					_collection_id: CollectionId,
+					_asset_id: U256,
+				) -> Result<u64, Self::Error> {
+					Ok(1_700_000_000)
+				}
+
+				fn royalty_info(
+					_collection_id: CollectionId,
+					_asset_id: U256,
+					sale_price: U256,
+				) -> Result<(AccountId, U256), Self::Error> {
+					Ok((H160::repeat_byte(0xAA), sale_price / 10))
+				}
+
+				fn balance_of(
+					_collection_id: CollectionId,
+					_owner: AccountId,
+				) -> Result<u32, Self::Error> {
+					Ok(2)
+				}
+
+				fn token_of_owner_by_index(
+					_collection_id: CollectionId,
+					_owner: AccountId,
+					index: u32,
+				) -> Result<U256, Self::Error> {
+					match index {
+						0 | 1 => Ok(U256::from(index) + 4),
+						_ => Err("OwnerIndexOutOfBounds"),
+					}
+				}
+			}
+
+			impl pallet_living_assets_ownership::traits::CollectionManager for Erc721Mock {
+				type Error = &'static str;
+				type AccountId = AccountId;
+				type BaseURI = Vec<u8>;
+				type Name = Vec<u8>;
+				type Symbol = Vec<u8>;
+
+				fn base_uri(_collection_id: CollectionId) -> Option<Self::BaseURI> {
+					Some("ipfs://carbonara".as_bytes().to_vec())
+				}
+
+				fn name(_collection_id: CollectionId) -> Option<Self::Name> {
+					Some("Carbonara".as_bytes().to_vec())
+				}
+
+				fn symbol(_collection_id: CollectionId) -> Option<Self::Symbol> {
+					Some("CRB".as_bytes().to_vec())
+				}
+
+				fn create_collection(
+					_owner: AccountId,
+					_base_uri: Self::BaseURI,
+				) -> Result<CollectionId, Self::Error> {
+					Ok(0)
+				}
+
+				fn create_collection_with_name(
+					_owner: AccountId,
+					_name: Self::Name,
+					_symbol: Self::Symbol,
+					_base_uri: Self::BaseURI,
+				) -> Result<CollectionId, Self::Error> {
+					Ok(0)
+				}
+
+				fn create_collection_with_mode(
+					_owner: AccountId,
+					_name: Self::Name,
+					_symbol: Self::Symbol,
+					_base_uri: Self::BaseURI,
+					_mode: MintingMode,
+				) -> Result<CollectionId, Self::Error> {
+					Ok(0)
+				}
+
+				fn mint(
+					_origin: AccountId,
+					_collection_id: CollectionId,
+					_to: H160,
+					_asset_id: U256,
+				) -> Result<(), Self::Error> {
+					Ok(())
+				}
+
+				fn total_supply(_collection_id: CollectionId) -> Result<u64, Self::Error> {
+					Ok(TOTAL_SUPPLY)
+				}
+
+				fn owner_of_collection(_collection_id: CollectionId) -> Option<AccountId> {
+					Some(H160::repeat_byte(0xCC))
+				}
+
+				fn transfer_collection_ownership(
+					_origin: AccountId,
+					_collection_id: CollectionId,
+					_new_owner: AccountId,
+				) -> Result<(), Self::Error> {
+					Ok(())
+				}
+
+				fn renounce_collection_ownership(
+					_origin: AccountId,
+					_collection_id: CollectionId,
+				) -> Result<(), Self::Error> {
+					Ok(())
+				}
+
+				fn set_base_uri(
+					_origin: AccountId,
+					_collection_id: CollectionId,
+					_base_uri: Self::BaseURI,
+				) -> Result<(), Self::Error> {
+					Ok(())
+				}
+
+				fn set_token_uri(
+					_origin: AccountId,
+					_collection_id: CollectionId,
+					_asset_id: U256,
+					_token_uri: Self::BaseURI,
+				) -> Result<(), Self::Error> {
+					Ok(())
+				}
+
+				fn destroy_collection(
+					_origin: AccountId,
+					_collection_id: CollectionId,
+				) -> Result<(), Self::Error> {
+					Ok(())
+				}
+
+				fn freeze_metadata(
+					_origin: AccountId,
+					_collection_id: CollectionId,
+				) -> Result<(), Self::Error> {
+					Ok(())
+				}
+
+				fn make_soulbound(
+					_origin: AccountId,
+					_collection_id: CollectionId,
+				) -> Result<(), Self::Error> {
+					Ok(())
+				}
+
+				fn pause(
+					_origin: AccountId,
+					_collection_id: CollectionId,
+				) -> Result<(), Self::Error> {
+					Ok(())
+				}
+
+				fn unpause(
+					_origin: AccountId,
+					_collection_id: CollectionId,
+				) -> Result<(), Self::Error> {
+					Ok(())
+				}
+
+				fn set_default_royalty(
+					_origin: AccountId,
+					_collection_id: CollectionId,
+					_receiver: H160,
+					_basis_points: u16,
+				) -> Result<(), Self::Error> {
+					Ok(())
+				}
+
+				fn set_token_royalty(
+					_origin: AccountId,
+					_collection_id: CollectionId,
+					_asset_id: U256,
+					_receiver: H160,
+					_basis_points: u16,
+				) -> Result<(), Self::Error> {
+					Ok(())
+				}
+
+				fn has_role(
+					_collection_id: CollectionId,
+					_role: CollectionRole,
+					_account: AccountId,
+				) -> Result<bool, Self::Error> {
+					Ok(true)
+				}
+
+				fn grant_role(
+					_origin: AccountId,
+					_collection_id: CollectionId,
+					_role: CollectionRole,
+					_account: AccountId,
+				) -> Result<bool, Self::Error> {
+					Ok(true)
+				}
+
+				fn revoke_role(
+					_origin: AccountId,
+					_collection_id: CollectionId,
+					_role: CollectionRole,
+					_account: AccountId,
+				) -> Result<bool, Self::Error> {
+					Ok(true)
+				}
+
+				fn renounce_role(
+					_origin: AccountId,
+					_collection_id: CollectionId,
+					_role: CollectionRole,
+				) -> Result<bool, Self::Error> {
+					Ok(true)
 				}
 			}
 
-			type $name = Erc721Precompile<Erc721Mock>;
+			type $name = Erc721Precompile<
+				Identity,
+				Identity,
+				Erc721Mock,
+				MockGasWeightMapping,
+				(),
+				MockIsContract,
+			>;
 		};
 	}
 
@@ -7,6 +7,8 @@ pragma solidity >=0.8.3;
 /// @custom:address 0x0000000000000000000000000000000000000402
 interface LivingAssets {
     /// @notice Event emitted when a new collection is created
+    /// @dev The new collection then emits the ERC173 `OwnershipTransferred` event from the zero
+    ///  address to the caller, which owns it
     /// @param collectionAddress Address of the newly created ERC721 collection
     event CreateCollection(address indexed collectionAddress);
 
@@ -14,4 +16,28 @@ interface LivingAssets {
     /// @dev Call this function to create a new collection
     /// @return address of the ERC721 collection
     function createCollection(string memory baseURI) external returns (address);
+
+    /// @notice Creates a new collection whose assets can never be transferred
+    /// @dev The collection reports its assets as locked, as described by ERC-5192, and emits its
+    ///  `Locked` event once with `tokenId = type(uint256).max`, which is not an asset but stands
+    ///  for every asset of the collection
+    /// @return address of the ERC721 collection
+    function createSoulboundCollection(string memory baseURI) external returns (address);
+
+    /// @notice Creates a new collection with a name and a symbol
+    /// @dev The name and symbol are returned by the `name()` and `symbol()` of the collection
+    /// @return address of the ERC721 collection
+    function createCollection(string memory name, string memory symbol, string memory baseURI) external returns (address);
+
+    /// @notice Creates a new collection with a name and a symbol whose assets only exist once minted
+    /// @dev Only the owner and the accounts granted `MINTER_ROLE` can `mint` its assets, up to
+    ///  `maxSupply` of them at a time, or without cap if it is zero
+    /// @return address of the ERC721 collection
+    function createExplicitCollection(string memory name, string memory symbol, string memory baseURI, uint256 maxSupply) external returns (address);
+
+    /// @notice Transfers `tokenIds[i]` of `collections[i]` from `from` to `to[i]`, all of them or none
+    /// @dev Each collection emits the ERC721 `Transfer` event of its assets, preceded by the
+    /// ERC4907 `UpdateUser` event of those whose user is reset. The caller must be allowed to
+    /// transfer every asset, as in `transferFrom`
+    function batchTransferFrom(address from, address[] calldata collections, address[] calldata to, uint256[] calldata tokenIds) external;
 }
@@ -2,8 +2,11 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 use fp_evm::{Precompile, PrecompileHandle, PrecompileOutput};
+use frame_support::weights::Weight;
 use pallet_living_assets_ownership::{
-	collection_id_to_address, traits::CollectionManager, CollectionId,
+	address_to_collection_id, collection_id_to_address,
+	traits::{CollectionManager, Erc721},
+	CollectionId, MintingMode,
 };
 use parity_scale_codec::Encode;
 use precompile_utils::{
@@ -12,75 +15,317 @@ use precompile_utils::{
 };
 use sp_runtime::SaturatedConversion;
 
-use sp_std::{fmt::Debug, marker::PhantomData, vec::Vec};
+use sp_core::{H160, H256, U256};
+use sp_std::{fmt::Debug, marker::PhantomData, vec, vec::Vec};
 
 /// Solidity selector of the CreateCollection log, which is the Keccak of the Log signature.
 pub const SELECTOR_LOG_CREATE_COLLECTION: [u8; 32] = keccak256!("CreateCollection(address)");
 
+/// Solidity selector of the OwnershipTransferred log of ERC-173, emitted by each collection created
+/// with the zero address as previous owner.
+pub const SELECTOR_LOG_OWNERSHIP_TRANSFERRED: [u8; 32] =
+	keccak256!("OwnershipTransferred(address,address)");
+
+/// Solidity selector of the Locked log of ERC-5192, emitted by the collections created soulbound.
+pub const SELECTOR_LOG_LOCKED: [u8; 32] = keccak256!("Locked(uint256)");
+
+/// Token id of the Locked log emitted once for every asset of a collection created soulbound, as
+/// a collection holds an asset per address and slot, far too many to log each.
+pub const ALL_ASSETS_TOKEN_ID: U256 = U256::MAX;
+
+/// Solidity selector of the Transfer log of ERC-721, emitted by the collection of each asset moved
+/// by a batch transfer.
+pub const SELECTOR_LOG_TRANSFER: [u8; 32] = keccak256!("Transfer(address,address,uint256)");
+
+/// Solidity selector of the UpdateUser log of ERC-4907, emitted by the collection of each asset
+/// whose user is reset by a batch transfer.
+pub const SELECTOR_LOG_UPDATE_USER: [u8; 32] = keccak256!("UpdateUser(uint256,address,uint64)");
+
 #[precompile_utils_macro::generate_function_selector]
 #[derive(Debug, PartialEq)]
 pub enum Action {
 	/// Create collection
 	CreateCollection = "createCollection(string)",
+	/// Create collection whose assets can not be transferred
+	CreateSoulboundCollection = "createSoulboundCollection(string)",
+	/// Create collection with name and symbol
+	CreateCollectionWithName = "createCollection(string,string,string)",
+	/// Create collection with name and symbol whose assets must be minted, up to a max supply
+	CreateExplicitCollection = "createExplicitCollection(string,string,string,uint256)",
+	/// Transfer several assets of the same owner across collections
+	BatchTransferFrom = "batchTransferFrom(address,address[],address[],uint256[])",
 }
 
 /// Wrapper for the precompile function.
-pub struct CollectionManagerPrecompile<AddressMapping, AccountId, LivingAssets>(
-	PhantomData<(AddressMapping, AccountId, LivingAssets)>,
-)
+///
+/// Every call is charged the gas equivalent, as given by `GasWeightMapping`, of its `WeightInfo`.
+/// `LivingAssets` must also implement `Erc721` to move assets across collections.
+pub struct CollectionManagerPrecompile<
+	AddressMapping,
+	AccountId,
+	LivingAssets,
+	GasWeightMapping,
+	WeightInfo,
+>(PhantomData<(AddressMapping, AccountId, LivingAssets, GasWeightMapping, WeightInfo)>)
 where
 	AddressMapping: pallet_evm::AddressMapping<AccountId>,
 	AccountId: Encode + Debug,
-	LivingAssets: CollectionManager;
+	LivingAssets: CollectionManager,
+	GasWeightMapping: pallet_evm::GasWeightMapping,
+	WeightInfo: pallet_living_assets_ownership::WeightInfo;
 
-impl<AddressMapping, AccountId, LivingAssets> Precompile
-	for CollectionManagerPrecompile<AddressMapping, AccountId, LivingAssets>
+impl<AddressMapping, AccountId, LivingAssets, GasWeightMapping, WeightInfo> Precompile
+	for CollectionManagerPrecompile<
+		AddressMapping,
+		AccountId,
+		LivingAssets,
+		GasWeightMapping,
+		WeightInfo,
+	>
 where
 	AddressMapping: pallet_evm::AddressMapping<AccountId>,
 	AccountId: Encode + Debug,
-	LivingAssets: CollectionManager<AccountId = AccountId>,
+	LivingAssets: CollectionManager<AccountId = AccountId> + Erc721,
+	GasWeightMapping: pallet_evm::GasWeightMapping,
+	WeightInfo: pallet_living_assets_ownership::WeightInfo,
 {
 	fn execute(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
 		let selector = handle.read_selector()?;
 
 		handle.check_function_modifier(match selector {
 			Action::CreateCollection => FunctionModifier::NonPayable,
+			Action::CreateSoulboundCollection => FunctionModifier::NonPayable,
+			Action::CreateCollectionWithName => FunctionModifier::NonPayable,
+			Action::CreateExplicitCollection => FunctionModifier::NonPayable,
+			Action::BatchTransferFrom => FunctionModifier::NonPayable,
 		})?;
 
 		match selector {
-			Action::CreateCollection => {
-				let mut input = handle.read_input()?;
-				input.expect_arguments(1)?;
-
-				let base_uri_bytes: Vec<u8> = match input.read::<Bytes>() {
-					Ok(bytes) => bytes.into(),
-					Err(e) => return Err(e),
-				};
-
-				let base_uri = match base_uri_bytes.try_into() {
-					Ok(value) => value,
-					Err(_) => return Err(revert("base_uri too long")),
-				};
-
-				let caller = handle.context().caller;
-				let owner = AddressMapping::into_account_id(caller);
-
-				match LivingAssets::create_collection(owner, base_uri) {
-					Ok(collection_id) => {
-						let collection_address = collection_id_to_address(
-							collection_id.saturated_into::<CollectionId>(),
-						);
-
-						LogsBuilder::new(handle.context().address)
-							.log2(SELECTOR_LOG_CREATE_COLLECTION, collection_address, Vec::new())
-							.record(handle)?;
-
-						Ok(succeed(EvmDataWriter::new().write(Address(collection_address)).build()))
-					},
-					Err(err) => Err(revert(err)),
-				}
-			},
+			Action::CreateCollection => Self::create_collection(handle, false),
+			Action::CreateSoulboundCollection => Self::create_collection(handle, true),
+			Action::CreateCollectionWithName => Self::create_collection_with_name(handle),
+			Action::CreateExplicitCollection => Self::create_explicit_collection(handle),
+			Action::BatchTransferFrom => Self::batch_transfer_from(handle),
+		}
+	}
+}
+
+impl<AddressMapping, AccountId, LivingAssets, GasWeightMapping, WeightInfo>
+	CollectionManagerPrecompile<AddressMapping, AccountId, LivingAssets, GasWeightMapping, WeightInfo>
+where
+	AddressMapping: pallet_evm::AddressMapping<AccountId>,
+	AccountId: Encode + Debug,
+	LivingAssets: CollectionManager<AccountId = AccountId> + Erc721,
+	GasWeightMapping: pallet_evm::GasWeightMapping,
+	WeightInfo: pallet_living_assets_ownership::WeightInfo,
+{
+	/// Creates a collection owned by the caller, making it soulbound right away if `soulbound`.
+	fn create_collection(
+		handle: &mut impl PrecompileHandle,
+		soulbound: bool,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(1)?;
+
+		let base_uri_bytes: Vec<u8> = match input.read::<Bytes>() {
+			Ok(bytes) => bytes.into(),
+			Err(e) => return Err(e),
+		};
+
+		let mut weight = WeightInfo::create_collection(base_uri_bytes.len().saturated_into());
+		if soulbound {
+			weight = weight.saturating_add(WeightInfo::make_soulbound());
 		}
+		Self::record_weight(handle, weight)?;
+
+		let base_uri = match base_uri_bytes.try_into() {
+			Ok(value) => value,
+			Err(_) => return Err(revert("base_uri too long")),
+		};
+
+		let caller = handle.context().caller;
+		let owner = AddressMapping::into_account_id(caller);
+
+		let collection_id =
+			LivingAssets::create_collection(owner, base_uri).map_err(|err| revert(err))?;
+		if soulbound {
+			LivingAssets::make_soulbound(AddressMapping::into_account_id(caller), collection_id)
+				.map_err(|err| revert(err))?;
+		}
+
+		let output = Self::collection_created(handle, collection_id)?;
+		if soulbound {
+			// ERC-5192, once for every asset of the collection
+			let log = LogsBuilder::new(collection_id_to_address(collection_id))
+				.log1(SELECTOR_LOG_LOCKED, EvmDataWriter::new().write(ALL_ASSETS_TOKEN_ID).build());
+			handle.record_cost(log.compute_cost()?)?;
+			log.record(handle)?;
+		}
+		Ok(output)
+	}
+
+	/// Creates a collection with a name and a symbol owned by the caller.
+	fn create_collection_with_name(
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(3)?;
+
+		let name_bytes: Vec<u8> = input.read::<Bytes>()?.into();
+		let symbol_bytes: Vec<u8> = input.read::<Bytes>()?.into();
+		let base_uri_bytes: Vec<u8> = input.read::<Bytes>()?.into();
+
+		let weight = WeightInfo::create_collection_with_name(base_uri_bytes.len().saturated_into());
+		Self::record_weight(handle, weight)?;
+
+		let name = name_bytes.try_into().map_err(|_| revert("name too long"))?;
+		let symbol = symbol_bytes.try_into().map_err(|_| revert("symbol too long"))?;
+		let base_uri = base_uri_bytes.try_into().map_err(|_| revert("base_uri too long"))?;
+
+		let owner = AddressMapping::into_account_id(handle.context().caller);
+		let collection_id =
+			LivingAssets::create_collection_with_name(owner, name, symbol, base_uri)
+				.map_err(|err| revert(err))?;
+
+		Self::collection_created(handle, collection_id)
+	}
+
+	/// Creates a collection with a name and a symbol owned by the caller, whose assets must be
+	/// minted. A zero max supply means there is no cap.
+	fn create_explicit_collection(
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(4)?;
+
+		let name_bytes: Vec<u8> = input.read::<Bytes>()?.into();
+		let symbol_bytes: Vec<u8> = input.read::<Bytes>()?.into();
+		let base_uri_bytes: Vec<u8> = input.read::<Bytes>()?.into();
+		let max_supply: U256 = input.read()?;
+
+		let weight = WeightInfo::create_collection_with_mode(base_uri_bytes.len().saturated_into());
+		Self::record_weight(handle, weight)?;
+
+		let name = name_bytes.try_into().map_err(|_| revert("name too long"))?;
+		let symbol = symbol_bytes.try_into().map_err(|_| revert("symbol too long"))?;
+		let base_uri = base_uri_bytes.try_into().map_err(|_| revert("base_uri too long"))?;
+		// a supply beyond `u64::MAX` can never be reached
+		let max_supply =
+			(!max_supply.is_zero()).then(|| max_supply.min(U256::from(u64::MAX)).low_u64());
+
+		let owner = AddressMapping::into_account_id(handle.context().caller);
+		let collection_id = LivingAssets::create_collection_with_mode(
+			owner,
+			name,
+			symbol,
+			base_uri,
+			MintingMode::Explicit { max_supply },
+		)
+		.map_err(|err| revert(err))?;
+
+		Self::collection_created(handle, collection_id)
+	}
+
+	/// Transfers assets of several collections owned by `from`, emitting the `Transfer` log of
+	/// each of them from its collection address, preceded by `UpdateUser` if its user is reset.
+	fn batch_transfer_from(handle: &mut impl PrecompileHandle) -> EvmResult<PrecompileOutput> {
+		let mut input = handle.read_input()?;
+		input.expect_arguments(4)?;
+
+		let from: H160 = input.read::<Address>()?.into();
+		let collections: Vec<Address> = input.read()?;
+		let to: Vec<Address> = input.read()?;
+		let asset_ids: Vec<U256> = input.read()?;
+		if collections.len() != to.len() || to.len() != asset_ids.len() {
+			return Err(revert("arrays of different length"));
+		}
+
+		let weight = WeightInfo::user_of()
+			.saturating_mul(to.len().saturated_into())
+			.saturating_add(WeightInfo::batch_transfer(to.len().saturated_into()));
+		Self::record_weight(handle, weight)?;
+
+		let transfers = collections
+			.iter()
+			.zip(to.iter().zip(asset_ids.iter()))
+			.map(|(collection, (to, asset_id))| {
+				let collection_id = address_to_collection_id(collection.0)
+					.map_err(|_| revert("invalid collection address"))?;
+				Ok((collection_id, to.0, *asset_id))
+			})
+			.collect::<EvmResult<Vec<_>>>()?;
+		// the transfers reset the users of the assets
+		let users = transfers
+			.iter()
+			.map(|(collection_id, _, asset_id)| LivingAssets::user_of(*collection_id, *asset_id))
+			.collect::<Result<Vec<_>, _>>()
+			.map_err(|err| revert(err))?;
+		LivingAssets::batch_transfer_from(handle.context().caller, from, transfers)
+			.map_err(|err| revert(err))?;
+
+		for ((Address(collection), (Address(to), asset_id)), user) in
+			collections.into_iter().zip(to.into_iter().zip(asset_ids)).zip(users)
+		{
+			let mut asset_id_big_endian = [0u8; 32];
+			asset_id.to_big_endian(&mut asset_id_big_endian);
+			if user != H160::zero() {
+				let log = LogsBuilder::new(collection).log3(
+					SELECTOR_LOG_UPDATE_USER,
+					H256::from_slice(asset_id_big_endian.as_slice()),
+					H160::zero(),
+					EvmDataWriter::new().write(U256::zero()).build(),
+				);
+				handle.record_cost(log.compute_cost()?)?;
+				log.record(handle)?;
+			}
+			let log = LogsBuilder::new(collection).log4(
+				SELECTOR_LOG_TRANSFER,
+				from,
+				to,
+				H256::from_slice(asset_id_big_endian.as_slice()),
+				Vec::new(),
+			);
+			handle.record_cost(log.compute_cost()?)?;
+			log.record(handle)?;
+		}
+
+		Ok(succeed(vec![]))
+	}
+
+	/// Charges the gas equivalent of `weight`, and its proof size.
+	fn record_weight(handle: &mut impl PrecompileHandle, weight: Weight) -> EvmResult {
+		handle.record_cost(GasWeightMapping::weight_to_gas(weight))?;
+		handle.record_external_cost(None, Some(weight.proof_size()))?;
+		Ok(())
+	}
+
+	/// Emits the `CreateCollection` log, and the `OwnershipTransferred` log of the new collection
+	/// to the caller, and returns the address of the new collection.
+	fn collection_created(
+		handle: &mut impl PrecompileHandle,
+		collection_id: CollectionId,
+	) -> EvmResult<PrecompileOutput> {
+		let collection_address = collection_id_to_address(collection_id);
+
+		let log = LogsBuilder::new(handle.context().address).log2(
+			SELECTOR_LOG_CREATE_COLLECTION,
+			collection_address,
+			Vec::new(),
+		);
+		handle.record_cost(log.compute_cost()?)?;
+		log.record(handle)?;
+
+		// ERC-173: the caller owns the collection from its creation
+		let log = LogsBuilder::new(collection_address).log3(
+			SELECTOR_LOG_OWNERSHIP_TRANSFERRED,
+			H160::zero(),
+			handle.context().caller,
+			Vec::new(),
+		);
+		handle.record_cost(log.compute_cost()?)?;
+		log.record(handle)?;
+
+		Ok(succeed(EvmDataWriter::new().write(Address(collection_address)).build()))
 	}
 }
 
@@ -5,20 +5,37 @@
 
 use super::*;
 use frame_support::assert_ok;
+use frame_support::weights::Weight;
 use precompile_utils::{
-	revert, succeed,
+	log_costs, revert, succeed,
 	testing::{create_mock_handle, create_mock_handle_from_input},
 };
-use sp_core::H160;
+use sp_core::{H160, H256, U256};
 use sp_std::vec::Vec;
 
 type BaseURI = Vec<u8>;
 type AccountId = H160;
 type AddressMapping = pallet_evm::IdentityAddressMapping;
 
+/// Charges a unit of gas per unit of `ref_time`.
+pub struct MockGasWeightMapping;
+impl pallet_evm::GasWeightMapping for MockGasWeightMapping {
+	fn gas_to_weight(gas: u64, _without_base_weight: bool) -> Weight {
+		Weight::from_parts(gas, 0)
+	}
+
+	fn weight_to_gas(weight: Weight) -> u64 {
+		weight.ref_time()
+	}
+}
+
 #[test]
 fn check_selectors() {
 	assert_eq!(Action::CreateCollection as u32, 0x059dfe13);
+	assert_eq!(Action::CreateSoulboundCollection as u32, 0xb0800709);
+	assert_eq!(Action::CreateCollectionWithName as u32, 0xece86c21);
+	assert_eq!(Action::CreateExplicitCollection as u32, 0x7c656800);
+	assert_eq!(Action::BatchTransferFrom as u32, 0x03ac4c43);
 }
 
 #[test]
@@ -27,6 +44,20 @@ fn check_log_selectors() {
 		hex::encode(SELECTOR_LOG_CREATE_COLLECTION),
 		"18896a5e5f9fd6b9d74f89291fe4640722c8dc4d6a1025ccf047607f3e6954ee"
 	);
+	assert_eq!(
+		hex::encode(SELECTOR_LOG_TRANSFER),
+		"ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef"
+	);
+	assert_eq!(
+		hex::encode(SELECTOR_LOG_OWNERSHIP_TRANSFERRED),
+		"8be0079c531659141344cd1fd0a4f28419497f9722a3daafe3b4186f6b6457e0"
+	);
+}
+
+#[test]
+fn all_assets_token_id_should_be_type_uint256_max() {
+	// the interface documents `type(uint256).max` as the id of every asset
+	assert_eq!(ALL_ASSETS_TOKEN_ID, U256::MAX);
 }
 
 #[test]
@@ -73,7 +104,7 @@ fn create_collection_should_generate_log() {
 	let result = Mock::execute(&mut handle);
 	assert!(result.is_ok());
 	let logs = handle.logs;
-	assert_eq!(logs.len(), 1);
+	assert_eq!(logs.len(), 2);
 	assert_eq!(logs[0].address, H160::zero());
 	assert_eq!(logs[0].topics.len(), 2);
 	assert_eq!(logs[0].topics[0], SELECTOR_LOG_CREATE_COLLECTION.into());
@@ -84,6 +115,255 @@ fn create_collection_should_generate_log() {
 	assert_eq!(logs[0].data, Vec::<u8>::new());
 }
 
+#[test]
+fn create_collection_should_log_the_ownership_of_the_caller() {
+	impl_precompile_mock_simple!(Mock, Ok(0xffff), Some(BaseURI::new()));
+
+	let caller = H160::repeat_byte(0xCC);
+	let input = EvmDataWriter::new_with_selector(Action::CreateCollection)
+		.write(Bytes("ipfs::/carbonara".into()))
+		.build();
+	let mut handle = create_mock_handle(input, 0, 0, caller);
+
+	assert_ok!(Mock::execute(&mut handle));
+	let logs = handle.logs;
+	assert_eq!(logs.len(), 2);
+	assert_eq!(logs[1].address, collection_id_to_address(0xffff));
+	assert_eq!(
+		logs[1].topics,
+		vec![SELECTOR_LOG_OWNERSHIP_TRANSFERRED.into(), H160::zero().into(), caller.into()]
+	);
+	assert_eq!(logs[1].data, Vec::<u8>::new());
+}
+
+#[test]
+fn create_collection_should_charge_its_weight_and_the_log() {
+	impl_precompile_mock_simple!(Mock, Ok(0xffff), Some(BaseURI::new()));
+
+	let base_uri = "ipfs::/carbonara".as_bytes().to_vec();
+	let input = EvmDataWriter::new_with_selector(Action::CreateCollection)
+		.write(Bytes(base_uri.clone()))
+		.build();
+	let mut handle = create_mock_handle_from_input(input);
+
+	assert_ok!(Mock::execute(&mut handle));
+	assert_eq!(
+		handle.gas_used,
+		<() as pallet_living_assets_ownership::WeightInfo>::create_collection(base_uri.len() as u32)
+			.ref_time() +
+			log_costs(2, 0).unwrap() + log_costs(3, 0).unwrap()
+	);
+}
+
+#[test]
+fn failing_create_collection_should_charge_its_weight() {
+	impl_precompile_mock_simple!(Mock, Err("this is an error"), Some(BaseURI::new()));
+
+	let input = EvmDataWriter::new_with_selector(Action::CreateCollection)
+		.write(Bytes(Vec::new()))
+		.build();
+	let mut handle = create_mock_handle_from_input(input);
+
+	assert!(Mock::execute(&mut handle).is_err());
+	assert_eq!(
+		handle.gas_used,
+		<() as pallet_living_assets_ownership::WeightInfo>::create_collection(0).ref_time()
+	);
+}
+
+#[test]
+fn create_soulbound_collection_should_return_address_and_generate_log() {
+	impl_precompile_mock_simple!(Mock, Ok(5), Some(BaseURI::new()));
+
+	let input = EvmDataWriter::new_with_selector(Action::CreateSoulboundCollection)
+		.write(Bytes("ipfs::/carbonara".into()))
+		.build();
+	let mut handle = create_mock_handle_from_input(input);
+
+	let result = Mock::execute(&mut handle);
+	assert_ok!(
+		result,
+		succeed(
+			hex::decode("000000000000000000000000ffffffffffffffffffffffff0000000000000005")
+				.unwrap()
+		)
+	);
+	assert_eq!(handle.logs.len(), 3);
+	assert_eq!(handle.logs[0].topics[0], SELECTOR_LOG_CREATE_COLLECTION.into());
+	assert_eq!(handle.logs[1].topics[0], SELECTOR_LOG_OWNERSHIP_TRANSFERRED.into());
+	assert_eq!(handle.logs[2].address, collection_id_to_address(5));
+	assert_eq!(handle.logs[2].topics, vec![SELECTOR_LOG_LOCKED.into()]);
+	assert_eq!(handle.logs[2].data, EvmDataWriter::new().write(U256::MAX).build());
+}
+
+#[test]
+fn create_soulbound_collection_should_charge_the_weight_of_making_it_soulbound() {
+	impl_precompile_mock_simple!(Mock, Ok(0xffff), Some(BaseURI::new()));
+
+	let base_uri = "ipfs::/carbonara".as_bytes().to_vec();
+	let input = EvmDataWriter::new_with_selector(Action::CreateSoulboundCollection)
+		.write(Bytes(base_uri.clone()))
+		.build();
+	let mut handle = create_mock_handle_from_input(input);
+
+	assert_ok!(Mock::execute(&mut handle));
+	assert_eq!(
+		handle.gas_used,
+		<() as pallet_living_assets_ownership::WeightInfo>::create_collection(base_uri.len() as u32)
+			.ref_time() +
+			<() as pallet_living_assets_ownership::WeightInfo>::make_soulbound().ref_time() +
+			log_costs(2, 0).unwrap() +
+			log_costs(3, 0).unwrap() + log_costs(1, 32).unwrap()
+	);
+}
+
+#[test]
+fn create_collection_with_name_should_return_address_and_charge_its_weight() {
+	impl_precompile_mock_simple!(Mock, Ok(5), Some(BaseURI::new()));
+
+	let base_uri = "ipfs::/carbonara".as_bytes().to_vec();
+	let input = EvmDataWriter::new_with_selector(Action::CreateCollectionWithName)
+		.write(Bytes("Carbonara".into()))
+		.write(Bytes("CRB".into()))
+		.write(Bytes(base_uri.clone()))
+		.build();
+	let mut handle = create_mock_handle_from_input(input);
+
+	assert_ok!(
+		Mock::execute(&mut handle),
+		succeed(
+			hex::decode("000000000000000000000000ffffffffffffffffffffffff0000000000000005")
+				.unwrap()
+		)
+	);
+	assert_eq!(handle.logs.len(), 2);
+	assert_eq!(
+		handle.gas_used,
+		<() as pallet_living_assets_ownership::WeightInfo>::create_collection_with_name(
+			base_uri.len() as u32
+		)
+		.ref_time() +
+			log_costs(2, 0).unwrap() +
+			log_costs(3, 0).unwrap()
+	);
+}
+
+#[test]
+fn create_collection_with_name_should_have_three_arguments() {
+	impl_precompile_mock_simple!(Mock, Ok(5), Some(BaseURI::new()));
+
+	let input = EvmDataWriter::new_with_selector(Action::CreateCollectionWithName)
+		.write(Bytes("Carbonara".into()))
+		.build();
+	let mut handle = create_mock_handle_from_input(input);
+
+	assert!(Mock::execute(&mut handle).is_err());
+}
+
+#[test]
+fn create_explicit_collection_should_return_address_and_charge_its_weight() {
+	impl_precompile_mock_simple!(Mock, Ok(5), Some(BaseURI::new()));
+
+	let base_uri = "ipfs::/carbonara".as_bytes().to_vec();
+	let input = EvmDataWriter::new_with_selector(Action::CreateExplicitCollection)
+		.write(Bytes("Carbonara".into()))
+		.write(Bytes("CRB".into()))
+		.write(Bytes(base_uri.clone()))
+		.write(U256::from(1000))
+		.build();
+	let mut handle = create_mock_handle_from_input(input);
+
+	assert_ok!(
+		Mock::execute(&mut handle),
+		succeed(
+			hex::decode("000000000000000000000000ffffffffffffffffffffffff0000000000000005")
+				.unwrap()
+		)
+	);
+	assert_eq!(handle.logs.len(), 2);
+	assert_eq!(
+		handle.gas_used,
+		<() as pallet_living_assets_ownership::WeightInfo>::create_collection_with_mode(
+			base_uri.len() as u32
+		)
+		.ref_time() +
+			log_costs(2, 0).unwrap() +
+			log_costs(3, 0).unwrap()
+	);
+}
+
+#[test]
+fn batch_transfer_from_should_log_each_transfer_from_its_collection() {
+	impl_precompile_mock_simple!(Mock, Ok(0), Some(BaseURI::new()));
+
+	let owner = H160::repeat_byte(0xCC);
+	let input = EvmDataWriter::new_with_selector(Action::BatchTransferFrom)
+		.write(Address(owner))
+		.write(vec![Address(collection_id_to_address(1)), Address(collection_id_to_address(2))])
+		.write(vec![Address(H160::repeat_byte(0xAA)), Address(H160::repeat_byte(0xBB))])
+		.write(vec![U256::from(4), U256::from(5)])
+		.build();
+	let mut handle = create_mock_handle(input, 0, 0, owner);
+
+	assert_ok!(Mock::execute(&mut handle));
+	assert_eq!(
+		handle.gas_used,
+		2 * <() as pallet_living_assets_ownership::WeightInfo>::user_of().ref_time() +
+			<() as pallet_living_assets_ownership::WeightInfo>::batch_transfer(2).ref_time() +
+			2 * log_costs(4, 0).unwrap()
+	);
+	let logs = handle.logs;
+	assert_eq!(logs.len(), 2);
+	assert_eq!(logs[0].address, collection_id_to_address(1));
+	assert_eq!(logs[0].topics[0], SELECTOR_LOG_TRANSFER.into());
+	assert_eq!(logs[0].topics[1], owner.into());
+	assert_eq!(logs[0].topics[2], H160::repeat_byte(0xAA).into());
+	assert_eq!(logs[0].topics[3], H256::from_low_u64_be(4));
+	assert_eq!(logs[1].address, collection_id_to_address(2));
+	assert_eq!(logs[1].topics[2], H160::repeat_byte(0xBB).into());
+	assert_eq!(logs[1].topics[3], H256::from_low_u64_be(5));
+}
+
+#[test]
+fn failing_batch_transfer_from_should_not_log() {
+	impl_precompile_mock_simple!(Mock, Ok(0), Some(BaseURI::new()));
+
+	let input = EvmDataWriter::new_with_selector(Action::BatchTransferFrom)
+		.write(Address(H160::repeat_byte(0xCC)))
+		.write(vec![Address(collection_id_to_address(1))])
+		.write(vec![Address(H160::repeat_byte(0xAA))])
+		.write(vec![U256::from(4)])
+		.build();
+	let mut handle = create_mock_handle(input, 0, 0, H160::repeat_byte(0xDD));
+
+	assert_eq!(Mock::execute(&mut handle).unwrap_err(), revert("NoPermission"));
+	assert!(handle.logs.is_empty());
+}
+
+#[test]
+fn batch_transfer_from_with_invalid_arguments_should_fail() {
+	impl_precompile_mock_simple!(Mock, Ok(0), Some(BaseURI::new()));
+
+	let owner = H160::repeat_byte(0xCC);
+	let input = EvmDataWriter::new_with_selector(Action::BatchTransferFrom)
+		.write(Address(owner))
+		.write(vec![Address(collection_id_to_address(1))])
+		.write(vec![Address(H160::repeat_byte(0xAA)), Address(H160::repeat_byte(0xBB))])
+		.write(vec![U256::from(4), U256::from(5)])
+		.build();
+	let mut handle = create_mock_handle(input, 0, 0, owner);
+	assert_eq!(Mock::execute(&mut handle).unwrap_err(), revert("arrays of different length"));
+
+	let input = EvmDataWriter::new_with_selector(Action::BatchTransferFrom)
+		.write(Address(owner))
+		.write(vec![Address(H160::repeat_byte(0x01))])
+		.write(vec![Address(H160::repeat_byte(0xAA))])
+		.write(vec![U256::from(4)])
+		.build();
+	let mut handle = create_mock_handle(input, 0, 0, owner);
+	assert_eq!(Mock::execute(&mut handle).unwrap_err(), revert("invalid collection address"));
+}
+
 #[test]
 fn create_collection_on_mock_with_nonzero_value_fails() {
 	impl_precompile_mock_simple!(Mock, Ok(5), Some(BaseURI::new()));
@@ -134,7 +414,7 @@ fn call_unexistent_selector_should_fail() {
 mod helpers {
 	/// Macro to define a precompile mock for testing.
 	///
-	/// This macro creates mock implementations of the `CollectionManager` trait,
+	/// This macro creates mock implementations of the `CollectionManager` and `Erc721` traits,
 	/// allowing you to test how your code interacts with the precompiled contracts.
 	/// The mock type is named `Mock`, and the implementation uses the provided expressions.
 	///
@@ -160,6 +440,8 @@ mod helpers {
 				type Error = &'static str;
 				type AccountId = AccountId;
 				type BaseURI = BaseURI;
+				type Name = Vec<u8>;
+				type Symbol = Vec<u8>;
 
 				fn create_collection(
 					owner: AccountId,
@@ -168,19 +450,337 @@ mod helpers {
 					($create_collection_result)(owner, base_uri)
 				}
 
+				fn create_collection_with_name(
+					owner: AccountId,
+					_name: Self::Name,
+					_symbol: Self::Symbol,
+					base_uri: Self::BaseURI,
+				) -> Result<CollectionId, Self::Error> {
+					($create_collection_result)(owner, base_uri)
+				}
+
+				fn create_collection_with_mode(
+					owner: AccountId,
+					_name: Self::Name,
+					_symbol: Self::Symbol,
+					base_uri: Self::BaseURI,
+					_mode: pallet_living_assets_ownership::MintingMode,
+				) -> Result<CollectionId, Self::Error> {
+					($create_collection_result)(owner, base_uri)
+				}
+
+				fn mint(
+					_origin: AccountId,
+					_collection_id: CollectionId,
+					_to: H160,
+					_asset_id: U256,
+				) -> Result<(), Self::Error> {
+					Ok(())
+				}
+
+				fn total_supply(_collection_id: CollectionId) -> Result<u64, Self::Error> {
+					Ok(0)
+				}
+
 				fn base_uri(collection_id: CollectionId) -> Option<Self::BaseURI> {
 					($base_uri_result)(collection_id)
 				}
+
+				fn name(_collection_id: CollectionId) -> Option<Self::Name> {
+					None
+				}
+
+				fn symbol(_collection_id: CollectionId) -> Option<Self::Symbol> {
+					None
+				}
+
+				fn owner_of_collection(_collection_id: CollectionId) -> Option<AccountId> {
+					None
+				}
+
+				fn transfer_collection_ownership(
+					_origin: AccountId,
+					_collection_id: CollectionId,
+					_new_owner: AccountId,
+				) -> Result<(), Self::Error> {
+					Ok(())
+				}
+
+				fn renounce_collection_ownership(
+					_origin: AccountId,
+					_collection_id: CollectionId,
+				) -> Result<(), Self::Error> {
+					Ok(())
+				}
+
+				fn set_base_uri(
+					_origin: AccountId,
+					_collection_id: CollectionId,
+					_base_uri: Self::BaseURI,
+				) -> Result<(), Self::Error> {
+					Ok(())
+				}
+
+				fn set_token_uri(
+					_origin: AccountId,
+					_collection_id: CollectionId,
+					_asset_id: U256,
+					_token_uri: Self::BaseURI,
+				) -> Result<(), Self::Error> {
+					Ok(())
+				}
+
+				fn destroy_collection(
+					_origin: AccountId,
+					_collection_id: CollectionId,
+				) -> Result<(), Self::Error> {
+					Ok(())
+				}
+
+				fn freeze_metadata(
+					_origin: AccountId,
+					_collection_id: CollectionId,
+				) -> Result<(), Self::Error> {
+					Ok(())
+				}
+
+				fn make_soulbound(
+					_origin: AccountId,
+					_collection_id: CollectionId,
+				) -> Result<(), Self::Error> {
+					Ok(())
+				}
+
+				fn pause(
+					_origin: AccountId,
+					_collection_id: CollectionId,
+				) -> Result<(), Self::Error> {
+					Ok(())
+				}
+
+				fn unpause(
+					_origin: AccountId,
+					_collection_id: CollectionId,
+				) -> Result<(), Self::Error> {
+					Ok(())
+				}
+
+				fn set_default_royalty(
+					_origin: AccountId,
+					_collection_id: CollectionId,
+					_receiver: H160,
+					_basis_points: u16,
+				) -> Result<(), Self::Error> {
+					Ok(())
+				}
+
+				fn set_token_royalty(
+					_origin: AccountId,
+					_collection_id: CollectionId,
+					_asset_id: U256,
+					_receiver: H160,
+					_basis_points: u16,
+				) -> Result<(), Self::Error> {
+					Ok(())
+				}
+
+				fn has_role(
+					_collection_id: CollectionId,
+					_role: pallet_living_assets_ownership::CollectionRole,
+					_account: AccountId,
+				) -> Result<bool, Self::Error> {
+					Ok(false)
+				}
+
+				fn grant_role(
+					_origin: AccountId,
+					_collection_id: CollectionId,
+					_role: pallet_living_assets_ownership::CollectionRole,
+					_account: AccountId,
+				) -> Result<bool, Self::Error> {
+					Ok(false)
+				}
+
+				fn revoke_role(
+					_origin: AccountId,
+					_collection_id: CollectionId,
+					_role: pallet_living_assets_ownership::CollectionRole,
+					_account: AccountId,
+				) -> Result<bool, Self::Error> {
+					Ok(false)
+				}
+
+				fn renounce_role(
+					_origin: AccountId,
+					_collection_id: CollectionId,
+					_role: pallet_living_assets_ownership::CollectionRole,
+				) -> Result<bool, Self::Error> {
+					Ok(false)
+				}
+			}
+
+			// only batch transfers are made through this precompile, which succeed if the caller
+			// is the owner of the assets, the rest succeeds as for a collection with no owners
+			impl pallet_living_assets_ownership::traits::Erc721 for CollectionManagerMock {
+				type Error = &'static str;
+
+				fn owner_of(
+					_collection_id: CollectionId,
+					_asset_id: U256,
+				) -> Result<H160, Self::Error> {
+					Ok(H160::zero())
+				}
+
+				fn token_uri(
+					_collection_id: CollectionId,
+					_asset_id: U256,
+				) -> Result<Vec<u8>, Self::Error> {
+					Ok(Vec::new())
+				}
+
+				fn transfer_from(
+					_origin: H160,
+					_collection_id: CollectionId,
+					_from: H160,
+					_to: H160,
+					_asset_id: U256,
+				) -> Result<(), Self::Error> {
+					Ok(())
+				}
+
+				fn batch_transfer_from(
+					origin: H160,
+					from: H160,
+					_transfers: Vec<(CollectionId, H160, U256)>,
+				) -> Result<(), Self::Error> {
+					if origin == from {
+						Ok(())
+					} else {
+						Err("NoPermission")
+					}
+				}
+
+				fn approve(
+					_origin: H160,
+					_collection_id: CollectionId,
+					_to: H160,
+					_asset_id: U256,
+				) -> Result<(), Self::Error> {
+					Ok(())
+				}
+
+				fn get_approved(
+					_collection_id: CollectionId,
+					_asset_id: U256,
+				) -> Result<H160, Self::Error> {
+					Ok(H160::zero())
+				}
+
+				fn set_approval_for_all(
+					_origin: H160,
+					_collection_id: CollectionId,
+					_operator: H160,
+					_approved: bool,
+				) -> Result<(), Self::Error> {
+					Ok(())
+				}
+
+				fn is_approved_for_all(
+					_collection_id: CollectionId,
+					_owner: H160,
+					_operator: H160,
+				) -> Result<bool, Self::Error> {
+					Ok(false)
+				}
+
+				fn burn(
+					_origin: H160,
+					_collection_id: CollectionId,
+					_asset_id: U256,
+				) -> Result<(), Self::Error> {
+					Ok(())
+				}
+
+				fn locked(
+					_collection_id: CollectionId,
+					_asset_id: U256,
+				) -> Result<bool, Self::Error> {
+					Ok(false)
+				}
+
+				fn is_locked(
+					_collection_id: CollectionId,
+					_asset_id: U256,
+				) -> Result<bool, Self::Error> {
+					Ok(false)
+				}
+
+				fn paused(_collection_id: CollectionId) -> Result<bool, Self::Error> {
+					Ok(false)
+				}
+
+				fn set_user(
+					_origin: H160,
+					_collection_id: CollectionId,
+					_asset_id: U256,
+					_user: H160,
+					_expires: u64,
+				) -> Result<(), Self::Error> {
+					Ok(())
+				}
+
+				// none of the assets has a user
+				fn user_of(
+					_collection_id: CollectionId,
+					_asset_id: U256,
+				) -> Result<H160, Self::Error> {
+					Ok(H160::zero())
+				}
+
+				fn user_expires(
+					_collection_id: CollectionId,
+					_asset_id: U256,
+				) -> Result<u64, Self::Error> {
+					Ok(0)
+				}
+
+				fn royalty_info(
+					_collection_id: CollectionId,
+					_asset_id: U256,
+					_sale_price: U256,
+				) -> Result<(H160, U256), Self::Error> {
+					Ok((H160::zero(), U256::zero()))
+				}
+
+				fn balance_of(
+					_collection_id: CollectionId,
+					_owner: H160,
+				) -> Result<u32, Self::Error> {
+					Ok(0)
+				}
+
+				fn token_of_owner_by_index(
+					_collection_id: CollectionId,
+					_owner: H160,
+					_index: u32,
+				) -> Result<U256, Self::Error> {
+					Err("OwnerIndexOutOfBounds")
+				}
 			}
 
-			type $name =
-				CollectionManagerPrecompile<AddressMapping, AccountId, CollectionManagerMock>;
+			type $name = CollectionManagerPrecompile<
+				AddressMapping,
+				AccountId,
+				CollectionManagerMock,
+				MockGasWeightMapping,
+				(),
+			>;
 		};
 	}
 
 	/// Macro to define a precompile mock for testing.
 	///
-	/// This macro creates mock implementations of the `CollectionManager` trait,
+	/// This macro creates mock implementations of the `CollectionManager` and `Erc721` traits,
 	/// allowing you to test how your code interacts with the precompiled contracts.
 	/// The mock type is named `Mock`, and the implementation uses the provided expressions.
 	///
@@ -1,5 +1,38 @@
 use super::*;
 use fp_evm::{ExitReason, Transfer};
+use sp_std::boxed::Box;
+
+/// A subcall made by a precompile through [`PrecompileHandle::call`].
+pub struct Subcall {
+	pub address: H160,
+	pub transfer: Option<Transfer>,
+	pub input: Vec<u8>,
+	pub target_gas: Option<u64>,
+	pub is_static: bool,
+	pub context: Context,
+}
+
+/// The outcome of a mocked subcall, `cost` being the gas it consumed.
+pub struct SubcallOutput {
+	pub reason: ExitReason,
+	pub output: Vec<u8>,
+	pub cost: u64,
+}
+
+impl SubcallOutput {
+	/// A subcall that succeeds returning `output` without consuming gas.
+	pub fn succeed(output: Vec<u8>) -> Self {
+		Self { reason: ExitReason::Succeed(ExitSucceed::Returned), output, cost: 0 }
+	}
+
+	/// A subcall that reverts with `output` without consuming gas.
+	pub fn revert(output: Vec<u8>) -> Self {
+		Self { reason: ExitReason::Revert(ExitRevert::Reverted), output, cost: 0 }
+	}
+}
+
+/// Answers the subcalls of a [`MockHandle`].
+pub type SubcallHandle = Box<dyn FnMut(Subcall) -> SubcallOutput>;
 
 pub struct MockHandle {
 	pub input: Vec<u8>,
@@ -9,6 +42,7 @@ pub struct MockHandle {
 	pub gas_used: u64,
 	pub logs: Vec<Log>,
 	pub code_address: H160,
+	pub subcall_handle: Option<SubcallHandle>,
 }
 
 impl MockHandle {
@@ -21,8 +55,18 @@ impl MockHandle {
 			gas_used: 0,
 			logs: vec![],
 			code_address: H160::zero(),
+			subcall_handle: None,
 		}
 	}
+
+	/// Answers every subcall with `subcall_handle`, which also gets to check it.
+	pub fn with_subcall_handle(
+		mut self,
+		subcall_handle: impl FnMut(Subcall) -> SubcallOutput + 'static,
+	) -> Self {
+		self.subcall_handle = Some(Box::new(subcall_handle));
+		self
+	}
 }
 
 impl PrecompileHandle for MockHandle {
@@ -30,14 +74,25 @@ impl PrecompileHandle for MockHandle {
 	/// Precompile specifies in which context the subcall is executed.
 	fn call(
 		&mut self,
-		_: H160,
-		_: Option<Transfer>,
-		_: Vec<u8>,
-		_: Option<u64>,
-		_: bool,
-		_: &Context,
+		address: H160,
+		transfer: Option<Transfer>,
+		input: Vec<u8>,
+		target_gas: Option<u64>,
+		is_static: bool,
+		context: &Context,
 	) -> (ExitReason, Vec<u8>) {
-		unimplemented!()
+		let subcall_handle =
+			self.subcall_handle.as_mut().expect("no subcall handle registered in the mock");
+		let SubcallOutput { reason, output, cost } = subcall_handle(Subcall {
+			address,
+			transfer,
+			input,
+			target_gas,
+			is_static,
+			context: context.clone(),
+		});
+		self.gas_used += cost;
+		(reason, output)
 	}
 
 	fn record_cost(&mut self, cost: u64) -> Result<(), ExitError> {
@@ -80,8 +80,8 @@ use xcm_executor::XcmExecutor;
 use fp_rpc::TransactionStatus;
 use pallet_ethereum::{Call::transact, PostLogContent, Transaction as EthereumTransaction};
 use pallet_evm::{
-	Account as EVMAccount, EVMCurrencyAdapter, EnsureAddressTruncated, FeeCalculator,
-	HashedAddressMapping, OnChargeEVMTransaction, Runner,
+	Account as EVMAccount, AddressMapping, EVMCurrencyAdapter, EnsureAddressTruncated,
+	FeeCalculator, HashedAddressMapping, OnChargeEVMTransaction, Runner,
 };
 
 mod precompiles;
@@ -147,6 +147,21 @@ pub type UncheckedExtrinsic =
 pub type CheckedExtrinsic =
 	fp_self_contained::CheckedExtrinsic<AccountId, RuntimeCall, SignedExtra, H160>;
 
+/// Migrations to apply on runtime upgrade.
+///
+/// The accounts `pallet_evm` held under `HashedH160ToAccountId` are moved, over several blocks, from
+/// the upgrade that made it use `H160ToAccountId`, see `HASHED_EVM_EOAS`.
+pub type Migrations =
+	(pallet_living_assets_ownership::migrations::v1::MigrateToV1<Runtime>, AddressMappingMigration);
+
+/// Moves the accounts of `HashedEvmAddresses` from `HashedH160ToAccountId` to `H160ToAccountId`.
+pub type AddressMappingMigration =
+	pallet_living_assets_ownership::migrations::MigrateAddressMapping<
+		Runtime,
+		HashedH160ToAccountId,
+		HashedEvmAddresses,
+	>;
+
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
 	Runtime,
@@ -154,6 +169,7 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
 
 pub type Precompiles = FrontierPrecompiles<Runtime>;
@@ -468,12 +484,28 @@ impl pallet_collator_selection::Config for Runtime {
 	type MinEligibleCollators = ConstU32<4>;
 }
 
+parameter_types! {
+	pub const CollectionDeposit: Balance = 100 * MILLIUNIT;
+	// 10 times the `TransactionByteFee`, as the base URI is kept in storage
+	pub const CollectionDepositPerByte: Balance = 100 * MICROUNIT;
+}
+
 impl pallet_living_assets_ownership::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type BaseURILimit = ConstU32<2015>;
+	type NameLimit = ConstU32<64>;
+	type SymbolLimit = ConstU32<16>;
 	type AccountIdToH160 = AccountIdToH160;
 	type H160ToAccountId = H160ToAccountId;
 	type AssetIdToInitialOwner = AssetIdToInitialOwner;
+	type WeightInfo = weights::pallet_living_assets_ownership::WeightInfo<Runtime>;
+	type Currency = Balances;
+	type CollectionDeposit = CollectionDeposit;
+	type DepositPerByte = CollectionDepositPerByte;
+	type MaxBatchTransfers = ConstU32<500>;
+	type MaxLocksPerAsset = ConstU32<16>;
+	type UnixTime = Timestamp;
+	type SteppedMigrations = AddressMappingMigration;
 }
 
 impl pallet_sudo::Config for Runtime {
@@ -500,6 +532,9 @@ impl Convert<AccountId, H160> for AccountIdToH160 {
 ///
 /// The `H160ToAccountId` struct provides a conversion from `H160`, commonly used in Ethereum-like networks,
 /// to `AccountId`, typically used as a native identity in a blockchain.
+///
+/// `pallet_evm`, the living assets pallet and its precompiles use it, so an EVM address has a single
+/// account, and `AccountIdToH160` is its inverse.
 pub struct H160ToAccountId;
 impl Convert<H160, AccountId> for H160ToAccountId {
 	fn convert(account_id: H160) -> AccountId {
@@ -509,6 +544,79 @@ impl Convert<H160, AccountId> for H160ToAccountId {
 	}
 }
 
+impl AddressMapping<AccountId> for H160ToAccountId {
+	fn into_account_id(address: H160) -> AccountId {
+		Self::convert(address)
+	}
+}
+
+/// The mapping of EVM addresses `pallet_evm` used before `H160ToAccountId`, as a `Convert`, to
+/// migrate the accounts it gives.
+pub struct HashedH160ToAccountId;
+impl Convert<H160, AccountId> for HashedH160ToAccountId {
+	fn convert(address: H160) -> AccountId {
+		HashedAddressMapping::<BlakeTwo256>::into_account_id(address)
+	}
+}
+
+/// Externally owned accounts holding funds under `HashedH160ToAccountId`, indexed off-chain from
+/// the senders of Ethereum transactions.
+///
+/// The accounts it gives can not be told apart in storage, so the funds of an account missing here
+/// are stranded.
+pub const HASHED_EVM_EOAS: &[H160] = &[];
+
+/// EVM addresses whose collections, deposits, balance and nonce are moved from the account given
+/// by `HashedH160ToAccountId` to the one given by `H160ToAccountId`: every contract, plus
+/// `HASHED_EVM_EOAS`.
+pub struct HashedEvmAddresses;
+
+/// Position of a page of `HashedEvmAddresses`.
+#[derive(Encode, Decode)]
+enum HashedEvmAddressesCursor {
+	/// Contracts after the one of the given raw key of `pallet_evm::AccountCodes`
+	Contracts(Vec<u8>),
+	/// `HASHED_EVM_EOAS` from the given index on
+	Eoas(u32),
+}
+
+impl pallet_living_assets_ownership::migrations::AddressPages for HashedEvmAddresses {
+	fn page(cursor: Option<Vec<u8>>, limit: u32) -> (Vec<H160>, Option<Vec<u8>>) {
+		let cursor = match cursor {
+			Some(cursor) => HashedEvmAddressesCursor::decode(&mut &cursor[..]).ok(),
+			None => None,
+		};
+		let limit = limit as usize;
+		let (addresses, next) = match cursor {
+			Some(HashedEvmAddressesCursor::Eoas(first)) => {
+				let first = first as usize;
+				let addresses: Vec<H160> =
+					HASHED_EVM_EOAS.iter().skip(first).take(limit).copied().collect();
+				let next = first.saturating_add(addresses.len());
+				let cursor = (next < HASHED_EVM_EOAS.len())
+					.then(|| HashedEvmAddressesCursor::Eoas(next as u32));
+				(addresses, cursor)
+			},
+			contracts => {
+				let mut keys = match contracts {
+					Some(HashedEvmAddressesCursor::Contracts(last_key)) => {
+						pallet_evm::AccountCodes::<Runtime>::iter_keys_from(last_key)
+					},
+					_ => pallet_evm::AccountCodes::<Runtime>::iter_keys(),
+				};
+				let addresses: Vec<H160> = keys.by_ref().take(limit).collect();
+				let cursor = if addresses.len() == limit {
+					HashedEvmAddressesCursor::Contracts(keys.last_raw_key().to_vec())
+				} else {
+					HashedEvmAddressesCursor::Eoas(0)
+				};
+				(addresses, Some(cursor))
+			},
+		};
+		(addresses, next.map(|cursor| cursor.encode()))
+	}
+}
+
 /// Represents a mapping between `AssetId` and `AccountId`.
 /// This struct provides functionalities to convert an `AssetId` (represented by `U256`) into an `AccountId`.
 pub struct AssetIdToInitialOwner;
@@ -615,7 +723,7 @@ impl pallet_evm::Config for Runtime {
 	type BlockHashMapping = pallet_ethereum::EthereumBlockHashMapping<Self>;
 	type CallOrigin = EnsureAddressTruncated;
 	type WithdrawOrigin = EnsureAddressTruncated;
-	type AddressMapping = HashedAddressMapping<BlakeTwo256>;
+	type AddressMapping = H160ToAccountId;
 	type Currency = Balances;
 	type RuntimeEvent = RuntimeEvent;
 	type PrecompilesType = FrontierPrecompiles<Self>;
@@ -844,6 +952,7 @@ mod benches {
 		[pallet_timestamp, Timestamp]
 		[pallet_collator_selection, CollatorSelection]
 		[cumulus_pallet_xcmp_queue, XcmpQueue]
+		[pallet_living_assets_ownership, LivingAssetsOwnership]
 	);
 }
 
@@ -91,9 +91,19 @@ impl pallet_timestamp::Config for Runtime {
 impl pallet_living_assets_ownership::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type BaseURILimit = ConstU32<256>;
+	type NameLimit = ConstU32<64>;
+	type SymbolLimit = ConstU32<16>;
 	type AccountIdToH160 = MockAccountIdToH160;
 	type H160ToAccountId = MockH160ToAccountId;
 	type AssetIdToInitialOwner = MockAssetIdToInitialOwner;
+	type WeightInfo = ();
+	type Currency = Balances;
+	type CollectionDeposit = ConstU64<0>;
+	type DepositPerByte = ConstU64<0>;
+	type MaxBatchTransfers = ConstU32<100>;
+	type MaxLocksPerAsset = ConstU32<8>;
+	type UnixTime = Timestamp;
+	type SteppedMigrations = ();
 }
 
 pub struct MockAccountIdToH160;
@@ -1,9 +1,9 @@
 #![allow(clippy::new_without_default)]
 
+use frame_support::traits::Contains;
 use pallet_evm::{
 	IsPrecompileResult, Precompile, PrecompileHandle, PrecompileResult, PrecompileSet,
 };
-use polkadot_primitives::BlakeTwo256;
 use sp_core::H160;
 use sp_std::marker::PhantomData;
 
@@ -29,13 +29,33 @@ where
 	}
 }
 
+// the living assets precompile maps callers as `pallet_evm` does, so deposits are paid from the
+// account holding their EVM funds
 type LivingAssetsPrecompile = CollectionManagerPrecompile<
-	pallet_evm::HashedAddressMapping<BlakeTwo256>,
+	<crate::Runtime as pallet_evm::Config>::AddressMapping,
 	AccountId,
 	pallet_living_assets_ownership::Pallet<crate::Runtime>,
+	<crate::Runtime as pallet_evm::Config>::GasWeightMapping,
+	<crate::Runtime as pallet_living_assets_ownership::Config>::WeightInfo,
 >;
 
-type Erc721 = Erc721Precompile<pallet_living_assets_ownership::Pallet<crate::Runtime>>;
+type Erc721 = Erc721Precompile<
+	<crate::Runtime as pallet_living_assets_ownership::Config>::H160ToAccountId,
+	<crate::Runtime as pallet_living_assets_ownership::Config>::AccountIdToH160,
+	pallet_living_assets_ownership::Pallet<crate::Runtime>,
+	<crate::Runtime as pallet_evm::Config>::GasWeightMapping,
+	<crate::Runtime as pallet_living_assets_ownership::Config>::WeightInfo,
+	IsContract<crate::Runtime>,
+>;
+
+/// Addresses with EVM code.
+pub struct IsContract<Runtime>(PhantomData<Runtime>);
+
+impl<Runtime: pallet_evm::Config> Contains<H160> for IsContract<Runtime> {
+	fn contains(address: &H160) -> bool {
+		pallet_evm::AccountCodes::<Runtime>::contains_key(address)
+	}
+}
 
 impl<Runtime> PrecompileSet for FrontierPrecompiles<Runtime>
 where
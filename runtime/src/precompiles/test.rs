@@ -1,5 +1,6 @@
-use super::{hash, mock::*, FrontierPrecompiles};
+use super::{hash, mock::*, FrontierPrecompiles, IsContract};
 use core::str::FromStr;
+use frame_support::{sp_io::TestExternalities, traits::Contains};
 use pallet_evm::{IsPrecompileResult, PrecompileSet};
 use sp_core::H160;
 
@@ -27,3 +28,14 @@ fn ethereum_precompiled_addresses_are_precompile() {
 	assert!(is_precompile(H160::from_str("0xffffffffffffffffffffffff0000000000000005").unwrap())
 		.unwrap());
 }
+
+#[test]
+fn is_contract_should_only_hold_for_addresses_with_code() {
+	TestExternalities::default().execute_with(|| {
+		let address = H160::repeat_byte(0xAA);
+		assert!(!IsContract::<Runtime>::contains(&address));
+
+		pallet_evm::AccountCodes::<Runtime>::insert(address, vec![0x00]);
+		assert!(IsContract::<Runtime>::contains(&address));
+	});
+}
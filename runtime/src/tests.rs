@@ -89,3 +89,148 @@ fn asset_id_to_address_two_assets_same_owner() {
 			.unwrap()
 	);
 }
+
+#[test]
+fn evm_and_living_assets_should_share_the_address_mapping() {
+	type EvmAddressMapping = <Runtime as pallet_evm::Config>::AddressMapping;
+	type TestH160ToAccountId = <Runtime as pallet_living_assets_ownership::Config>::H160ToAccountId;
+
+	for address in [
+		H160::zero(),
+		H160::from([0xFFu8; 20]),
+		H160::from_str("C0F0f4ab324C46e55D02D0033343B4Be8A55532d").unwrap(),
+	] {
+		assert_eq!(
+			EvmAddressMapping::into_account_id(address),
+			TestH160ToAccountId::convert(address)
+		);
+	}
+}
+
+#[test]
+fn address_mapping_should_round_trip() {
+	type TestH160ToAccountId = <Runtime as pallet_living_assets_ownership::Config>::H160ToAccountId;
+	type TestAccountIdToH160 = <Runtime as pallet_living_assets_ownership::Config>::AccountIdToH160;
+
+	let address = H160::from_str("C0F0f4ab324C46e55D02D0033343B4Be8A55532d").unwrap();
+	assert_eq!(TestAccountIdToH160::convert(TestH160ToAccountId::convert(address)), address);
+}
+
+#[test]
+fn collection_creator_should_be_the_initial_owner_of_its_address_assets() {
+	type EvmAddressMapping = <Runtime as pallet_evm::Config>::AddressMapping;
+	type TestAssetIdToInitialOwner =
+		<Runtime as pallet_living_assets_ownership::Config>::AssetIdToInitialOwner;
+
+	// the account a precompile caller creates collections with owns the assets encoding its address
+	let address = H160::from_str("C0F0f4ab324C46e55D02D0033343B4Be8A55532d").unwrap();
+	let asset_id =
+		U256::from(hex::decode("01C0F0f4ab324C46e55D02D0033343B4Be8A55532d").unwrap().as_slice());
+	assert_eq!(
+		EvmAddressMapping::into_account_id(address),
+		TestAssetIdToInitialOwner::convert(asset_id)
+	);
+}
+
+#[test]
+fn hashed_address_mapping_should_differ_from_the_current_one() {
+	let address = H160::from_str("C0F0f4ab324C46e55D02D0033343B4Be8A55532d").unwrap();
+	assert_ne!(HashedH160ToAccountId::convert(address), H160ToAccountId::convert(address));
+}
+
+#[test]
+fn evm_call_should_only_accept_the_truncated_account_of_its_source() {
+	let source = H160::from_str("C0F0f4ab324C46e55D02D0033343B4Be8A55532d").unwrap();
+	let target = H160::from_low_u64_be(0x1234);
+	let account = H160ToAccountId::convert(source);
+	let mut truncated = [0u8; 32];
+	truncated[..20].copy_from_slice(&source.0);
+	let call = |origin: RuntimeOrigin| {
+		EVM::call(
+			origin,
+			source,
+			target,
+			Vec::new(),
+			U256::zero(),
+			100_000,
+			<Runtime as pallet_evm::Config>::FeeCalculator::min_gas_price().0,
+			None,
+			None,
+			Vec::new(),
+		)
+	};
+
+	let storage = RuntimeGenesisConfig::default().build_storage().unwrap();
+	frame_support::sp_io::TestExternalities::new(storage).execute_with(|| {
+		Balances::make_free_balance_be(&account, 1_000 * UNIT);
+
+		assert!(call(RuntimeOrigin::signed(account.clone())).is_err());
+		assert!(call(RuntimeOrigin::signed(AccountId32::from(truncated))).is_ok());
+	});
+}
+
+#[test]
+fn hashed_evm_addresses_should_page_through_every_contract() {
+	use pallet_living_assets_ownership::migrations::AddressPages;
+
+	let storage = RuntimeGenesisConfig::default().build_storage().unwrap();
+	frame_support::sp_io::TestExternalities::new(storage).execute_with(|| {
+		for contract in 1..=5 {
+			pallet_evm::AccountCodes::<Runtime>::insert(H160::from_low_u64_be(contract), vec![0]);
+		}
+
+		let mut addresses = Vec::new();
+		let mut cursor = None;
+		let mut pages = 0;
+		loop {
+			let (page, next) = HashedEvmAddresses::page(cursor, 2);
+			assert!(page.len() <= 2);
+			addresses.extend(page);
+			pages += 1;
+			match next {
+				Some(next) => cursor = Some(next),
+				None => break,
+			}
+		}
+
+		addresses.sort();
+		assert_eq!(addresses, (1..=5).map(H160::from_low_u64_be).collect::<Vec<_>>());
+		// three pages of contracts and an empty one of externally owned accounts
+		assert_eq!(pages, 4);
+	});
+}
+
+#[test]
+fn transfer_event_should_report_the_account_given() {
+	type TestAccountIdToH160 = <Runtime as pallet_living_assets_ownership::Config>::AccountIdToH160;
+
+	let owner = AccountId32::from([1u8; 32]);
+	// not the account its address maps back to
+	let to = AccountId32::from([2u8; 32]);
+	let asset_id = U256::from_big_endian(TestAccountIdToH160::convert(owner.clone()).as_bytes());
+
+	let storage = RuntimeGenesisConfig::default().build_storage().unwrap();
+	frame_support::sp_io::TestExternalities::new(storage).execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&owner, 1_000 * UNIT);
+		frame_support::assert_ok!(LivingAssetsOwnership::create_collection(
+			RuntimeOrigin::signed(owner.clone()),
+			Default::default()
+		));
+
+		frame_support::assert_ok!(LivingAssetsOwnership::transfer(
+			RuntimeOrigin::signed(owner),
+			0,
+			to.clone(),
+			asset_id
+		));
+		System::assert_last_event(
+			pallet_living_assets_ownership::Event::AssetTransferred {
+				collection_id: 0,
+				asset_id,
+				to,
+			}
+			.into(),
+		);
+	});
+}
@@ -19,6 +19,7 @@
 
 pub mod block_weights;
 pub mod extrinsic_weights;
+pub mod pallet_living_assets_ownership;
 pub mod paritydb_weights;
 pub mod rocksdb_weights;
 
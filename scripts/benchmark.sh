@@ -0,0 +1,23 @@
+#!/usr/bin/env bash
+# Benchmarks pallet_living_assets_ownership and writes its weights, both the default ones of the
+# pallet and the ones of the runtime. Run it on reference hardware, from the root of the repository.
+set -euo pipefail
+
+cargo build --release --features runtime-benchmarks
+
+benchmark() {
+	./target/release/laos benchmark pallet \
+		--chain=dev \
+		--steps=50 \
+		--repeat=20 \
+		--pallet=pallet_living_assets_ownership \
+		--extrinsic='*' \
+		--wasm-execution=compiled \
+		--heap-pages=4096 \
+		"$@"
+}
+
+benchmark --output=./runtime/src/weights/pallet_living_assets_ownership.rs
+benchmark \
+	--template=./.maintain/frame-weight-template.hbs \
+	--output=./pallets/living-assets-ownership/src/weights.rs